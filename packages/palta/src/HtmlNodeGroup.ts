@@ -16,11 +16,15 @@
 
 export type HtmlNodeGroupChild = HtmlNodeGroup | ChildNode | null;
 
-const appendNode = (parent: Node, node: HtmlNodeGroupChild) => {
+const appendNode = (
+  parent: Node,
+  node: HtmlNodeGroupChild,
+  before: Node | null
+) => {
   if (node instanceof HtmlNodeGroup) {
-    node.appendToParent(parent);
+    node.appendToParent(parent, before);
   } else if (node) {
-    parent.appendChild(node);
+    parent.insertBefore(node, before);
   }
 };
 
@@ -34,19 +38,23 @@ const removeNode = (node: HtmlNodeGroupChild) => {
 
 class HtmlNodeGroup {
   private _parent: Node | null = null;
+  // Node the children are inserted before, when the group is not at the end
+  // of its parent
+  private _before: Node | null = null;
   private _children: HtmlNodeGroupChild[] = [];
 
-  appendToParent(parent: Node) {
+  appendToParent(parent: Node, before: Node | null = null) {
     if (parent === this._parent) {
       return;
     }
 
     for (const child of this._children) {
       removeNode(child);
-      appendNode(parent, child);
+      appendNode(parent, child, before);
     }
 
     this._parent = parent;
+    this._before = before;
   }
 
   removeFromNode() {
@@ -55,6 +63,7 @@ class HtmlNodeGroup {
     }
 
     this._parent = null;
+    this._before = null;
   }
 
   push(...node: HtmlNodeGroupChild[]) {
@@ -65,7 +74,7 @@ class HtmlNodeGroup {
     }
 
     for (const child of node) {
-      appendNode(this._parent, child);
+      appendNode(this._parent, child, this._before);
     }
   }
 
@@ -100,7 +109,28 @@ class HtmlNodeGroup {
       removeNode(oldNode);
     }
 
-    appendNode(this._parent, newNode);
+    appendNode(this._parent, newNode, this._before);
+  }
+
+  // Replaces the children in place, the ones kept being moved to their new
+  // position instead of being recreated
+  setChildren(children: HtmlNodeGroupChild[]) {
+    for (const child of this._children) {
+      if (!children.includes(child)) {
+        removeNode(child);
+      }
+    }
+
+    this._children = children;
+
+    if (!this._parent) {
+      return;
+    }
+
+    for (const child of children) {
+      removeNode(child);
+      appendNode(this._parent, child, this._before);
+    }
   }
 
   clear() {
@@ -115,6 +145,16 @@ class HtmlNodeGroup {
     return this._children.includes(node);
   }
 
+  childAt(index: number): HtmlNodeGroupChild {
+    return this._children[index] ?? null;
+  }
+
+  get nodes(): ChildNode[] {
+    return this._children.flatMap((child) =>
+      child instanceof HtmlNodeGroup ? child.nodes : child ? [child] : []
+    );
+  }
+
   get length() {
     return this._children.length;
   }
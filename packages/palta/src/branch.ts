@@ -0,0 +1,120 @@
+/**
+ * Copyright 2024 Aubin REBILLAT
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+import HtmlNodeGroup from "./HtmlNodeGroup";
+import {
+  PaltaBranchElement,
+  PaltaEffect,
+  PaltaElementSymbol,
+  PaltaNode,
+} from "./types";
+import { getHtmlNodeGroupChildFromPaltaNode, unmountPaltaNode } from "./utils";
+import { cleanupEffect } from "./effect";
+
+class Branch implements PaltaBranchElement {
+  [PaltaElementSymbol]: "branch" = "branch";
+
+  private _branches: Array<() => PaltaNode>;
+  private _nodes: PaltaNode[] = [];
+  private _initialized: boolean[];
+  // Effects of the component run by each branch
+  private _effects: PaltaEffect[][];
+  private _active: number = -1;
+  private _mounted: boolean = false;
+  private _nodeGroup: HtmlNodeGroup = new HtmlNodeGroup();
+
+  // Branch nodes are only created by their initializer, so they are read
+  // through getters once the branch has been activated for the first time.
+  constructor(branches: Array<() => PaltaNode>) {
+    this._branches = branches;
+    this._initialized = branches.map(() => false);
+    this._effects = branches.map(() => []);
+  }
+
+  mount() {
+    if (!this._mounted && this._active !== -1) {
+      this._nodeGroup.push(
+        getHtmlNodeGroupChildFromPaltaNode(this._nodes[this._active])
+      );
+    }
+
+    this._mounted = true;
+
+    return this._nodeGroup;
+  }
+
+  unmount() {
+    if (this._active !== -1) {
+      unmountPaltaNode(this._nodes[this._active]);
+    }
+
+    this._nodeGroup.clear();
+    this._mounted = false;
+  }
+
+  updateBranch(
+    index: number,
+    initialize?: () => void,
+    update?: () => void,
+    effects: PaltaEffect[] = []
+  ) {
+    this._effects[index] = effects;
+
+    if (this._initialized[index]) {
+      update?.();
+    } else {
+      initialize?.();
+      this._nodes[index] = this._branches[index]();
+      this._initialized[index] = true;
+    }
+
+    if (index === this._active) {
+      return;
+    }
+
+    if (this._active !== -1) {
+      this.cleanupEffects(this._active, index);
+
+      if (this._mounted) {
+        unmountPaltaNode(this._nodes[this._active]);
+      }
+    }
+
+    this._active = index;
+
+    if (this._mounted) {
+      this._nodeGroup.replaceChild(
+        0,
+        getHtmlNodeGroupChildFromPaltaNode(this._nodes[index])
+      );
+    }
+  }
+
+  // The effects left by the branch are cleaned up as for an unmount, the
+  // ones shared with the activated branch being kept running
+  private cleanupEffects(previous: number, next: number) {
+    for (const effect of this._effects[previous]) {
+      if (!this._effects[next].includes(effect)) {
+        cleanupEffect(effect);
+      }
+    }
+  }
+}
+
+export const createBranch = (
+  branches: Array<() => PaltaNode>
+): PaltaBranchElement =>
+  new Branch(branches);
@@ -15,41 +15,158 @@
  */
 
 import HtmlNodeGroup from "./HtmlNodeGroup";
-import { PaltaChildrenElement, PaltaElementSymbol, PaltaNode } from "./types";
-import { getHtmlNodeGroupChildFromPaltaNode, isPaltaElement } from "./utils";
+import { createList } from "./list";
+import {
+  PaltaChildrenElement,
+  PaltaComponent,
+  PaltaElementSymbol,
+  PaltaList,
+  PaltaNode,
+} from "./types";
+import {
+  getHtmlNodeGroupChildFromPaltaNode,
+  isEmptyPaltaNode,
+  isRenderProp,
+  unmountPaltaNode,
+  updatePaltaNode,
+} from "./utils";
 
 class Children implements PaltaChildrenElement {
   [PaltaElementSymbol]: "children" = "children";
 
   private _children: PaltaNode[] = [];
   private _nodeGroup: HtmlNodeGroup = new HtmlNodeGroup();
+  // Nodes of the children, kept apart from the fallback ones so that they are
+  // found at the index of their child
+  private _childrenGroup: HtmlNodeGroup = new HtmlNodeGroup();
+  private _mounted: boolean = false;
+  // The fallback is only read when mounted, as it may be created in a branch
+  private _fallback?: () => PaltaNode;
+  private _mountedFallback: { node: PaltaNode; group: HtmlNodeGroup } | null =
+    null;
+  // Elements of the render prop, for each expression calling `$children`
+  private _renderLists = new Map<
+    number,
+    { component: PaltaComponent<any[]>; list: PaltaList<any[]> }
+  >();
+
+  constructor(fallback?: () => PaltaNode) {
+    this._fallback = fallback;
+  }
 
   mount() {
+    this._childrenGroup.push(
+      ...this._children.map(getHtmlNodeGroupChildFromPaltaNode)
+    );
+    this._nodeGroup.push(this._childrenGroup);
+    this._mounted = true;
+    this.updateFallback();
+
     return this._nodeGroup;
   }
 
   unmount() {
-    this._nodeGroup.clear();
+    this.unmountFallback();
 
     for (const child of this._children) {
-      if (isPaltaElement(child)) {
-        child.unmount();
-      }
+      unmountPaltaNode(child);
     }
+
+    this._childrenGroup.clear();
+    this._nodeGroup.clear();
+    this._mounted = false;
   }
 
   updateChild(index: number, value: () => PaltaNode) {
     const node = value();
+    const previous = this._children[index];
+
+    if (index < this._children.length && node === previous) {
+      return;
+    }
+
     this._children[index] = node;
-    this._nodeGroup.replaceChild(
+
+    if (!this._mounted) {
+      unmountPaltaNode(previous);
+      return;
+    }
+
+    this._childrenGroup.replaceChild(
       index,
-      getHtmlNodeGroupChildFromPaltaNode(node)
+      updatePaltaNode(previous, node, this._childrenGroup.childAt(index))
     );
+    this.updateFallback();
   }
 
   setNodes(value: PaltaNode[]) {
     this._children = value;
   }
+
+  collect<T>(site: number, render: () => T): T {
+    const list = this.getRenderList(site);
+
+    return list ? list.collect(render) : render();
+  }
+
+  render(site: number, ...args: any[]) {
+    const renderProp = this._children.find(isRenderProp);
+
+    if (!renderProp) {
+      return null;
+    }
+
+    return this.getRenderList(site)!.render(renderProp.props(...args));
+  }
+
+  private getRenderList(site: number) {
+    const renderProp = this._children.find(isRenderProp);
+
+    if (!renderProp) {
+      return null;
+    }
+
+    let renderList = this._renderLists.get(site);
+
+    if (!renderList || renderList.component !== renderProp.component) {
+      renderList = {
+        component: renderProp.component,
+        list: createList(renderProp.component),
+      };
+      this._renderLists.set(site, renderList);
+    }
+
+    return renderList.list;
+  }
+
+  // The fallback nodes are kept after the children ones, which all render
+  // nothing while it is mounted
+  private updateFallback() {
+    const empty = this._children.every(isEmptyPaltaNode);
+
+    if (!empty) {
+      this.unmountFallback();
+    } else if (this._fallback && !this._mountedFallback) {
+      const node = this._fallback();
+      const group = new HtmlNodeGroup();
+
+      group.push(getHtmlNodeGroupChildFromPaltaNode(node));
+      this._nodeGroup.push(group);
+      this._mountedFallback = { node, group };
+    }
+  }
+
+  private unmountFallback() {
+    if (!this._mountedFallback) {
+      return;
+    }
+
+    unmountPaltaNode(this._mountedFallback.node);
+    this._nodeGroup.remove(this._mountedFallback.group);
+    this._mountedFallback = null;
+  }
 }
 
-export const createChildren = (): PaltaChildrenElement => new Children();
+export const createChildren = (
+  fallback?: () => PaltaNode
+): PaltaChildrenElement => new Children(fallback);
@@ -23,55 +23,144 @@ import {
   PaltaElement,
   PaltaComponentDefinition,
   PaltaBuiltinComponent,
+  PaltaHotState,
 } from "./types";
-import { isBuiltinComponent } from "./utils";
+import { isBuiltinComponent, isRenderProp, isSlotElement } from "./utils";
+import { resolveHotComponent, trackHotInstance } from "./hmr";
 import HtmlNodeGroup from "./HtmlNodeGroup";
 
 class Component<P extends any> implements PaltaComponentElement<P> {
   [PaltaElementSymbol]: "component" = "component";
 
   private _nodeGroup: HtmlNodeGroup;
-  private _childrenElement: PaltaChildrenElement | null;
+  private _slots!: Record<string, PaltaChildrenElement>;
+  // Named slots filled by the prop of the same name, without a `<Slot>` child
+  private _propSlots: string[] = [];
   private _children: PaltaNode[] = [];
+  private _props!: P;
+  private _mounted: boolean = false;
 
-  private _initialize: (props: P) => void;
-  private _update: (props: P) => void;
-  private _getRoot: () => PaltaElement;
+  private _initialize!: (props: P) => void;
+  private _update!: (props: P) => void;
+  private _getRoot!: () => PaltaElement;
+  private _unmount?: () => void;
+  private _hot?: PaltaHotState;
 
   constructor(definition: PaltaComponentDefinition<P>, children: PaltaNode[]) {
     this._nodeGroup = new HtmlNodeGroup();
-    this._childrenElement = definition.childrenElement;
     this._children = children;
+    this.setDefinition(definition);
+  }
+
+  private setDefinition(definition: PaltaComponentDefinition<P>) {
+    this._slots = definition.slots;
     this._initialize = definition.initialize;
     this._update = definition.update;
     this._getRoot = definition.getRoot;
+    this._unmount = definition.unmount;
+    this._hot = definition.hot;
   }
 
+  // The slots are filled before the component is initialized, which may
+  // render the render prop given as children
   initialize(props: P) {
+    this._props = props;
+    this.setSlotNodes();
     this._initialize(props);
   }
 
   updateProps(props: P) {
+    this._props = props;
     this._update(props);
+
+    if (this._mounted) {
+      for (const name of this._propSlots) {
+        this._slots[name].updateChild(0, () => this.getPropSlot(name));
+      }
+    }
+  }
+
+  // Swaps the definition after a hot reload, the state being carried over
+  // when the new definition declares the same one
+  replace(definition: PaltaComponentDefinition<P>) {
+    const state = this._hot?.save();
+    const shape = this._hot?.shape;
+    const mounted = this._mounted;
+
+    if (mounted) {
+      this.unmount();
+    }
+
+    this.setDefinition(definition);
+
+    if (state && this._hot?.shape === shape) {
+      this._hot?.restore(state);
+    }
+
+    this.initialize(this._props);
+
+    if (mounted) {
+      this.mount();
+    }
   }
 
   updateChild(index: number, value: () => any) {
-    this._childrenElement?.updateChild(index, value);
+    const node = value();
+
+    this._children[index] = node;
+    this._slots.children?.updateChild(index, () => node);
+
+    // Render props capture values of the parent component, so the component
+    // renders them again
+    if (this._mounted && isRenderProp(node)) {
+      this._update(this._props);
+    }
   }
 
-  mount() {
-    if (this._childrenElement) {
-      this._childrenElement.setNodes(this._children);
+  private getPropSlot(name: string): PaltaNode {
+    return (this._props as Record<string, PaltaNode>)?.[name];
+  }
+
+  // Children wrapped in a `<Slot>` fill the slot of the same name, and are
+  // left out of the default one
+  private setSlotNodes() {
+    const { children, ...slots } = this._slots;
+    const slotted: Record<string, PaltaNode> = {};
+
+    const nodes = this._children.map((child) => {
+      if (isSlotElement(child)) {
+        slotted[child.slot] = child;
+        return null;
+      }
+
+      return child;
+    });
+
+    children?.setNodes(nodes);
+    this._propSlots = [];
+
+    for (const [name, slot] of Object.entries(slots)) {
+      if (name in slotted) {
+        slot.setNodes([slotted[name]]);
+      } else {
+        slot.setNodes([this.getPropSlot(name)]);
+        this._propSlots.push(name);
+      }
     }
+  }
 
+  mount() {
     this._nodeGroup.push(this._getRoot().mount());
+    this._mounted = true;
 
     return this._nodeGroup;
   }
 
   unmount() {
+    this._unmount?.();
     this._getRoot().unmount();
     this._nodeGroup.clear();
+    this._mounted = false;
   }
 }
 
@@ -79,9 +168,15 @@ export const createComponent = <P extends any>(
   component: PaltaComponent<P>,
   children: PaltaNode[]
 ): PaltaComponentElement<P> | PaltaBuiltinComponent<P> => {
-  const componentDefinition = component();
+  const componentDefinition = resolveHotComponent(component)();
+
+  if (isBuiltinComponent(componentDefinition)) {
+    return componentDefinition;
+  }
+
+  const instance = new Component(componentDefinition, children);
+
+  trackHotInstance(component, instance);
 
-  return isBuiltinComponent(componentDefinition)
-    ? componentDefinition
-    : new Component(componentDefinition, children);
+  return instance;
 };
@@ -14,9 +14,24 @@
  * limitations under the License.
  */
 
-import { PaltaElementSymbol, PaltaTagElement, PaltaNode } from "./types";
+import {
+  PaltaElementSymbol,
+  PaltaTagElement,
+  PaltaNode,
+  PaltaHydrationRange,
+} from "./types";
 import { EVENT_MAP, EVENT_NAME, EventName } from "./events";
-import { getHtmlNodeGroupChildFromPaltaNode, unmountPaltaNode } from "./utils";
+import {
+  getHtmlNodeGroupChildFromPaltaNode,
+  isPaltaElement,
+  unmountPaltaNode,
+  updatePaltaNode,
+} from "./utils";
+import {
+  getHydrationRanges,
+  placeHydrationRange,
+  updateHydrationRange,
+} from "./hydration";
 import HtmlNodeGroup from "./HtmlNodeGroup";
 
 class DomElement<Tag extends keyof HTMLElementTagNameMap>
@@ -24,29 +39,55 @@ class DomElement<Tag extends keyof HTMLElementTagNameMap>
 {
   [PaltaElementSymbol]: "tag" = "tag";
 
-  private _htmlElement: HTMLElement;
+  private _htmlElement: HTMLElement | null;
   private _nodeGroup: HtmlNodeGroup;
   private _children: PaltaNode[];
 
+  // Resolves the existing node adopted by the element, either rendered on the
+  // server or cloned from a template
+  private _claim: (() => HTMLElement) | null;
+  private _hydrationRanges: PaltaHydrationRange[] | null = null;
+
   private _boundEventListeners = new Map<EventName, EventListener>();
 
-  constructor(tag: Tag, children: PaltaNode[]) {
-    this._htmlElement = document.createElement(tag);
+  constructor(
+    tag: Tag,
+    children: PaltaNode[],
+    claim: (() => HTMLElement) | null = null
+  ) {
+    this._htmlElement = claim ? null : document.createElement(tag);
+    this._claim = claim;
     this._children = children;
     this._nodeGroup = new HtmlNodeGroup();
   }
 
+  private get htmlElement(): HTMLElement {
+    if (!this._htmlElement) {
+      this._htmlElement = this._claim!();
+    }
+
+    return this._htmlElement;
+  }
+
   initialize(props: JSX.IntrinsicElements[Tag]) {
     this.clearEventListeners();
     this.setHtmlElementProps(props);
-    this._nodeGroup.appendToParent(this._htmlElement);
+
+    if (!this._claim) {
+      this._nodeGroup.appendToParent(this.htmlElement);
+    }
   }
 
   mount() {
+    if (this._claim) {
+      this.hydrateChildren();
+      return this.htmlElement;
+    }
+
     this._nodeGroup.push(
       ...this._children.map(getHtmlNodeGroupChildFromPaltaNode)
     );
-    return this._htmlElement;
+    return this.htmlElement;
   }
 
   unmount() {
@@ -64,25 +105,60 @@ class DomElement<Tag extends keyof HTMLElementTagNameMap>
 
   updateChild(index: number, value: () => PaltaNode) {
     const node = value();
+    const previous = this._children[index];
 
-    if (index < this._children.length) {
-      if (node === this._children[index]) {
-        return;
-      }
-
-      unmountPaltaNode(this._children[index]);
+    if (index < this._children.length && node === previous) {
+      return;
     }
 
     this._children[index] = node;
+
+    if (this._claim) {
+      unmountPaltaNode(previous);
+
+      const range = this.getHydrationRange(index);
+
+      if (range) {
+        updateHydrationRange(this.htmlElement, range, node);
+      }
+      return;
+    }
+
     this._nodeGroup.replaceChild(
       index,
-      getHtmlNodeGroupChildFromPaltaNode(node)
+      updatePaltaNode(previous, node, this._nodeGroup.childAt(index))
     );
   }
 
+  // The ranges are read before any child is updated, as the updates replace
+  // the server-rendered nodes
+  getHydrationRange(index: number): PaltaHydrationRange | undefined {
+    if (!this._hydrationRanges) {
+      this._hydrationRanges = getHydrationRanges(this.htmlElement.childNodes);
+    }
+
+    return this._hydrationRanges[index];
+  }
+
+  // Static text and claimed elements are already in place, the other
+  // elements replacing their server-rendered nodes
+  private hydrateChildren() {
+    this._children.forEach((child, index) => {
+      const range = this.getHydrationRange(index);
+
+      if (range && range.child === undefined && isPaltaElement(child)) {
+        placeHydrationRange(
+          this.htmlElement,
+          range,
+          getHtmlNodeGroupChildFromPaltaNode(child)
+        );
+      }
+    });
+  }
+
   private clearEventListeners() {
     for (const [key, value] of this._boundEventListeners) {
-      this._htmlElement.removeEventListener(EVENT_MAP[key], value);
+      this.htmlElement.removeEventListener(EVENT_MAP[key], value);
     }
     this._boundEventListeners.clear();
   }
@@ -97,26 +173,28 @@ class DomElement<Tag extends keyof HTMLElementTagNameMap>
         for (const [styleKey, styleValue] of Object.entries(
           value as Record<string, string>
         )) {
-          this._htmlElement.style[styleKey as any] = styleValue;
+          this.htmlElement.style[styleKey as any] = styleValue;
         }
         continue;
       } else if (key === "className") {
-        this._htmlElement.className = value;
+        this.htmlElement.className = value;
         continue;
       }
 
       if (EVENT_NAME.includes(key as string)) {
-        this._htmlElement.addEventListener(EVENT_MAP[key as EventName], value);
+        this.htmlElement.addEventListener(EVENT_MAP[key as EventName], value);
         this._boundEventListeners.set(key as EventName, value);
         continue;
       }
 
-      this._htmlElement.setAttribute(key, value as string);
+      this.htmlElement.setAttribute(key, value as string);
     }
   }
 }
 
 export const createElement = <Tag extends keyof HTMLElementTagNameMap>(
   tag: Tag,
-  children: PaltaNode[]
-): PaltaTagElement<JSX.IntrinsicElements[Tag]> => new DomElement(tag, children);
+  children: PaltaNode[],
+  claim: (() => HTMLElement) | null = null
+): PaltaTagElement<JSX.IntrinsicElements[Tag]> =>
+  new DomElement(tag, children, claim);
@@ -0,0 +1,52 @@
+/**
+ * Copyright 2024 Aubin REBILLAT
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+import { PaltaEffect } from "./types";
+import { Scheduler } from "./scheduler";
+
+export const runEffect = (
+  effect: PaltaEffect,
+  callback: () => void | (() => void),
+  deps: any[]
+) => {
+  const shouldRun =
+    effect.deps === null || effect.deps.some((dep, i) => dep !== deps[i]);
+
+  if (shouldRun) {
+    effect.cleanup?.();
+
+    const cleanup = callback();
+
+    effect.cleanup = typeof cleanup === "function" ? cleanup : null;
+  }
+
+  effect.deps = deps;
+};
+
+export const mountEffect = (
+  effect: PaltaEffect,
+  callback: () => void | (() => void),
+  deps: any[]
+) => {
+  Scheduler.get().enqueueEffect(() => runEffect(effect, callback, deps));
+};
+
+// Resetting the dependencies runs the effect again if it is reused
+export const cleanupEffect = (effect: PaltaEffect) => {
+  effect.cleanup?.();
+  effect.cleanup = null;
+  effect.deps = null;
+};
@@ -16,6 +16,7 @@
 
 import { createComponent } from "./component";
 import HtmlNodeGroup, { HtmlNodeGroupChild } from "./HtmlNodeGroup";
+import { renderComponent, SsrComponent } from "./ssr";
 import {
   PaltaBuiltinComponent,
   PaltaComponent,
@@ -77,6 +78,18 @@ class ForComponent<T extends any, K extends any>
     this.updateChildren();
   }
 
+  renderToString(props: ForInternalProps<T, K>) {
+    return props.each
+      .map(
+        (value) =>
+          renderComponent(
+            props.component as unknown as SsrComponent<T>,
+            value
+          ).value
+      )
+      .join("");
+  }
+
   updateChild(_index: number, _value: () => any) {
     console.warn("ForComponent cannot have children");
   }
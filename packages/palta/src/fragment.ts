@@ -0,0 +1,76 @@
+/**
+ * Copyright 2024 Aubin REBILLAT
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+import HtmlNodeGroup from "./HtmlNodeGroup";
+import { PaltaElementSymbol, PaltaFragmentElement, PaltaNode } from "./types";
+import {
+  getHtmlNodeGroupChildFromPaltaNode,
+  unmountPaltaNode,
+  updatePaltaNode,
+} from "./utils";
+
+class Fragment implements PaltaFragmentElement {
+  [PaltaElementSymbol]: "fragment" = "fragment";
+
+  readonly slot?: string;
+
+  private _children: PaltaNode[];
+  private _nodeGroup: HtmlNodeGroup = new HtmlNodeGroup();
+
+  constructor(children: PaltaNode[], slot?: string) {
+    this._children = children;
+    this.slot = slot;
+  }
+
+  mount() {
+    this._nodeGroup.push(
+      ...this._children.map(getHtmlNodeGroupChildFromPaltaNode)
+    );
+
+    return this._nodeGroup;
+  }
+
+  unmount() {
+    for (const child of this._children) {
+      unmountPaltaNode(child);
+    }
+
+    this._nodeGroup.clear();
+  }
+
+  updateChild(index: number, value: () => PaltaNode) {
+    const node = value();
+    const previous = this._children[index];
+
+    if (index < this._children.length && node === previous) {
+      return;
+    }
+
+    this._children[index] = node;
+    this._nodeGroup.replaceChild(
+      index,
+      updatePaltaNode(previous, node, this._nodeGroup.childAt(index))
+    );
+  }
+}
+
+export const createFragment = (children: PaltaNode[]): PaltaFragmentElement =>
+  new Fragment(children);
+
+export const createSlot = (
+  name: string,
+  children: PaltaNode[]
+): PaltaFragmentElement => new Fragment(children, name);
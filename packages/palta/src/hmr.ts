@@ -0,0 +1,80 @@
+/**
+ * Copyright 2024 Aubin REBILLAT
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+import { PaltaComponent, PaltaComponentDefinition } from "./types";
+import { Scheduler } from "./scheduler";
+import { isBuiltinComponent } from "./utils";
+
+const PaltaHotSymbol = Symbol();
+
+export interface PaltaHotInstance {
+  replace: (definition: PaltaComponentDefinition) => void;
+}
+
+type HotRecord = {
+  component: PaltaComponent;
+  instances: Set<PaltaHotInstance>;
+  outdated: boolean;
+};
+
+type HotComponent = PaltaComponent & { [PaltaHotSymbol]?: HotRecord };
+
+const records = new Map<string, HotRecord>();
+
+// Every version of a component is attached to the same record, so instances
+// created from a stale module still use the latest implementation
+export const registerHotComponent = (component: PaltaComponent, id: string) => {
+  let record = records.get(id);
+
+  if (record) {
+    record.component = component;
+    record.outdated = true;
+  } else {
+    record = { component, instances: new Set(), outdated: false };
+    records.set(id, record);
+  }
+
+  (component as HotComponent)[PaltaHotSymbol] = record;
+};
+
+export const resolveHotComponent = (component: PaltaComponent) =>
+  (component as HotComponent)[PaltaHotSymbol]?.component ?? component;
+
+export const trackHotInstance = (
+  component: PaltaComponent,
+  instance: PaltaHotInstance
+) => {
+  (component as HotComponent)[PaltaHotSymbol]?.instances.add(instance);
+};
+
+export const reloadHotComponents = () => {
+  for (const record of records.values()) {
+    if (!record.outdated) {
+      continue;
+    }
+
+    record.outdated = false;
+    record.instances.forEach((instance) =>
+      Scheduler.get().enqueueUpdate(() => {
+        const definition = record.component();
+
+        if (!isBuiltinComponent(definition)) {
+          instance.replace(definition);
+        }
+      })
+    );
+  }
+};
@@ -0,0 +1,196 @@
+/**
+ * Copyright 2024 Aubin REBILLAT
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+import HtmlNodeGroup, { HtmlNodeGroupChild } from "./HtmlNodeGroup";
+import { createComponent } from "./component";
+import { createElement } from "./dom-element";
+import {
+  PaltaComponent,
+  PaltaHydrationRange,
+  PaltaNode,
+  PaltaTagElement,
+} from "./types";
+import { getHtmlNodeGroupChildFromPaltaNode } from "./utils";
+
+export type HydrationParent = (() => PaltaTagElement) | null;
+
+// Server-rendered nodes the root elements of the component being created are
+// claimed from
+let currentAnchor: (() => ChildNode[]) | null = null;
+
+const isMarker = (node: ChildNode, value: string): node is Comment =>
+  node.nodeType === Node.COMMENT_NODE && (node as Comment).data === value;
+
+const isWhitespace = (node: ChildNode) =>
+  node.nodeType === Node.TEXT_NODE && !node.textContent?.trim();
+
+// Groups server-rendered nodes by the position of the child they render:
+// static text and elements are a single node, while dynamic children are all
+// the nodes between their markers
+export const getHydrationRanges = (
+  nodes: ArrayLike<ChildNode>
+): PaltaHydrationRange[] => {
+  const ranges: PaltaHydrationRange[] = [];
+  let range: PaltaHydrationRange | null = null;
+  let depth = 0;
+
+  for (const node of Array.from(nodes)) {
+    if (range) {
+      if (isMarker(node, "[")) {
+        depth++;
+      } else if (isMarker(node, "]") && depth-- === 0) {
+        range.end = node;
+        ranges.push(range);
+        range = null;
+        continue;
+      }
+
+      range.nodes.push(node);
+    } else if (isMarker(node, "[")) {
+      range = { nodes: [], end: null };
+    } else if (!isWhitespace(node)) {
+      ranges.push({ nodes: [node], end: null });
+    }
+  }
+
+  return ranges;
+};
+
+const getNodes = (child: HtmlNodeGroupChild) =>
+  child instanceof HtmlNodeGroup ? child.nodes : child ? [child] : [];
+
+const removeHydrationRange = (range: PaltaHydrationRange) => {
+  if (range.child instanceof HtmlNodeGroup) {
+    range.child.removeFromNode();
+  } else {
+    range.child?.remove();
+  }
+
+  for (const node of range.nodes) {
+    node.remove();
+  }
+
+  range.nodes = [];
+};
+
+// Renders a child in place of the server-rendered nodes of its range, unless
+// they were claimed by the child
+export const placeHydrationRange = (
+  parent: Node,
+  range: PaltaHydrationRange,
+  child: HtmlNodeGroupChild
+) => {
+  const nodes = getNodes(child);
+
+  range.child = child;
+
+  if (nodes.length > 0 && nodes.every((node) => range.nodes.includes(node))) {
+    return;
+  }
+
+  const before = range.end ?? range.nodes[range.nodes.length - 1]?.nextSibling ?? null;
+
+  removeHydrationRange(range);
+
+  if (child instanceof HtmlNodeGroup) {
+    child.appendToParent(parent, before);
+  } else if (child) {
+    parent.insertBefore(child, before);
+    range.nodes = [child];
+  }
+};
+
+// Text interpolations reuse their server-rendered text node
+export const updateHydrationRange = (
+  parent: Node,
+  range: PaltaHydrationRange,
+  node: PaltaNode
+) => {
+  const current = range.child ?? range.nodes[0];
+  const isText = typeof node === "string" || typeof node === "number";
+
+  if (
+    isText &&
+    current instanceof Text &&
+    (range.child !== undefined || range.nodes.length === 1)
+  ) {
+    current.data = node.toString();
+    range.child = current;
+    return;
+  }
+
+  placeHydrationRange(parent, range, getHtmlNodeGroupChildFromPaltaNode(node));
+};
+
+const getClaimedRange = (parent: HydrationParent, position: number) => {
+  const anchor = currentAnchor;
+
+  return () =>
+    parent
+      ? parent().getHydrationRange?.(position)
+      : anchor && getHydrationRanges(anchor())[position];
+};
+
+export const withHydrationAnchor = <T>(
+  anchor: () => ChildNode[],
+  create: () => T
+): T => {
+  const previous = currentAnchor;
+
+  currentAnchor = anchor;
+
+  try {
+    return create();
+  } finally {
+    currentAnchor = previous;
+  }
+};
+
+// Elements are claimed when first used, as their parent is declared after them
+export const claimElement = <Tag extends keyof HTMLElementTagNameMap>(
+  parent: HydrationParent,
+  position: number,
+  tag: Tag,
+  children: PaltaNode[]
+) => {
+  const range = getClaimedRange(parent, position);
+
+  return createElement(tag, children, () => {
+    const node = range()?.nodes[0];
+
+    if (!(node instanceof HTMLElement) || node.localName !== tag) {
+      throw new Error(
+        `Hydration mismatch: expected <${tag}> at position ${position}`
+      );
+    }
+
+    return node;
+  });
+};
+
+export const claimComponent = <P extends any>(
+  parent: HydrationParent,
+  position: number,
+  component: PaltaComponent<P>,
+  children: PaltaNode[]
+) => {
+  const range = getClaimedRange(parent, position);
+
+  return withHydrationAnchor(
+    () => range()?.nodes ?? [],
+    () => createComponent(component, children)
+  );
+};
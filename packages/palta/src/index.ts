@@ -25,7 +25,41 @@ import {
 import { createComponent as _createComponent } from "./component";
 import { createElement as _createElement } from "./dom-element";
 import { createChildren as _createChildren } from "./children";
+import { createRenderProp as _createRenderProp } from "./render-prop";
+import {
+  createFragment as _createFragment,
+  createSlot as _createSlot,
+} from "./fragment";
+import { createBranch as _createBranch } from "./branch";
+import {
+  runEffect as _runEffect,
+  mountEffect as _mountEffect,
+  cleanupEffect as _cleanupEffect,
+} from "./effect";
+import { createList as _createList } from "./list";
 import { For as _For } from "./for";
+import {
+  registerHotComponent as _registerHotComponent,
+  reloadHotComponents as _reloadHotComponents,
+} from "./hmr";
+import {
+  claimElement as _claimElement,
+  claimComponent as _claimComponent,
+  withHydrationAnchor,
+} from "./hydration";
+import {
+  createTemplate as _createTemplate,
+  cloneTemplate as _cloneTemplate,
+} from "./template";
+import {
+  html as _html,
+  attribute as _attribute,
+  attributes as _attributes,
+  fallback as _fallback,
+  renderChildren as _renderChildren,
+  renderComponent as _renderComponent,
+  renderToString as _renderToString,
+} from "./ssr";
 
 import { Scheduler } from "./scheduler";
 import HtmlNodeGroup from "./HtmlNodeGroup";
@@ -36,12 +70,34 @@ namespace Palta {
   export type ComponentDefinition<T = any> = PaltaComponentDefinition<T>;
   export type Component<T = any> = PaltaComponent<T>;
 
+  // Marks a component for the compiler, which replaces the call by the
+  // compiled component
+  export const component = <P = any>(
+    component: (props: P) => JSX.Element
+  ): ((props: P) => JSX.Element) => component;
+
   export const createComponent = _createComponent;
 
   export const createElement = _createElement;
 
   export const createChildren = _createChildren;
 
+  export const createFragment = _createFragment;
+  export const createSlot = _createSlot;
+  export const createRenderProp = _createRenderProp;
+
+  export const createBranch = _createBranch;
+
+  export const createList = _createList;
+
+  export const createTemplate = _createTemplate;
+
+  export const cloneTemplate = _cloneTemplate;
+
+  export const claimElement = _claimElement;
+
+  export const claimComponent = _claimComponent;
+
   export const render = (selector: string, component: () => JSX.Element) => {
     const root = document.querySelector(selector);
 
@@ -59,38 +115,69 @@ namespace Palta {
 
     instance.initialize({});
     rootGroup.push(instance.mount());
-    instance.updateProps({});
 
     rootGroup.appendToParent(root);
 
+    Scheduler.get().flushEffects();
     Scheduler.get().start();
   };
 
+  // Adopts the nodes rendered on the server with `renderToString` from the
+  // same component, rendering it again when they were not claimed
+  export const hydrate = (selector: string, component: () => JSX.Element) => {
+    const root = document.querySelector(selector);
+
+    if (!root) {
+      throw new Error(`Element with selector "${selector}" not found`);
+    }
+
+    Scheduler.init();
+
+    const instance = withHydrationAnchor(
+      () => Array.from(root.childNodes),
+      () => createComponent(component as unknown as Palta.Component, [])
+    ) as PaltaComponentElement;
+    const rootGroup = new HtmlNodeGroup();
+
+    instance.initialize({});
+    rootGroup.push(instance.mount());
+
+    if (!rootGroup.nodes.every((node) => node.parentNode === root)) {
+      root.replaceChildren();
+      rootGroup.appendToParent(root);
+    }
+
+    Scheduler.get().flushEffects();
+    Scheduler.get().start();
+  };
+
+  export const html = _html;
+
+  export const attribute = _attribute;
+
+  export const attributes = _attributes;
+
+  export const fallback = _fallback;
+
+  export const renderChildren = _renderChildren;
+
+  export const renderComponent = _renderComponent;
+
+  export const renderToString = _renderToString;
+
+  export const registerHotComponent = _registerHotComponent;
+
+  export const reloadHotComponents = _reloadHotComponents;
+
   export const componentUpdate = (fn: () => void) => {
     Scheduler.get().enqueueUpdate(fn);
   };
 
-  export const runEffect = (
-    effect: {
-      deps: null | any[];
-    },
-    callback: () => void,
-    cleanup: (() => void) | null,
-    deps: any[]
-  ) => {
-    const shouldRun =
-      effect.deps === null || effect.deps.some((dep, i) => dep !== deps[i]);
-
-    if (shouldRun) {
-      if (cleanup) {
-        cleanup();
-      }
-
-      callback();
-    }
+  export const runEffect = _runEffect;
 
-    effect.deps = deps;
-  };
+  export const mountEffect = _mountEffect;
+
+  export const cleanupEffect = _cleanupEffect;
 }
 
 declare namespace Palta {
@@ -104,9 +191,21 @@ export const $state = <T = any>(value: T): [T, Palta.StateUpdater<T>] => {
   return [value, (_: Palta.StateUpdaterValue<T>) => {}];
 };
 
-export const $effect = (_callback: () => void, _deps: any[]) => {};
+export const $effect = (
+  _callback: () => void | (() => void),
+  _deps: any[]
+) => {};
+
+export const $children = (..._args: any[]): JSX.Element =>
+  ({} as JSX.Element);
+
+export const Children = (_props: { name?: string }): JSX.Element =>
+  ({} as JSX.Element);
 
-export const Children = (): JSX.Element => ({} as JSX.Element);
+export const Slot = (_props: {
+  name: string;
+  children?: Palta.Node;
+}): JSX.Element => ({} as JSX.Element);
 
 export const For = _For;
 
@@ -0,0 +1,76 @@
+/**
+ * Copyright 2024 Aubin REBILLAT
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+import { createComponent } from "./component";
+import {
+  PaltaBuiltinComponent,
+  PaltaComponent,
+  PaltaComponentElement,
+  PaltaList,
+} from "./types";
+
+class List<P extends any[]> implements PaltaList<P> {
+  private _component: PaltaComponent<P>;
+  private _elements = new Map<
+    any,
+    PaltaComponentElement<P> | PaltaBuiltinComponent<P>
+  >();
+  private _rendered = new Set<any>();
+  private _position = 0;
+
+  constructor(component: PaltaComponent<P>) {
+    this._component = component;
+  }
+
+  collect<T>(render: () => T): T {
+    this._rendered.clear();
+    this._position = 0;
+
+    const result = render();
+
+    // Elements which are not rendered anymore are forgotten, the parent
+    // unmounting them when it removes their nodes
+    for (const key of this._elements.keys()) {
+      if (!this._rendered.has(key)) {
+        this._elements.delete(key);
+      }
+    }
+
+    return result;
+  }
+
+  // Elements without a key attribute are identified by their position
+  render(props: P, key: any = this._position) {
+    this._position++;
+    this._rendered.add(key);
+
+    let element = this._elements.get(key);
+
+    if (element) {
+      element.updateProps(props);
+    } else {
+      element = createComponent(this._component, []);
+      element.initialize(props);
+      this._elements.set(key, element);
+    }
+
+    return element;
+  }
+}
+
+export const createList = <P extends any[]>(
+  component: PaltaComponent<P>
+): PaltaList<P> => new List(component);
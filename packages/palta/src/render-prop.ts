@@ -0,0 +1,43 @@
+/**
+ * Copyright 2024 Aubin REBILLAT
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+import { createComponent } from "./component";
+import {
+  PaltaComponent,
+  PaltaElement,
+  PaltaRenderProp,
+  PaltaRenderPropSymbol,
+} from "./types";
+
+// Called directly, a render prop creates a new element on each call, while
+// `$children` keeps the elements of its previous calls.
+export const createRenderProp = <A extends any[]>(
+  component: PaltaComponent<any[]>,
+  props: (...args: A) => any[]
+): PaltaRenderProp<A> => {
+  const render = (...args: A): PaltaElement => {
+    const element = createComponent(component, []);
+
+    element.initialize(props(...args));
+    return element;
+  };
+
+  return Object.assign(render, {
+    [PaltaRenderPropSymbol]: true as const,
+    component,
+    props,
+  });
+};
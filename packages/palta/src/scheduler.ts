@@ -18,6 +18,7 @@ export class Scheduler {
   private static instance: Scheduler;
 
   private tasks: Array<() => void> = [];
+  private effects: Array<() => void> = [];
 
   private constructor() {}
 
@@ -41,11 +42,30 @@ export class Scheduler {
     this.tasks.push(task);
   }
 
+  enqueueEffect(effect: () => void) {
+    this.effects.push(effect);
+  }
+
+  // Runs the effects of components attached since the last flush, in the
+  // order they were enqueued. A component enqueues its effects before
+  // initializing the elements it creates, so parents run before their
+  // children, unlike React. Effects only run once the whole tree is attached,
+  // so a parent still sees the nodes of its children. Components created by
+  // an update, in a branch or a list, run theirs once the whole update has
+  // been applied.
+  flushEffects() {
+    const effects = this.effects;
+
+    this.effects = [];
+    effects.forEach((effect) => effect());
+  }
+
   processNextUpdate() {
     const task = this.tasks.shift();
 
     if (task) {
       task();
+      this.flushEffects();
     }
 
     requestAnimationFrame(() => {
@@ -0,0 +1,142 @@
+/**
+ * Copyright 2024 Aubin REBILLAT
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+import { EVENT_NAME } from "./events";
+import { PaltaBuiltinComponent, PaltaElementSymbol } from "./types";
+
+// Markup produced by the compiler, which is never escaped again
+export class SafeHtml {
+  constructor(readonly value: string) {}
+
+  toString() {
+    return this.value;
+  }
+}
+
+export type SsrComponent<P = any> = (
+  props: P,
+  children: SafeHtml,
+  slots: Record<string, any>
+) => SafeHtml | PaltaBuiltinComponent<P>;
+
+const HTML_ESCAPES: Record<string, string> = {
+  "&": "&amp;",
+  "<": "&lt;",
+  ">": "&gt;",
+  '"': "&quot;",
+  "'": "&#39;",
+};
+
+export const escapeHtml = (value: string) =>
+  value.replace(/[&<>"']/g, (char) => HTML_ESCAPES[char]);
+
+const isBuiltin = (value: any): value is PaltaBuiltinComponent =>
+  !!value &&
+  typeof value === "object" &&
+  value[PaltaElementSymbol] === "builtin";
+
+const renderNode = (node: any): string => {
+  if (node instanceof SafeHtml) {
+    return node.value;
+  } else if (
+    typeof node === "string" ||
+    typeof node === "number" ||
+    node === true
+  ) {
+    return escapeHtml(node.toString());
+  } else if (!!node && typeof node === "object" && Symbol.iterator in node) {
+    return Array.from(node as Iterable<any>, renderNode).join("");
+  }
+
+  return "";
+};
+
+export const html = (strings: TemplateStringsArray, ...values: any[]) =>
+  new SafeHtml(
+    strings.reduce(
+      (result, string, i) => result + renderNode(values[i - 1]) + string
+    )
+  );
+
+// Content nested in `<Children>`, rendered when the slot is given nothing
+export const fallback = (value: any, content: SafeHtml) => {
+  const rendered = renderNode(value);
+
+  return rendered === "" ? content : new SafeHtml(rendered);
+};
+
+const toKebabCase = (value: string) =>
+  value.replace(/[A-Z]/g, (char) => `-${char.toLowerCase()}`);
+
+export const attribute = (name: string, value: any) => {
+  if (
+    EVENT_NAME.includes(name) ||
+    value === null ||
+    value === undefined ||
+    value === false ||
+    typeof value === "function"
+  ) {
+    return new SafeHtml("");
+  }
+
+  const key = name === "className" ? "class" : name;
+
+  if (value === true) {
+    return new SafeHtml(` ${key}`);
+  }
+
+  if (key === "style" && typeof value === "object") {
+    value = Object.entries(value)
+      .map(([styleKey, styleValue]) => `${toKebabCase(styleKey)}:${styleValue}`)
+      .join(";");
+  }
+
+  return new SafeHtml(` ${key}="${escapeHtml(String(value))}"`);
+};
+
+export const attributes = (props: Record<string, any>) =>
+  new SafeHtml(
+    Object.entries(props ?? {})
+      .filter(([key]) => key !== "key")
+      .map(([key, value]) => attribute(key, value).value)
+      .join("")
+  );
+
+export const renderComponent = <P = any>(
+  component: SsrComponent<P>,
+  props: P = {} as P,
+  children: SafeHtml = new SafeHtml(""),
+  slots: Record<string, SafeHtml> = {}
+): SafeHtml => {
+  // Named slots without a `<Slot>` child are filled by the prop of that name
+  const result = component(props, children, { ...props, ...slots });
+
+  if (isBuiltin(result)) {
+    return new SafeHtml(result.renderToString?.(props) ?? "");
+  }
+
+  return result;
+};
+
+// Render props given as children are passed with the slots
+export const renderChildren = (slots: Record<string, any>, ...args: any[]) =>
+  slots.$children?.(...args);
+
+export const renderToString = <P = any>(
+  component: () => JSX.Element,
+  props?: P
+) =>
+  renderComponent(component as unknown as SsrComponent<P>, props).toString();
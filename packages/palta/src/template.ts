@@ -0,0 +1,43 @@
+/**
+ * Copyright 2024 Aubin REBILLAT
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+import { createElement } from "./dom-element";
+import { PaltaNode } from "./types";
+
+export type PaltaTemplate = () => HTMLElement;
+
+// Static markup of a component, parsed on first use and cloned for each
+// instance
+export const createTemplate = (html: string): PaltaTemplate => {
+  let template: HTMLTemplateElement | null = null;
+
+  return () => {
+    if (!template) {
+      template = document.createElement("template");
+      template.innerHTML = html;
+    }
+
+    return template.content.firstChild!.cloneNode(true) as HTMLElement;
+  };
+};
+
+// The elements of the clone which change between renders are claimed with
+// `claimElement`, from their parent element
+export const cloneTemplate = <Tag extends keyof HTMLElementTagNameMap>(
+  template: PaltaTemplate,
+  tag: Tag,
+  children: PaltaNode[]
+) => createElement(tag, children, template);
@@ -18,11 +18,14 @@ import { HtmlNodeGroupChild } from "./HtmlNodeGroup";
 
 export const PaltaElementSymbol = Symbol();
 
+export const PaltaRenderPropSymbol = Symbol();
+
 export interface PaltaElement {
   [PaltaElementSymbol]:
     | "tag"
     | "component"
     | "children"
+    | "branch"
     | "fragment"
     | "builtin";
   mount: () => HtmlNodeGroupChild;
@@ -34,6 +37,16 @@ export interface PaltaTagElement<P = any> extends PaltaElement {
   initialize: (props: P) => void;
   updateProps: (props: P) => void;
   updateChild: (index: number, value: () => any) => void;
+  getHydrationRange?: (index: number) => PaltaHydrationRange | undefined;
+}
+
+// Server-rendered nodes of a child, the dynamic ones being rendered between
+// `<!--[-->` and `<!--]-->` markers
+export interface PaltaHydrationRange {
+  nodes: ChildNode[];
+  end: Comment | null;
+  // Node rendered by the client in place of the server-rendered ones
+  child?: HtmlNodeGroupChild;
 }
 
 export interface PaltaComponentElement<P = any> extends PaltaElement {
@@ -47,12 +60,47 @@ export interface PaltaChildrenElement extends PaltaElement {
   [PaltaElementSymbol]: "children";
   setNodes: (nodes: PaltaNode[]) => void;
   updateChild: (index: number, value: () => any) => void;
+  collect: <T>(site: number, render: () => T) => T;
+  render: (site: number, ...args: any[]) => PaltaElement | null;
+}
+
+// A JSX returning callback given to a component, compiled into a component
+// created with the props built from the callback arguments
+export type PaltaRenderProp<A extends any[] = any[]> = ((
+  ...args: A
+) => PaltaElement) & {
+  [PaltaRenderPropSymbol]: true;
+  component: PaltaComponent<any[]>;
+  props: (...args: A) => any[];
+};
+
+export interface PaltaFragmentElement extends PaltaElement {
+  [PaltaElementSymbol]: "fragment";
+  // Name of the component slot filled by the fragment, if any
+  slot?: string;
+  updateChild: (index: number, value: () => any) => void;
+}
+
+export interface PaltaBranchElement extends PaltaElement {
+  [PaltaElementSymbol]: "branch";
+  updateBranch: (
+    index: number,
+    initialize?: () => void,
+    update?: () => void,
+    effects?: PaltaEffect[]
+  ) => void;
+}
+
+export interface PaltaList<P extends any[] = any[]> {
+  collect: <T>(render: () => T) => T;
+  render: (props: P, key?: any) => PaltaElement;
 }
 
 export interface PaltaBuiltinComponent<P = any> extends PaltaElement {
   [PaltaElementSymbol]: "builtin";
   initialize: (props: P) => void;
   updateProps: (props: P) => void;
+  renderToString?: (props: P) => string;
 }
 
 export type PaltaNode =
@@ -66,10 +114,25 @@ export type PaltaNode =
   | undefined;
 
 export type PaltaComponentDefinition<P = any> = {
-  childrenElement: PaltaChildrenElement | null;
+  slots: Record<string, PaltaChildrenElement>;
   initialize: (props: P) => void;
   update: (props: P) => void;
   getRoot: () => PaltaElement;
+  unmount?: () => void;
+  hot?: PaltaHotState;
+};
+
+// State of a component compiled for hot module replacement, the shape
+// listing its `$state` variables
+export type PaltaHotState = {
+  shape: string;
+  save: () => any[];
+  restore: (state: any[]) => void;
+};
+
+export type PaltaEffect = {
+  deps: null | any[];
+  cleanup: null | (() => void);
 };
 
 export type PaltaComponent<T = any> = () =>
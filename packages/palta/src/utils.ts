@@ -17,12 +17,15 @@
 import HtmlNodeGroup, { HtmlNodeGroupChild } from "./HtmlNodeGroup";
 import {
   PaltaElementSymbol,
+  PaltaRenderProp,
+  PaltaRenderPropSymbol,
   PaltaNode,
   PaltaElement,
   PaltaTagElement,
   PaltaComponentElement,
   PaltaComponentDefinition,
   PaltaBuiltinComponent,
+  PaltaFragmentElement,
 } from "./types";
 
 export const isPaltaElement = (node: PaltaNode): node is PaltaElement =>
@@ -36,9 +39,32 @@ export const isComponentElement = (
 ): node is PaltaComponentElement =>
   isPaltaElement(node) && node[PaltaElementSymbol] === "component";
 
+export const isSlotElement = (
+  node: PaltaNode
+): node is PaltaFragmentElement & { slot: string } =>
+  isPaltaElement(node) &&
+  node[PaltaElementSymbol] === "fragment" &&
+  (node as PaltaFragmentElement).slot !== undefined;
+
+export const isRenderProp = (value: any): value is PaltaRenderProp =>
+  typeof value === "function" && PaltaRenderPropSymbol in value;
+
 export const isIterable = (node: PaltaNode): node is Iterable<PaltaNode> =>
   !!node && typeof node === "object" && Symbol.iterator in node;
 
+// Nodes rendering nothing, which leave a slot to its fallback content
+export const isEmptyPaltaNode = (node: PaltaNode): boolean => {
+  if (node === null || node === undefined || node === false || node === "") {
+    return true;
+  }
+
+  return (
+    !isPaltaElement(node) &&
+    isIterable(node) &&
+    Array.from(node).every(isEmptyPaltaNode)
+  );
+};
+
 export const isBuiltinComponent = <T extends any>(
   component: PaltaComponentDefinition<T> | PaltaBuiltinComponent<T>
 ): component is PaltaBuiltinComponent<T> =>
@@ -71,3 +97,43 @@ export const unmountPaltaNode = (node: PaltaNode) => {
     }
   }
 };
+
+// The elements of a list are kept between its renders, so the ones rendered
+// again keep their mounted nodes instead of being unmounted and mounted again
+export const updatePaltaNode = (
+  previous: PaltaNode,
+  node: PaltaNode,
+  mounted: HtmlNodeGroupChild
+): HtmlNodeGroupChild => {
+  if (
+    !(mounted instanceof HtmlNodeGroup) ||
+    isPaltaElement(previous) ||
+    !isIterable(previous) ||
+    isPaltaElement(node) ||
+    !isIterable(node)
+  ) {
+    unmountPaltaNode(previous);
+    return getHtmlNodeGroupChildFromPaltaNode(node);
+  }
+
+  const items = Array.from(node);
+  const kept = new Map<PaltaNode, HtmlNodeGroupChild>();
+
+  Array.from(previous).forEach((item, index) => {
+    if (isPaltaElement(item) && items.includes(item)) {
+      kept.set(item, mounted.childAt(index));
+    } else {
+      unmountPaltaNode(item);
+    }
+  });
+
+  mounted.setChildren(
+    items.map((item) =>
+      kept.has(item)
+        ? kept.get(item)!
+        : getHtmlNodeGroupChildFromPaltaNode(item)
+    )
+  );
+
+  return mounted;
+};
@@ -0,0 +1,63 @@
+// @vitest-environment jsdom
+import { describe, expect, it, vi } from "vitest";
+
+import { createChildren } from "../src/children";
+import { createComponent } from "../src/component";
+import { createElement } from "../src/dom-element";
+import HtmlNodeGroup from "../src/HtmlNodeGroup";
+
+const createContent = (text: string, unmount: () => void) =>
+  createComponent(() => {
+    const element = createElement("p", [text]);
+
+    return {
+      slots: {},
+      initialize: () => element.initialize({}),
+      update: () => {},
+      getRoot: () => element,
+      unmount,
+    };
+  }, []);
+
+const mountChildren = (fallback?: () => string) => {
+  const children = createChildren(fallback);
+  const container = document.createElement("div");
+  const group = new HtmlNodeGroup();
+
+  group.push(children.mount());
+  group.appendToParent(container);
+
+  return { children, container };
+};
+
+describe("children", () => {
+  it("unmount the content they replace", () => {
+    const { children, container } = mountChildren();
+    const unmount = vi.fn();
+    const previous = createContent("previous", unmount);
+    const next = createContent("next", () => {});
+
+    previous.initialize({});
+    next.initialize({});
+    children.updateChild(0, () => previous);
+    children.updateChild(0, () => next);
+
+    expect(unmount).toHaveBeenCalledOnce();
+    expect(container.textContent).toBe("next");
+  });
+
+  it("replace the content of children added after their fallback", () => {
+    const { children, container } = mountChildren(() => "fallback");
+
+    expect(container.textContent).toBe("fallback");
+
+    children.updateChild(1, () => "previous");
+    children.updateChild(1, () => "next");
+
+    expect(container.textContent).toBe("next");
+
+    children.updateChild(1, () => null);
+
+    expect(container.textContent).toBe("fallback");
+  });
+});
@@ -0,0 +1,130 @@
+// @vitest-environment jsdom
+import { beforeEach, describe, expect, it, vi } from "vitest";
+
+import { createBranch } from "../src/branch";
+import { createComponent } from "../src/component";
+import { createElement } from "../src/dom-element";
+import { mountEffect, runEffect } from "../src/effect";
+import { createFragment } from "../src/fragment";
+import { createList } from "../src/list";
+import HtmlNodeGroup from "../src/HtmlNodeGroup";
+import { Scheduler } from "../src/scheduler";
+import { PaltaEffect } from "../src/types";
+
+const createEffect = (): PaltaEffect => ({ deps: null, cleanup: null });
+
+describe("effects", () => {
+  beforeEach(() => {
+    vi.spyOn(console, "warn").mockImplementation(() => {});
+    Scheduler.init();
+  });
+
+  it("run parents first once the whole tree is attached", () => {
+    const order: string[] = [];
+    const container = document.createElement("div");
+    const attached = () => container.textContent === "child";
+
+    const child = createComponent(() => {
+      const element = createElement("span", ["child"]);
+
+      return {
+        slots: {},
+        initialize: () => {
+          mountEffect(
+            createEffect(),
+            () => void order.push(`child ${attached()}`),
+            []
+          );
+          element.initialize({});
+        },
+        update: () => {},
+        getRoot: () => element,
+      };
+    }, []);
+
+    const parent = createComponent(() => {
+      const element = createElement("div", [child]);
+
+      return {
+        slots: {},
+        initialize: () => {
+          mountEffect(
+            createEffect(),
+            () => void order.push(`parent ${attached()}`),
+            []
+          );
+          child.initialize({});
+          element.initialize({});
+        },
+        update: () => {},
+        getRoot: () => element,
+      };
+    }, []);
+
+    const group = new HtmlNodeGroup();
+
+    parent.initialize({});
+    group.push(parent.mount());
+    group.appendToParent(container);
+
+    expect(order).toEqual([]);
+
+    Scheduler.get().flushEffects();
+
+    expect(order).toEqual(["parent true", "child true"]);
+  });
+
+  it("are cleaned up when their branch is deactivated", () => {
+    const pending = createEffect();
+    const shared = createEffect();
+    const pendingCleanup = vi.fn();
+    const sharedCleanup = vi.fn();
+    const branch = createBranch([() => null, () => null]);
+
+    branch.updateBranch(0, undefined, undefined, [pending, shared]);
+    runEffect(pending, () => pendingCleanup, []);
+    runEffect(shared, () => sharedCleanup, []);
+
+    branch.updateBranch(1, undefined, undefined, [shared]);
+
+    expect(pendingCleanup).toHaveBeenCalledOnce();
+    expect(pending.deps).toBeNull();
+    expect(sharedCleanup).not.toHaveBeenCalled();
+  });
+
+  it("run again when their branch is activated again", () => {
+    const effect = createEffect();
+    const callback = vi.fn();
+    const branch = createBranch([() => null, () => null]);
+
+    branch.updateBranch(0, undefined, undefined, [effect]);
+    runEffect(effect, callback, []);
+    branch.updateBranch(1);
+    branch.updateBranch(0, undefined, undefined, [effect]);
+    runEffect(effect, callback, []);
+
+    expect(callback).toHaveBeenCalledTimes(2);
+  });
+
+  it("are cleaned up when their list item is removed", () => {
+    const unmount = vi.fn();
+    const list = createList<[number]>(() => ({
+      slots: {},
+      initialize: () => {},
+      update: () => {},
+      getRoot: () => createFragment([]),
+      unmount,
+    }));
+
+    const parent = createFragment([""]);
+
+    parent.updateChild(0, () =>
+      list.collect(() => [1, 2].map((item) => list.render([item], item)))
+    );
+    parent.updateChild(0, () =>
+      list.collect(() => [1].map((item) => list.render([item], item)))
+    );
+
+    expect(unmount).toHaveBeenCalledOnce();
+  });
+});
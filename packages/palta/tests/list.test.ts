@@ -0,0 +1,50 @@
+// @vitest-environment jsdom
+import { describe, expect, it, vi } from "vitest";
+
+import { createElement } from "../src/dom-element";
+import { createList } from "../src/list";
+import { Scheduler } from "../src/scheduler";
+
+const createItems = (unmount: () => void) => {
+  const list = createList<[string]>(() => {
+    const element = createElement("li", []);
+
+    return {
+      slots: {},
+      initialize: ([name]) => element.initialize({ className: name }),
+      update: ([name]) => element.updateProps({ className: name }),
+      getRoot: () => element,
+      unmount,
+    };
+  });
+
+  return (names: string[]) => () =>
+    list.collect(() => names.map((name) => list.render([name], name)));
+};
+
+describe("lists", () => {
+  it("keep the nodes of the items rendered again", () => {
+    Scheduler.init();
+
+    const unmount = vi.fn();
+    const items = createItems(unmount);
+    const element = createElement("ul", [""]);
+    const container = document.createElement("div");
+
+    element.initialize({});
+    container.appendChild(element.mount());
+    element.updateChild(0, items(["a", "b", "c"]));
+
+    const ul = container.firstChild!;
+    const [a, , c] = Array.from(ul.childNodes);
+
+    element.updateChild(0, items(["c", "a"]));
+
+    expect(
+      Array.from(ul.childNodes, (node) => (node as Element).className)
+    ).toEqual(["c", "a"]);
+    expect(ul.childNodes[0]).toBe(c);
+    expect(ul.childNodes[1]).toBe(a);
+    expect(unmount).toHaveBeenCalledOnce();
+  });
+});
@@ -0,0 +1,88 @@
+/**
+ * Copyright 2024 Aubin REBILLAT
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields, default)]
+pub struct Config {
+    // Module the runtime and the `$state`, `$effect` and `Children` helpers
+    // are imported from
+    pub import_source: String,
+    // Line comment text marking a declaration as a component
+    pub marker: String,
+    // Line comment text excluding a declaration from the detection
+    pub ignore_marker: String,
+    pub detection: Detection,
+    pub target: Target,
+    // Registers compiled components for hot module replacement, which only
+    // applies to the DOM target
+    pub hmr: bool,
+    // Adopts the server-rendered nodes instead of creating them, which only
+    // applies to the DOM target
+    pub hydrate: bool,
+}
+
+#[derive(Debug, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Detection {
+    // Only declarations with the marker comment are components
+    #[default]
+    Marker,
+    // Capitalized functions returning JSX are components as well
+    Convention,
+}
+
+#[derive(Debug, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Target {
+    // Components create and update DOM elements
+    #[default]
+    Dom,
+    // Components render an HTML string on the server
+    Ssr,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            import_source: "palta".into(),
+            marker: "@Palta.component".into(),
+            ignore_marker: "@Palta.ignore".into(),
+            detection: Detection::default(),
+            target: Target::default(),
+            hmr: false,
+            hydrate: false,
+        }
+    }
+}
+
+impl Config {
+    pub fn hot(&self) -> bool {
+        self.hmr && self.target == Target::Dom
+    }
+
+    pub fn hydrating(&self) -> bool {
+        self.hydrate && self.target == Target::Dom
+    }
+
+    pub fn parse(config: Option<&str>) -> Result<Self, String> {
+        match config.map(str::trim) {
+            None | Some("") => Ok(Config::default()),
+            Some(config) => serde_json::from_str(config)
+                .map_err(|error| format!("Invalid swc-plugin-palta configuration: {}", error)),
+        }
+    }
+}
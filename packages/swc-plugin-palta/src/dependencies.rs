@@ -0,0 +1,290 @@
+/**
+ * Copyright 2024 Aubin REBILLAT
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+use std::collections::HashSet;
+use std::ops::Deref;
+
+use swc_core::ecma::ast::{
+    AssignExpr, AssignTarget, BindingIdent, BlockStmtOrExpr, CallExpr, Callee, ClassDecl, Decl,
+    Expr, ExprStmt, FnDecl, Id, Ident, JSXElementName, Pat, SimpleAssignTarget, Stmt, UpdateExpr,
+};
+use swc_core::ecma::visit::{Visit, VisitWith};
+
+use crate::names::is_generated_ident;
+
+#[derive(Debug, Default)]
+pub struct StatementDependencies {
+    pub reads: HashSet<Id>,
+    pub writes: HashSet<Id>,
+    // Set when the statement calls a function for its side effects, which
+    // may change any value given to it
+    pub opaque: bool,
+}
+
+struct DependenciesCollector {
+    dependencies: StatementDependencies,
+}
+
+impl DependenciesCollector {
+    // Changing a property of an object changes the object itself
+    fn write_root(&mut self, expr: &Expr) {
+        if let Some(ident) = get_root_ident(expr) {
+            if !is_generated_ident(ident) {
+                self.dependencies.writes.insert(ident.to_id());
+            }
+        }
+    }
+}
+
+impl Visit for DependenciesCollector {
+    fn visit_ident(&mut self, ident: &Ident) {
+        self.dependencies.reads.insert(ident.to_id());
+    }
+
+    fn visit_assign_expr(&mut self, assign: &AssignExpr) {
+        match &assign.left {
+            AssignTarget::Simple(SimpleAssignTarget::Ident(ident)) => {
+                self.dependencies.writes.insert(ident.id.to_id());
+            }
+            AssignTarget::Simple(SimpleAssignTarget::Member(member)) => {
+                self.write_root(&member.obj);
+            }
+            _ => {}
+        }
+
+        assign.visit_children_with(self);
+    }
+
+    fn visit_update_expr(&mut self, update: &UpdateExpr) {
+        self.write_root(&update.arg);
+
+        update.visit_children_with(self);
+    }
+
+    // Methods may mutate the object they are called on
+    fn visit_call_expr(&mut self, call: &CallExpr) {
+        if let Callee::Expr(callee) = &call.callee {
+            if let Expr::Member(member) = callee.deref() {
+                self.write_root(&member.obj);
+            }
+        }
+
+        call.visit_children_with(self);
+    }
+}
+
+fn get_root_ident(expr: &Expr) -> Option<&Ident> {
+    match expr {
+        Expr::Ident(ident) => Some(ident),
+        Expr::Member(member) => get_root_ident(&member.obj),
+        Expr::Paren(paren) => get_root_ident(&paren.expr),
+        _ => None,
+    }
+}
+
+// A function called for its side effects, as opposed to a method whose
+// object is considered written
+fn is_opaque_statement(stmt: &Stmt) -> bool {
+    let Stmt::Expr(ExprStmt { expr, .. }) = stmt else {
+        return false;
+    };
+
+    let mut expr = expr.deref();
+
+    loop {
+        expr = match expr {
+            Expr::Paren(paren) => &paren.expr,
+            Expr::Await(await_expr) => &await_expr.arg,
+            _ => break,
+        };
+    }
+
+    match expr {
+        Expr::Call(CallExpr {
+            callee: Callee::Expr(callee),
+            ..
+        }) => match callee.deref() {
+            Expr::Member(_) => false,
+            Expr::Ident(ident) => !is_generated_ident(ident),
+            _ => true,
+        },
+        _ => false,
+    }
+}
+
+struct BindingsCollector {
+    bindings: Vec<Ident>,
+}
+
+impl Visit for BindingsCollector {
+    fn visit_binding_ident(&mut self, ident: &BindingIdent) {
+        self.bindings.push(ident.id.clone());
+    }
+
+    // Default values may hold closures whose parameters are not declared here
+    fn visit_expr(&mut self, _: &Expr) {}
+}
+
+pub fn get_pat_identifiers(pat: &Pat) -> Vec<Ident> {
+    let mut collector = BindingsCollector { bindings: vec![] };
+
+    pat.visit_with(&mut collector);
+
+    collector.bindings
+}
+
+fn get_pat_bindings(pat: &Pat) -> HashSet<Id> {
+    get_pat_identifiers(pat)
+        .into_iter()
+        .map(|ident| ident.to_id())
+        .collect()
+}
+
+pub fn get_statement_dependencies(stmt: &Stmt) -> StatementDependencies {
+    let mut collector = DependenciesCollector {
+        dependencies: StatementDependencies::default(),
+    };
+
+    stmt.visit_with(&mut collector);
+
+    let mut dependencies = collector.dependencies;
+
+    dependencies.opaque = is_opaque_statement(stmt);
+    let declarations = match stmt {
+        Stmt::Decl(Decl::Var(var_decl)) => var_decl
+            .decls
+            .iter()
+            .flat_map(|decl| get_pat_bindings(&decl.name))
+            .collect(),
+        Stmt::Decl(Decl::Fn(fn_decl)) => HashSet::from([fn_decl.ident.to_id()]),
+        Stmt::Decl(Decl::Class(class_decl)) => HashSet::from([class_decl.ident.to_id()]),
+        _ => HashSet::new(),
+    };
+
+    for declaration in declarations {
+        dependencies.reads.remove(&declaration);
+        dependencies.writes.insert(declaration);
+    }
+
+    dependencies
+}
+
+// Keeps the statements affected by a change of `changed`, along with the
+// statements producing the bindings they read. Every statement is kept when
+// a changed value is given to a function whose effects are unknown.
+pub fn select_dependent_statements(stmts: &[Stmt], changed: &Id) -> Vec<Stmt> {
+    let dependencies = stmts
+        .iter()
+        .map(get_statement_dependencies)
+        .collect::<Vec<_>>();
+    let mut selected = vec![false; stmts.len()];
+    let mut dirty = HashSet::from([changed.clone()]);
+
+    for (index, dependency) in dependencies.iter().enumerate() {
+        if !dependency.reads.is_disjoint(&dirty) {
+            if dependency.opaque {
+                return stmts.to_vec();
+            }
+
+            selected[index] = true;
+            dirty.extend(dependency.writes.iter().cloned());
+        }
+    }
+
+    let mut needed = HashSet::new();
+
+    for (index, dependency) in dependencies.iter().enumerate().rev() {
+        if !selected[index] && dependency.writes.is_disjoint(&needed) {
+            continue;
+        }
+
+        selected[index] = true;
+        needed.extend(dependency.reads.iter().cloned());
+    }
+
+    stmts
+        .iter()
+        .zip(selected)
+        .filter(|(_, selected)| *selected)
+        .map(|(stmt, _)| stmt.clone())
+        .collect()
+}
+
+struct FreeIdentifiersCollector {
+    identifiers: Vec<Ident>,
+    bindings: HashSet<Id>,
+}
+
+impl Visit for FreeIdentifiersCollector {
+    fn visit_ident(&mut self, ident: &Ident) {
+        if !self
+            .identifiers
+            .iter()
+            .any(|other| other.to_id() == ident.to_id())
+        {
+            self.identifiers.push(ident.clone());
+        }
+    }
+
+    fn visit_binding_ident(&mut self, ident: &BindingIdent) {
+        self.bindings.insert(ident.id.to_id());
+
+        ident.visit_children_with(self);
+    }
+
+    fn visit_fn_decl(&mut self, fn_decl: &FnDecl) {
+        self.bindings.insert(fn_decl.ident.to_id());
+
+        fn_decl.function.visit_with(self);
+    }
+
+    fn visit_class_decl(&mut self, class_decl: &ClassDecl) {
+        self.bindings.insert(class_decl.ident.to_id());
+
+        class_decl.class.visit_with(self);
+    }
+
+    // Intrinsic element names are not identifiers, and components are
+    // resolved where the elements are created
+    fn visit_jsx_element_name(&mut self, _: &JSXElementName) {}
+}
+
+fn collect_identifiers(params: &[Pat], body: &BlockStmtOrExpr) -> FreeIdentifiersCollector {
+    let mut collector = FreeIdentifiersCollector {
+        identifiers: vec![],
+        bindings: HashSet::new(),
+    };
+
+    params.visit_with(&mut collector);
+    body.visit_with(&mut collector);
+
+    collector
+}
+
+// Lists the identifiers used by a callback which are not declared by it, so
+// that they can be given to the callback when it is moved out of its scope.
+pub fn get_free_identifiers(params: &[Pat], body: &BlockStmtOrExpr) -> Vec<Ident> {
+    let collector = collect_identifiers(params, body);
+
+    collector
+        .identifiers
+        .into_iter()
+        .filter(|ident| !collector.bindings.contains(&ident.to_id()) && ident.sym != "undefined")
+        .collect()
+}
+
+pub fn get_declared_identifiers(body: &BlockStmtOrExpr) -> HashSet<Id> {
+    collect_identifiers(&[], body).bindings
+}
@@ -0,0 +1,136 @@
+/**
+ * Copyright 2024 Aubin REBILLAT
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+use std::fmt;
+
+use swc_core::common::errors::HANDLER;
+use swc_core::common::Span;
+
+#[derive(Debug)]
+pub enum ComponentError {
+    ComponentNotArrowFunction(Span),
+    ArrowBodyNotJsx(Span),
+    ReturnArgumentNotJsx(Span),
+    ParenthesisNotJsx(Span),
+    UnsupportedReturn(Span),
+    StateDeclarationNotArrayPattern(Span),
+    StateDeclarationLength(Span),
+    StateVariableNotIdentifier(Span),
+    StateUpdaterNotIdentifier(Span),
+    EffectArgumentsCount(Span),
+    EffectCallbackNotArrowFunction(Span),
+    EffectDepsNotArray(Span),
+    EffectCleanupNotFunction(Span),
+    NamespacedName(Span),
+    MarkerNotApplied(Span),
+    SlotNameNotString(Span),
+    SlotOutsideComponent(Span),
+}
+
+pub type ComponentResult<T> = Result<T, ComponentError>;
+
+impl ComponentError {
+    pub fn span(&self) -> Span {
+        match self {
+            ComponentError::ComponentNotArrowFunction(span)
+            | ComponentError::ArrowBodyNotJsx(span)
+            | ComponentError::ReturnArgumentNotJsx(span)
+            | ComponentError::ParenthesisNotJsx(span)
+            | ComponentError::UnsupportedReturn(span)
+            | ComponentError::StateDeclarationNotArrayPattern(span)
+            | ComponentError::StateDeclarationLength(span)
+            | ComponentError::StateVariableNotIdentifier(span)
+            | ComponentError::StateUpdaterNotIdentifier(span)
+            | ComponentError::EffectArgumentsCount(span)
+            | ComponentError::EffectCallbackNotArrowFunction(span)
+            | ComponentError::EffectDepsNotArray(span)
+            | ComponentError::EffectCleanupNotFunction(span)
+            | ComponentError::NamespacedName(span)
+            | ComponentError::MarkerNotApplied(span)
+            | ComponentError::SlotNameNotString(span)
+            | ComponentError::SlotOutsideComponent(span) => *span,
+        }
+    }
+
+    // Markers left unapplied do not prevent the rest of the module from being
+    // compiled, so they are only reported as warnings
+    fn is_warning(&self) -> bool {
+        matches!(self, ComponentError::MarkerNotApplied(_))
+    }
+
+    pub fn emit(&self) {
+        HANDLER.with(|handler| {
+            if self.is_warning() {
+                handler
+                    .struct_span_warn(self.span(), &self.to_string())
+                    .emit();
+            } else {
+                handler
+                    .struct_span_err(self.span(), &self.to_string())
+                    .emit();
+            }
+        });
+    }
+}
+
+impl fmt::Display for ComponentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            ComponentError::ComponentNotArrowFunction(_) => {
+                "Component declaration must be an arrow function"
+            }
+            ComponentError::ArrowBodyNotJsx(_) => "Arrow expression body is not JSX Element",
+            ComponentError::ReturnArgumentNotJsx(_) => {
+                "Return statement argument is not JSX Element"
+            }
+            ComponentError::ParenthesisNotJsx(_) => "Parenthesis expression is not JSX Element",
+            ComponentError::UnsupportedReturn(_) => {
+                "Return statement is only supported in blocks and if statements"
+            }
+            ComponentError::StateDeclarationNotArrayPattern(_) => {
+                "Palta state declaration should be an array pattern"
+            }
+            ComponentError::StateDeclarationLength(_) => {
+                "Palta state declaration should have one or two elements"
+            }
+            ComponentError::StateVariableNotIdentifier(_) => {
+                "First element of Palta state declaration should be an identifier"
+            }
+            ComponentError::StateUpdaterNotIdentifier(_) => {
+                "Second element of Palta state declaration should be an identifier"
+            }
+            ComponentError::EffectArgumentsCount(_) => "Palta effect should have two arguments",
+            ComponentError::EffectCallbackNotArrowFunction(_) => {
+                "First argument of Palta effect should be an arrow function"
+            }
+            ComponentError::EffectDepsNotArray(_) => {
+                "Second argument of Palta effect should be an array"
+            }
+            ComponentError::EffectCleanupNotFunction(_) => {
+                "Return value from an effect should be a cleanup function"
+            }
+            ComponentError::NamespacedName(_) => "JSX Namespaced Name is not supported",
+            ComponentError::MarkerNotApplied(_) => {
+                "Palta component marker is not attached to a component declaration"
+            }
+            ComponentError::SlotNameNotString(_) => "Slot name should be a string literal",
+            ComponentError::SlotOutsideComponent(_) => {
+                "Slot should be a direct child of a component element"
+            }
+        };
+
+        write!(f, "{}", message)
+    }
+}
@@ -13,26 +13,87 @@
  * See the License for the specific language governing permissions and
  * limitations under the License.
  */
+use std::collections::{HashMap, HashSet};
+
 use swc_core::atoms::Atom;
-use swc_core::common::DUMMY_SP;
+use swc_core::common::{Span, Spanned, DUMMY_SP};
 use swc_core::ecma::ast::{
-    ArrayLit, ArrowExpr, AssignExpr, AssignOp, AssignTarget, BinExpr, BinaryOp, BindingIdent,
-    BlockStmt, BlockStmtOrExpr, CallExpr, Callee, CondExpr, Decl, EmptyStmt, Expr, ExprOrSpread,
-    ExprStmt, Function, Ident, IdentName, KeyValueProp, Lit, MemberExpr, MemberProp, Null, Number,
-    ObjectLit, ObjectPat, ParenExpr, Pat, Prop, PropName, PropOrSpread, ReturnStmt,
-    SimpleAssignTarget, Stmt, Str, TsEntityName, TsQualifiedName, TsType, TsTypeAnn,
-    TsTypeParamInstantiation, TsTypeRef, UnaryExpr, UnaryOp, VarDecl, VarDeclKind, VarDeclarator,
+    ArrayLit, ArrayPat, ArrowExpr, AssignExpr, AssignOp, AssignTarget, BinExpr, BinaryOp,
+    BindingIdent, BlockStmt, BlockStmtOrExpr, BreakStmt, CallExpr, Callee, ComputedPropName,
+    CondExpr, Decl, Expr, ExprOrSpread, ExprStmt, Function, Ident, IdentName, IfStmt, KeyValueProp,
+    LabeledStmt, Lit, MemberExpr, MemberProp, MetaPropExpr, MetaPropKind, Null, Number, ObjectLit,
+    ObjectPat, ParenExpr, Pat, Prop, PropName, PropOrSpread, ReturnStmt, SimpleAssignTarget, Stmt,
+    Str, TsEntityName, TsQualifiedName, TsType, TsTypeAnn, TsTypeParamInstantiation, TsTypeRef,
+    UnaryExpr, UnaryOp, VarDecl, VarDeclKind, VarDeclarator,
 };
 
+use crate::config::{Config, Target};
+use crate::dependencies::select_dependent_statements;
+use crate::error::ComponentResult;
+use crate::names::{
+    effect_ident, element_ident, generated_ident, list_ident, runtime_ident, template_ident,
+};
 use crate::processor::{
-    ComponentElementDescriptor, ComponentName, ElementChildren, ElementDescriptor, Processor,
-    StateDescriptor, TagElementDescriptor,
+    BranchElementDescriptor, ChildrenElementDescriptor, ComponentElementDescriptor, ComponentName,
+    EffectDescriptor, ElementChildren, ElementDescriptor, FragmentElementDescriptor,
+    HydrationClaim, ListDescriptor, Processor, SlotElementDescriptor, StateDescriptor,
+    TagElementDescriptor,
 };
-use crate::utils::pat_to_expr;
+use crate::ssr::generate_ssr_component_declaration;
+use crate::template::Templates;
+use crate::utils::{pat_to_expr, slot_prop_name};
 
 pub enum ComponentDeclaration<'a> {
     Function(&'a mut Function),
-    VarDecl(&'a mut VarDecl),
+    Arrow(&'a mut ArrowExpr),
+}
+
+// Elements created with the component rendering existing nodes: the
+// server-rendered ones when hydrating, or the clone of their template
+#[derive(Default)]
+struct ElementNodes {
+    claims: HashMap<usize, HydrationClaim>,
+    templates: HashMap<usize, Ident>,
+    static_elements: HashSet<usize>,
+}
+
+impl ElementNodes {
+    fn new(processor: &Processor, hydrate: bool, templates: &mut Vec<Stmt>) -> Self {
+        if hydrate {
+            return ElementNodes {
+                claims: processor.get_hydration_claims(),
+                ..ElementNodes::default()
+            };
+        }
+
+        let Templates {
+            roots,
+            claims,
+            static_elements,
+        } = Templates::new(processor);
+
+        ElementNodes {
+            claims,
+            templates: roots
+                .into_iter()
+                .map(|(root, html)| {
+                    let ident = template_ident(templates.len());
+
+                    templates.push(generate_template_declaration(&ident, html));
+                    (root, ident)
+                })
+                .collect(),
+            static_elements,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct ComponentOptions {
+    // Adds the state carried over hot reloads to the definition
+    hot: bool,
+    // Claims the server-rendered nodes instead of creating them
+    hydrate: bool,
 }
 
 fn generate_children_array(children: &[ElementChildren]) -> ExprOrSpread {
@@ -53,10 +114,7 @@ fn generate_children_array(children: &[ElementChildren]) -> ExprOrSpread {
                         },
                         ElementChildren::Element(index) => ExprOrSpread {
                             spread: None,
-                            expr: Box::new(Expr::Ident(Ident {
-                                sym: format!("__$element${}", index).into(),
-                                ..Ident::default()
-                            })),
+                            expr: Box::new(Expr::Ident(element_ident(*index))),
                         },
                     })
                 })
@@ -66,43 +124,187 @@ fn generate_children_array(children: &[ElementChildren]) -> ExprOrSpread {
     }
 }
 
-fn generate_palta_element_call(element: &TagElementDescriptor) -> Option<Box<Expr>> {
+// The parent is only read when the node is claimed, as it is declared after
+// its children
+fn generate_hydration_claim_args(claim: &HydrationClaim) -> Vec<ExprOrSpread> {
+    let parent = match claim.parent {
+        Some(parent) => Expr::Arrow(ArrowExpr {
+            body: Box::new(BlockStmtOrExpr::Expr(Box::new(Expr::Ident(element_ident(
+                parent,
+            ))))),
+            ..ArrowExpr::default()
+        }),
+        None => Expr::Lit(Lit::Null(Null { span: DUMMY_SP })),
+    };
+
+    vec![
+        ExprOrSpread {
+            spread: None,
+            expr: Box::new(parent),
+        },
+        ExprOrSpread {
+            spread: None,
+            expr: Box::new(Expr::Lit(Lit::Num(Number {
+                span: DUMMY_SP,
+                value: claim.position as f64,
+                raw: None,
+            }))),
+        },
+    ]
+}
+
+// Static elements of a template are only part of its markup
+fn generate_template_children_array(
+    children: &[ElementChildren],
+    static_elements: &HashSet<usize>,
+) -> ExprOrSpread {
+    let mut array = generate_children_array(children);
+
+    if let Expr::Array(array) = array.expr.as_mut() {
+        for (child, elem) in children.iter().zip(array.elems.iter_mut()) {
+            if matches!(child, ElementChildren::Element(index) if static_elements.contains(index)) {
+                *elem = Some(ExprOrSpread {
+                    spread: None,
+                    expr: Box::new(Expr::Lit(Lit::Null(Null { span: DUMMY_SP }))),
+                });
+            }
+        }
+    }
+
+    array
+}
+
+fn generate_palta_element_call(
+    index: usize,
+    element: &TagElementDescriptor,
+    nodes: &ElementNodes,
+) -> Option<Box<Expr>> {
+    let (method, mut args) = match (nodes.templates.get(&index), nodes.claims.get(&index)) {
+        (Some(template), _) => (
+            "cloneTemplate",
+            vec![ExprOrSpread {
+                spread: None,
+                expr: Box::new(Expr::Ident(template.clone())),
+            }],
+        ),
+        (None, Some(claim)) => ("claimElement", generate_hydration_claim_args(claim)),
+        (None, None) => ("createElement", vec![]),
+    };
+
+    args.extend([
+        ExprOrSpread {
+            spread: None,
+            expr: Box::new(Expr::Lit(Lit::Str(Str {
+                span: DUMMY_SP,
+                value: Atom::new(element.tag.clone()),
+                raw: None,
+            }))),
+        },
+        generate_template_children_array(&element.children, &nodes.static_elements),
+    ]);
+
     Some(Box::new(Expr::Call(CallExpr {
         callee: Callee::Expr(Box::new(Expr::Member(MemberExpr {
-            obj: Box::new(Expr::Ident(Ident {
-                sym: "Palta".into(),
-                ..Ident::default()
-            })),
+            obj: Box::new(Expr::Ident(runtime_ident())),
             prop: MemberProp::Ident(IdentName {
-                sym: "createElement".into(),
+                sym: method.into(),
                 ..IdentName::default()
             }),
             ..MemberExpr::default()
         }))),
-        args: vec![
-            ExprOrSpread {
-                spread: None,
-                expr: Box::new(Expr::Lit(Lit::Str(Str {
-                    span: DUMMY_SP,
-                    value: Atom::new(element.tag.clone()),
-                    raw: None,
-                }))),
+        args,
+        span: element.span,
+        ..CallExpr::default()
+    })))
+}
+
+fn generate_palta_component_call(
+    element: &ComponentElementDescriptor,
+    claim: Option<&HydrationClaim>,
+) -> Option<Box<Expr>> {
+    let (method, mut args) = match claim {
+        Some(claim) => ("claimComponent", generate_hydration_claim_args(claim)),
+        None => ("createComponent", vec![]),
+    };
+
+    args.extend([
+        ExprOrSpread {
+            spread: None,
+            expr: match &element.component {
+                ComponentName::MemberExpression(member_expression) => {
+                    Box::new(Expr::Member(member_expression.clone()))
+                }
+                ComponentName::Identifier(identifier) => Box::new(Expr::Ident(identifier.clone())),
             },
-            generate_children_array(&element.children),
-        ],
+        },
+        generate_children_array(&element.children),
+    ]);
+
+    Some(Box::new(Expr::Call(CallExpr {
+        callee: Callee::Expr(Box::new(Expr::Member(MemberExpr {
+            obj: Box::new(Expr::Ident(runtime_ident())),
+            prop: MemberProp::Ident(IdentName {
+                sym: method.into(),
+                ..IdentName::default()
+            }),
+            ..MemberExpr::default()
+        }))),
+        args,
+        span: element.span,
         ..CallExpr::default()
     })))
 }
 
-fn generate_palta_component_call(element: &ComponentElementDescriptor) -> Option<Box<Expr>> {
+fn generate_palta_children_call(element: &ChildrenElementDescriptor) -> Option<Box<Expr>> {
     Some(Box::new(Expr::Call(CallExpr {
         callee: Callee::Expr(Box::new(Expr::Member(MemberExpr {
-            obj: Box::new(Expr::Ident(Ident {
-                sym: "Palta".into(),
-                ..Ident::default()
-            })),
+            obj: Box::new(Expr::Ident(runtime_ident())),
+            prop: MemberProp::Ident(IdentName {
+                sym: "createChildren".into(),
+                ..IdentName::default()
+            }),
+            ..MemberExpr::default()
+        }))),
+        // The fallback is read when mounted, as it may be created in a branch
+        args: element
+            .fallback
+            .map(|fallback| ExprOrSpread {
+                spread: None,
+                expr: Box::new(Expr::Arrow(ArrowExpr {
+                    body: Box::new(BlockStmtOrExpr::Expr(Box::new(Expr::Ident(element_ident(
+                        fallback,
+                    ))))),
+                    ..ArrowExpr::default()
+                })),
+            })
+            .into_iter()
+            .collect(),
+        ..CallExpr::default()
+    })))
+}
+
+fn generate_palta_fragment_call(element: &FragmentElementDescriptor) -> Option<Box<Expr>> {
+    Some(Box::new(Expr::Call(CallExpr {
+        callee: Callee::Expr(Box::new(Expr::Member(MemberExpr {
+            obj: Box::new(Expr::Ident(runtime_ident())),
             prop: MemberProp::Ident(IdentName {
-                sym: "createComponent".into(),
+                sym: "createFragment".into(),
+                ..IdentName::default()
+            }),
+            ..MemberExpr::default()
+        }))),
+        args: vec![generate_children_array(&element.children)],
+        span: element.span,
+        ..CallExpr::default()
+    })))
+}
+
+fn generate_palta_slot_call(element: &SlotElementDescriptor) -> Option<Box<Expr>> {
+    Some(Box::new(Expr::Call(CallExpr {
+        callee: Callee::Expr(Box::new(Expr::Member(MemberExpr {
+            obj: Box::new(Expr::Ident(runtime_ident())),
+            prop: MemberProp::Ident(IdentName {
+                sym: "createSlot".into(),
                 ..IdentName::default()
             }),
             ..MemberExpr::default()
@@ -110,44 +312,71 @@ fn generate_palta_component_call(element: &ComponentElementDescriptor) -> Option
         args: vec![
             ExprOrSpread {
                 spread: None,
-                expr: match &element.component {
-                    ComponentName::MemberExpression(member_expression) => {
-                        Box::new(Expr::Member(member_expression.clone()))
-                    }
-                    ComponentName::Identifier(identifier) => {
-                        Box::new(Expr::Ident(identifier.clone()))
-                    }
-                },
+                expr: Box::new(Expr::Lit(Lit::Str(element.name.as_str().into()))),
             },
             generate_children_array(&element.children),
         ],
+        span: element.span,
         ..CallExpr::default()
     })))
 }
 
-fn generate_palta_children_call() -> Option<Box<Expr>> {
+fn generate_branch_node(children: &[ElementChildren]) -> Option<ExprOrSpread> {
+    let node = match children {
+        [] => Box::new(Expr::Lit(Lit::Null(Null { span: DUMMY_SP }))),
+        [ElementChildren::Element(index)] => Box::new(Expr::Ident(element_ident(*index))),
+        children => generate_children_array(children).expr,
+    };
+
+    Some(ExprOrSpread {
+        spread: None,
+        expr: Box::new(Expr::Arrow(ArrowExpr {
+            body: Box::new(BlockStmtOrExpr::Expr(node)),
+            ..ArrowExpr::default()
+        })),
+    })
+}
+
+fn generate_palta_branch_call(element: &BranchElementDescriptor) -> Option<Box<Expr>> {
     Some(Box::new(Expr::Call(CallExpr {
         callee: Callee::Expr(Box::new(Expr::Member(MemberExpr {
-            obj: Box::new(Expr::Ident(Ident {
-                sym: "Palta".into(),
-                ..Ident::default()
-            })),
+            obj: Box::new(Expr::Ident(runtime_ident())),
             prop: MemberProp::Ident(IdentName {
-                sym: "createChildren".into(),
+                sym: "createBranch".into(),
                 ..IdentName::default()
             }),
             ..MemberExpr::default()
         }))),
-        args: vec![],
+        args: vec![ExprOrSpread {
+            spread: None,
+            expr: Box::new(Expr::Array(ArrayLit {
+                elems: element
+                    .branches
+                    .iter()
+                    .map(|branch| generate_branch_node(branch))
+                    .collect(),
+                ..ArrayLit::default()
+            })),
+        }],
+        span: element.span,
         ..CallExpr::default()
     })))
 }
 
-fn generate_element_creation_call(element: &ElementDescriptor) -> Option<Box<Expr>> {
+fn generate_element_creation_call(
+    index: usize,
+    element: &ElementDescriptor,
+    nodes: &ElementNodes,
+) -> Option<Box<Expr>> {
     match element {
-        ElementDescriptor::Tag(tag) => generate_palta_element_call(tag),
-        ElementDescriptor::Component(component) => generate_palta_component_call(component),
-        ElementDescriptor::Children => generate_palta_children_call(),
+        ElementDescriptor::Tag(tag) => generate_palta_element_call(index, tag, nodes),
+        ElementDescriptor::Component(component) => {
+            generate_palta_component_call(component, nodes.claims.get(&index))
+        }
+        ElementDescriptor::Fragment(fragment) => generate_palta_fragment_call(fragment),
+        ElementDescriptor::Branch(branch) => generate_palta_branch_call(branch),
+        ElementDescriptor::Slot(slot) => generate_palta_slot_call(slot),
+        ElementDescriptor::Children(children) => generate_palta_children_call(children),
     }
 }
 
@@ -157,10 +386,7 @@ fn generate_props_variable_declaration(props: Pat) -> Stmt {
         decls: vec![VarDeclarator {
             span: DUMMY_SP,
             name: Pat::Ident(BindingIdent {
-                id: Ident {
-                    sym: "__$props".into(),
-                    ..Ident::default()
-                },
+                id: generated_ident("__$props"),
                 type_ann: match props {
                     Pat::Ident(ident) => ident.type_ann.map(|_| {
                         Box::new(TsTypeAnn {
@@ -175,6 +401,19 @@ fn generate_props_variable_declaration(props: Pat) -> Stmt {
                             })),
                         })
                     }),
+                    Pat::Array(array) => array.type_ann.map(|_| {
+                        Box::new(TsTypeAnn {
+                            span: DUMMY_SP,
+                            type_ann: Box::new(TsType::TsTypeRef(TsTypeRef {
+                                span: DUMMY_SP,
+                                type_name: TsEntityName::Ident(Ident {
+                                    sym: "any".into(),
+                                    ..Ident::default()
+                                }),
+                                type_params: None,
+                            })),
+                        })
+                    }),
                     Pat::Object(object) => object.type_ann.map(|_| {
                         Box::new(TsTypeAnn {
                             span: DUMMY_SP,
@@ -203,73 +442,93 @@ fn generate_props_variable_declaration(props: Pat) -> Stmt {
     })))
 }
 
-fn generate_run_effect_call(processor: &Processor) -> Vec<Stmt> {
-    let mut result = vec![];
+pub fn generate_run_effect_call(index: usize, effect: &EffectDescriptor) -> Stmt {
+    generate_effect_call("runEffect", index, effect)
+}
 
-    for (index, effect) in processor.get_effects().iter().enumerate() {
-        result.push(Stmt::Expr(ExprStmt {
-            expr: Box::new(Expr::Call(CallExpr {
-                callee: Callee::Expr(Box::new(Expr::Member(MemberExpr {
-                    obj: Box::new(Expr::Ident(Ident {
-                        sym: "Palta".into(),
-                        ..Ident::default()
-                    })),
-                    prop: MemberProp::Ident(IdentName {
-                        sym: "runEffect".into(),
-                        ..IdentName::default()
-                    }),
-                    ..MemberExpr::default()
-                }))),
-                args: vec![
-                    ExprOrSpread {
-                        spread: None,
-                        expr: Box::new(Expr::Ident(Ident {
-                            sym: format!("__$effect${}", index).into(),
-                            ..Ident::default()
-                        })),
-                    },
-                    ExprOrSpread {
-                        spread: None,
-                        expr: Box::new(Expr::Arrow(ArrowExpr {
-                            body: Box::new(BlockStmtOrExpr::BlockStmt(BlockStmt {
-                                stmts: effect.callback.clone(),
-                                ..BlockStmt::default()
-                            })),
-                            ..ArrowExpr::default()
-                        })),
-                    },
-                    ExprOrSpread {
-                        spread: None,
-                        expr: match &effect.cleanup {
-                            Some(cleanup) => Box::new(Expr::Arrow(ArrowExpr {
-                                body: Box::new(BlockStmtOrExpr::BlockStmt(BlockStmt {
-                                    stmts: cleanup.clone(),
-                                    ..BlockStmt::default()
-                                })),
-                                ..ArrowExpr::default()
-                            })),
-                            None => Box::new(Expr::Lit(Lit::Null(Null { span: DUMMY_SP }))),
-                        },
-                    },
-                    ExprOrSpread {
-                        spread: None,
-                        expr: Box::new(Expr::Array(ArrayLit {
-                            elems: effect
-                                .deps
-                                .iter()
-                                .map(|dependency| Some(dependency.clone()))
-                                .collect(),
-                            ..ArrayLit::default()
+// Effects of the first render are deferred until the component is attached
+pub fn generate_mount_effect_call(index: usize, effect: &EffectDescriptor) -> Stmt {
+    generate_effect_call("mountEffect", index, effect)
+}
+
+fn generate_effect_call(method: &str, index: usize, effect: &EffectDescriptor) -> Stmt {
+    Stmt::Expr(ExprStmt {
+        expr: Box::new(Expr::Call(CallExpr {
+            callee: Callee::Expr(Box::new(Expr::Member(MemberExpr {
+                obj: Box::new(Expr::Ident(runtime_ident())),
+                prop: MemberProp::Ident(IdentName {
+                    sym: method.into(),
+                    ..IdentName::default()
+                }),
+                ..MemberExpr::default()
+            }))),
+            args: vec![
+                ExprOrSpread {
+                    spread: None,
+                    expr: Box::new(Expr::Ident(effect_ident(index))),
+                },
+                ExprOrSpread {
+                    spread: None,
+                    expr: Box::new(Expr::Arrow(ArrowExpr {
+                        body: Box::new(BlockStmtOrExpr::BlockStmt(BlockStmt {
+                            stmts: effect.callback.clone(),
+                            ..BlockStmt::default()
                         })),
-                    },
-                ],
-                ..CallExpr::default()
-            })),
-            ..ExprStmt::default()
-        }));
+                        ..ArrowExpr::default()
+                    })),
+                },
+                ExprOrSpread {
+                    spread: None,
+                    expr: Box::new(Expr::Array(ArrayLit {
+                        elems: effect
+                            .deps
+                            .iter()
+                            .map(|dependency| Some(dependency.clone()))
+                            .collect(),
+                        ..ArrayLit::default()
+                    })),
+                },
+            ],
+            span: effect.span,
+            ..CallExpr::default()
+        })),
+        ..ExprStmt::default()
+    })
+}
+
+fn get_component_update_statements(processor: &Processor) -> Vec<Stmt> {
+    let mut stmts = processor.get_update_statements().clone();
+
+    for (index, effect) in processor.get_effects().iter().enumerate() {
+        if !effect.in_branch {
+            stmts.push(generate_run_effect_call(index, effect));
+        }
     }
 
-    result
+    stmts
+}
+
+fn generate_update_function_declaration(name: &str, stmts: Vec<Stmt>, props: Pat) -> Stmt {
+    Stmt::Decl(Decl::Var(Box::new(VarDecl {
+        kind: VarDeclKind::Const,
+        decls: vec![VarDeclarator {
+            span: DUMMY_SP,
+            name: Pat::Ident(BindingIdent {
+                id: generated_ident(name),
+                ..BindingIdent::default()
+            }),
+            init: Some(Box::new(Expr::Arrow(ArrowExpr {
+                body: Box::new(BlockStmtOrExpr::BlockStmt(BlockStmt {
+                    stmts,
+                    ..BlockStmt::default()
+                })),
+                params: vec![props],
+                ..ArrowExpr::default()
+            }))),
+            definite: false,
+        }],
+        ..VarDecl::default()
+    })))
 }
 
 fn generate_component_update_function(processor: &Processor, props: Pat) -> Stmt {
@@ -277,10 +536,7 @@ fn generate_component_update_function(processor: &Processor, props: Pat) -> Stmt
         expr: Box::new(Expr::Assign(AssignExpr {
             op: AssignOp::Assign,
             left: AssignTarget::Simple(SimpleAssignTarget::Ident(BindingIdent {
-                id: Ident {
-                    sym: "__$props".into(),
-                    ..Ident::default()
-                },
+                id: generated_ident("__$props"),
                 type_ann: None,
             })),
             right: Box::new(pat_to_expr(&props.clone())),
@@ -289,50 +545,100 @@ fn generate_component_update_function(processor: &Processor, props: Pat) -> Stmt
         ..ExprStmt::default()
     })];
 
-    stmts.append(&mut processor.get_update_statements().clone());
-    stmts.append(&mut generate_run_effect_call(processor));
+    stmts.append(&mut get_component_update_statements(processor));
 
-    match processor.get_update_statements().len() {
-        0 => Stmt::Empty(EmptyStmt { span: DUMMY_SP }),
-        _ => Stmt::Decl(Decl::Var(Box::new(VarDecl {
-            kind: VarDeclKind::Const,
-            decls: vec![VarDeclarator {
+    generate_update_function_declaration("__$update", stmts, props)
+}
+
+// Each state gets its own update function, only made of the statements
+// depending on it, so that setting a state does not re-run the whole body.
+fn generate_state_update_functions(processor: &Processor, props: Pat) -> Vec<Option<Stmt>> {
+    let stmts = get_component_update_statements(processor);
+
+    processor
+        .get_states()
+        .iter()
+        .enumerate()
+        .map(|(index, state)| {
+            let dependent_stmts = select_dependent_statements(&stmts, &state.variable_name.to_id());
+
+            match (&state.updater_name, dependent_stmts.len()) {
+                (None, _) | (_, 0) => None,
+                _ => Some(generate_update_function_declaration(
+                    &format!("__$update${}", index),
+                    dependent_stmts,
+                    props.clone(),
+                )),
+            }
+        })
+        .collect()
+}
+
+fn generate_any_type_annotation(is_typescript: bool) -> Option<Box<TsTypeAnn>> {
+    match is_typescript {
+        true => Some(Box::new(TsTypeAnn {
+            span: DUMMY_SP,
+            type_ann: Box::new(TsType::TsTypeRef(TsTypeRef {
                 span: DUMMY_SP,
-                name: Pat::Ident(BindingIdent {
-                    id: Ident {
-                        sym: "__$update".into(),
-                        ..Ident::default()
-                    },
-                    ..BindingIdent::default()
+                type_name: TsEntityName::Ident(Ident {
+                    sym: "any".into(),
+                    ..Ident::default()
                 }),
-                init: Some(Box::new(Expr::Arrow(ArrowExpr {
-                    body: Box::new(BlockStmtOrExpr::BlockStmt(BlockStmt {
-                        stmts,
-                        ..BlockStmt::default()
-                    })),
-                    params: vec![props],
-                    ..ArrowExpr::default()
-                }))),
-                definite: false,
-            }],
-            ..VarDecl::default()
-        }))),
+                type_params: None,
+            })),
+        })),
+        false => None,
     }
 }
 
-fn generate_element_declaration(index: usize, element: &ElementDescriptor) -> Stmt {
+// Elements of a conditional branch are created when the branch is activated
+// for the first time, so they are only declared with the component.
+fn generate_lazy_element_declaration(index: usize, is_typescript: bool) -> Stmt {
     Stmt::Decl(Decl::Var(Box::new(VarDecl {
-        kind: VarDeclKind::Const,
+        kind: VarDeclKind::Let,
         decls: vec![VarDeclarator {
             span: DUMMY_SP,
             name: Pat::Ident(BindingIdent {
-                id: Ident {
-                    sym: format!("__$element${}", index).into(),
-                    ..Ident::default()
-                },
+                id: element_ident(index),
+                type_ann: generate_any_type_annotation(is_typescript),
+            }),
+            init: None,
+            definite: false,
+        }],
+        ..VarDecl::default()
+    })))
+}
+
+pub fn generate_element_assignment(index: usize, element: &ElementDescriptor) -> Stmt {
+    Stmt::Expr(ExprStmt {
+        expr: Box::new(Expr::Assign(AssignExpr {
+            op: AssignOp::Assign,
+            left: AssignTarget::Simple(SimpleAssignTarget::Ident(BindingIdent {
+                id: element_ident(index),
+                type_ann: None,
+            })),
+            right: generate_element_creation_call(index, element, &ElementNodes::default())
+                .unwrap(),
+            span: element.span(),
+        })),
+        ..ExprStmt::default()
+    })
+}
+
+fn generate_element_declaration(
+    index: usize,
+    element: &ElementDescriptor,
+    nodes: &ElementNodes,
+) -> Stmt {
+    Stmt::Decl(Decl::Var(Box::new(VarDecl {
+        kind: VarDeclKind::Const,
+        decls: vec![VarDeclarator {
+            span: element.span(),
+            name: Pat::Ident(BindingIdent {
+                id: element_ident(index),
                 ..BindingIdent::default()
             }),
-            init: generate_element_creation_call(element),
+            init: generate_element_creation_call(index, element, nodes),
             definite: false,
         }],
         ..VarDecl::default()
@@ -345,17 +651,11 @@ fn generate_root_declaration_statement(processor: &Processor) -> Stmt {
         decls: vec![VarDeclarator {
             span: DUMMY_SP,
             name: Pat::Ident(BindingIdent {
-                id: Ident {
-                    sym: "__$root".into(),
-                    ..Ident::default()
-                },
+                id: generated_ident("__$root"),
                 ..BindingIdent::default()
             }),
             init: Some(Box::new(match processor.get_root_element() {
-                Some(position) => Expr::Ident(Ident {
-                    sym: format!("__$element${}", position).into(),
-                    ..Ident::default()
-                }),
+                Some(position) => Expr::Ident(element_ident(position)),
                 None => Expr::Lit(Lit::Null(Null { span: DUMMY_SP })),
             })),
             definite: false,
@@ -364,15 +664,16 @@ fn generate_root_declaration_statement(processor: &Processor) -> Stmt {
     })))
 }
 
-fn generate_initialize_function(processor: &Processor, props: Pat) -> Box<Expr> {
+fn generate_initialize_function(
+    processor: &Processor,
+    props: Pat,
+    static_elements: &HashSet<usize>,
+) -> Box<Expr> {
     let mut stmts = vec![Stmt::Expr(ExprStmt {
         expr: Box::new(Expr::Assign(AssignExpr {
             op: AssignOp::Assign,
             left: AssignTarget::Simple(SimpleAssignTarget::Ident(BindingIdent {
-                id: Ident {
-                    sym: "__$props".into(),
-                    ..Ident::default()
-                },
+                id: generated_ident("__$props"),
                 type_ann: None,
             })),
             right: Box::new(pat_to_expr(&props.clone())),
@@ -381,7 +682,17 @@ fn generate_initialize_function(processor: &Processor, props: Pat) -> Box<Expr>
         ..ExprStmt::default()
     })];
 
-    stmts.append(&mut processor.get_initialze_statements().clone());
+    stmts.extend(
+        processor
+            .get_initialze_statements()
+            .iter()
+            .filter(|stmt| {
+                !static_elements
+                    .iter()
+                    .any(|element| is_element_initialize_call(stmt, *element))
+            })
+            .cloned(),
+    );
 
     Box::new(Expr::Arrow(ArrowExpr {
         body: Box::new(BlockStmtOrExpr::BlockStmt(BlockStmt {
@@ -393,164 +704,301 @@ fn generate_initialize_function(processor: &Processor, props: Pat) -> Box<Expr>
     }))
 }
 
-fn generate_component_return_statement(processor: &Processor, props: Pat) -> Stmt {
+fn generate_unmount_function(processor: &Processor) -> Box<Expr> {
+    Box::new(Expr::Arrow(ArrowExpr {
+        body: Box::new(BlockStmtOrExpr::BlockStmt(BlockStmt {
+            stmts: (0..processor.get_effects().len())
+                .map(|index| {
+                    Stmt::Expr(ExprStmt {
+                        expr: Box::new(Expr::Call(CallExpr {
+                            callee: Callee::Expr(Box::new(Expr::Member(MemberExpr {
+                                obj: Box::new(Expr::Ident(runtime_ident())),
+                                prop: MemberProp::Ident(IdentName {
+                                    sym: "cleanupEffect".into(),
+                                    ..IdentName::default()
+                                }),
+                                ..MemberExpr::default()
+                            }))),
+                            args: vec![ExprOrSpread {
+                                spread: None,
+                                expr: Box::new(Expr::Ident(effect_ident(index))),
+                            }],
+                            ..CallExpr::default()
+                        })),
+                        ..ExprStmt::default()
+                    })
+                })
+                .collect(),
+            ..BlockStmt::default()
+        })),
+        ..ArrowExpr::default()
+    }))
+}
+
+fn generate_hot_state(processor: &Processor) -> Box<Expr> {
+    let states = processor.get_states();
+    let shape = states
+        .iter()
+        .map(|state| state.variable_name.sym.as_str())
+        .collect::<Vec<_>>()
+        .join(",");
+
+    Box::new(Expr::Object(ObjectLit {
+        props: vec![
+            PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                key: PropName::Ident(IdentName {
+                    sym: "shape".into(),
+                    ..IdentName::default()
+                }),
+                value: Box::new(Expr::Lit(Lit::Str(shape.into()))),
+            }))),
+            PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                key: PropName::Ident(IdentName {
+                    sym: "save".into(),
+                    ..IdentName::default()
+                }),
+                value: Box::new(Expr::Arrow(ArrowExpr {
+                    body: Box::new(BlockStmtOrExpr::Expr(Box::new(Expr::Array(ArrayLit {
+                        elems: states
+                            .iter()
+                            .map(|state| {
+                                Some(ExprOrSpread {
+                                    spread: None,
+                                    expr: Box::new(Expr::Ident(state.variable_name.clone())),
+                                })
+                            })
+                            .collect(),
+                        ..ArrayLit::default()
+                    })))),
+                    ..ArrowExpr::default()
+                })),
+            }))),
+            PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                key: PropName::Ident(IdentName {
+                    sym: "restore".into(),
+                    ..IdentName::default()
+                }),
+                value: Box::new(Expr::Arrow(ArrowExpr {
+                    params: vec![Pat::Ident(BindingIdent {
+                        id: generated_ident("__$state"),
+                        type_ann: None,
+                    })],
+                    body: Box::new(BlockStmtOrExpr::BlockStmt(BlockStmt {
+                        stmts: states
+                            .iter()
+                            .enumerate()
+                            .map(|(index, state)| {
+                                Stmt::Expr(ExprStmt {
+                                    expr: Box::new(Expr::Assign(AssignExpr {
+                                        op: AssignOp::Assign,
+                                        left: AssignTarget::Simple(SimpleAssignTarget::Ident(
+                                            BindingIdent {
+                                                id: state.variable_name.clone(),
+                                                type_ann: None,
+                                            },
+                                        )),
+                                        right: Box::new(Expr::Member(MemberExpr {
+                                            obj: Box::new(Expr::Ident(generated_ident("__$state"))),
+                                            prop: MemberProp::Computed(ComputedPropName {
+                                                span: DUMMY_SP,
+                                                expr: Box::new(Expr::Lit(Lit::Num(Number {
+                                                    span: DUMMY_SP,
+                                                    value: index as f64,
+                                                    raw: None,
+                                                }))),
+                                            }),
+                                            ..MemberExpr::default()
+                                        })),
+                                        ..AssignExpr::default()
+                                    })),
+                                    ..ExprStmt::default()
+                                })
+                            })
+                            .collect(),
+                        ..BlockStmt::default()
+                    })),
+                    ..ArrowExpr::default()
+                })),
+            }))),
+        ],
+        ..ObjectLit::default()
+    }))
+}
+
+fn generate_component_return_statement(
+    processor: &Processor,
+    props: Pat,
+    hot: bool,
+    nodes: &ElementNodes,
+) -> Stmt {
+    let mut definition_props = vec![
+        PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+            key: PropName::Ident(IdentName {
+                sym: "slots".into(),
+                ..IdentName::default()
+            }),
+            value: Box::new(Expr::Object(ObjectLit {
+                props: processor
+                    .get_slots()
+                    .iter()
+                    .map(|(name, index)| {
+                        PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                            key: slot_prop_name(name),
+                            value: Box::new(Expr::Ident(element_ident(*index))),
+                        })))
+                    })
+                    .collect(),
+                ..ObjectLit::default()
+            })),
+        }))),
+        PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+            key: PropName::Ident(IdentName {
+                sym: "initialize".into(),
+                ..IdentName::default()
+            }),
+            value: generate_initialize_function(processor, props, &nodes.static_elements),
+        }))),
+        PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+            key: PropName::Ident(IdentName {
+                sym: "update".into(),
+                ..IdentName::default()
+            }),
+            value: Box::new(Expr::Ident(generated_ident("__$update"))),
+        }))),
+        PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+            key: PropName::Ident(IdentName {
+                sym: "getRoot".into(),
+                ..IdentName::default()
+            }),
+            value: Box::new(Expr::Arrow(ArrowExpr {
+                body: Box::new(BlockStmtOrExpr::Expr(Box::new(Expr::Paren(ParenExpr {
+                    expr: Box::new(Expr::Ident(generated_ident("__$root"))),
+                    ..ParenExpr::default()
+                })))),
+                params: vec![],
+                ..ArrowExpr::default()
+            })),
+        }))),
+    ];
+
+    // Cleanups can only be pending in components declaring effects
+    if !processor.get_effects().is_empty() {
+        definition_props.push(PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+            key: PropName::Ident(IdentName {
+                sym: "unmount".into(),
+                ..IdentName::default()
+            }),
+            value: generate_unmount_function(processor),
+        }))));
+    }
+
+    // State is carried over hot reloads when its shape is unchanged
+    if hot && !processor.get_states().is_empty() {
+        definition_props.push(PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+            key: PropName::Ident(IdentName {
+                sym: "hot".into(),
+                ..IdentName::default()
+            }),
+            value: generate_hot_state(processor),
+        }))));
+    }
+
     Stmt::Return(ReturnStmt {
         arg: Some(Box::new(Expr::Object(ObjectLit {
-            props: vec![
-                PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
-                    key: PropName::Ident(IdentName {
-                        sym: "childrenElement".into(),
-                        ..IdentName::default()
-                    }),
-                    value: Box::new(match processor.get_children_element() {
-                        Some(pos) => Expr::Ident(Ident {
-                            sym: format!("__$element${}", pos).into(),
-                            ..Ident::default()
-                        }),
-                        _ => Expr::Lit(Lit::Null(Null { span: DUMMY_SP })),
-                    }),
-                }))),
-                PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
-                    key: PropName::Ident(IdentName {
-                        sym: "initialize".into(),
-                        ..IdentName::default()
-                    }),
-                    value: generate_initialize_function(processor, props),
-                }))),
-                PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
-                    key: PropName::Ident(IdentName {
-                        sym: "update".into(),
-                        ..IdentName::default()
-                    }),
-                    value: Box::new(Expr::Ident(Ident {
-                        sym: "__$update".into(),
-                        ..Ident::default()
+            props: definition_props,
+            ..ObjectLit::default()
+        }))),
+        ..ReturnStmt::default()
+    })
+}
+
+fn generate_state_updater_function(
+    state_variable_name: &Ident,
+    update_function: Option<String>,
+    span: Span,
+) -> Expr {
+    let mut stmts = vec![Stmt::Expr(ExprStmt {
+        expr: Box::new(Expr::Assign(AssignExpr {
+            op: AssignOp::Assign,
+            left: AssignTarget::Simple(SimpleAssignTarget::Ident(BindingIdent {
+                id: state_variable_name.clone(),
+                ..BindingIdent::default()
+            })),
+            right: Box::new(Expr::Cond(CondExpr {
+                test: Box::new(Expr::Bin(BinExpr {
+                    op: BinaryOp::EqEqEq,
+                    left: Box::new(Expr::Unary(UnaryExpr {
+                        op: UnaryOp::TypeOf,
+                        arg: Box::new(Expr::Ident(generated_ident("value"))),
+                        ..UnaryExpr::default()
                     })),
-                }))),
-                PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
-                    key: PropName::Ident(IdentName {
-                        sym: "getRoot".into(),
+                    right: Box::new(Expr::Lit(Lit::Str(Str {
+                        span: DUMMY_SP,
+                        value: "function".into(),
+                        raw: None,
+                    }))),
+                    ..BinExpr::default()
+                })),
+                cons: Box::new(Expr::Call(CallExpr {
+                    callee: Callee::Expr(Box::new(Expr::Ident(generated_ident("value")))),
+                    args: vec![ExprOrSpread {
+                        spread: None,
+                        expr: Box::new(Expr::Ident(state_variable_name.clone())),
+                    }],
+                    ..CallExpr::default()
+                })),
+                alt: Box::new(Expr::Ident(generated_ident("value"))),
+                ..CondExpr::default()
+            })),
+            ..AssignExpr::default()
+        })),
+        ..ExprStmt::default()
+    })];
+
+    if let Some(update_function) = update_function {
+        stmts.push(Stmt::Expr(ExprStmt {
+            expr: Box::new(Expr::Call(CallExpr {
+                callee: Callee::Expr(Box::new(Expr::Member(MemberExpr {
+                    obj: Box::new(Expr::Ident(runtime_ident())),
+                    prop: MemberProp::Ident(IdentName {
+                        sym: "componentUpdate".into(),
                         ..IdentName::default()
                     }),
-                    value: Box::new(Expr::Arrow(ArrowExpr {
-                        body: Box::new(BlockStmtOrExpr::Expr(Box::new(Expr::Paren(ParenExpr {
-                            expr: Box::new(Expr::Ident(Ident {
-                                sym: "__$root".into(),
-                                ..Ident::default()
-                            })),
-                            ..ParenExpr::default()
-                        })))),
+                    ..MemberExpr::default()
+                }))),
+                args: vec![ExprOrSpread {
+                    spread: None,
+                    expr: Box::new(Expr::Arrow(ArrowExpr {
                         params: vec![],
+                        body: Box::new(BlockStmtOrExpr::Expr(Box::new(Expr::Call(CallExpr {
+                            callee: Callee::Expr(Box::new(Expr::Ident(generated_ident(
+                                update_function,
+                            )))),
+                            args: vec![ExprOrSpread {
+                                spread: None,
+                                expr: Box::new(Expr::Ident(generated_ident("__$props"))),
+                            }],
+                            ..CallExpr::default()
+                        })))),
                         ..ArrowExpr::default()
                     })),
-                }))),
-            ],
-            ..ObjectLit::default()
-        }))),
-        ..ReturnStmt::default()
-    })
-}
+                }],
+                ..CallExpr::default()
+            })),
+            ..ExprStmt::default()
+        }));
+    }
 
-fn generate_state_updater_function(state_variable_name: &Ident) -> Expr {
     Expr::Arrow(ArrowExpr {
         params: vec![Pat::Ident(BindingIdent {
-            id: Ident {
-                sym: "value".into(),
-                ..Ident::default()
-            },
+            id: generated_ident("value"),
             ..BindingIdent::default()
         })],
         body: Box::new(BlockStmtOrExpr::BlockStmt(BlockStmt {
-            stmts: vec![
-                Stmt::Expr(ExprStmt {
-                    expr: Box::new(Expr::Assign(AssignExpr {
-                        op: AssignOp::Assign,
-                        left: AssignTarget::Simple(SimpleAssignTarget::Ident(BindingIdent {
-                            id: state_variable_name.clone(),
-                            ..BindingIdent::default()
-                        })),
-                        right: Box::new(Expr::Cond(CondExpr {
-                            test: Box::new(Expr::Bin(BinExpr {
-                                op: BinaryOp::EqEqEq,
-                                left: Box::new(Expr::Unary(UnaryExpr {
-                                    op: UnaryOp::TypeOf,
-                                    arg: Box::new(Expr::Ident(Ident {
-                                        sym: "value".into(),
-                                        ..Ident::default()
-                                    })),
-                                    ..UnaryExpr::default()
-                                })),
-                                right: Box::new(Expr::Lit(Lit::Str(Str {
-                                    span: DUMMY_SP,
-                                    value: "function".into(),
-                                    raw: None,
-                                }))),
-                                ..BinExpr::default()
-                            })),
-                            cons: Box::new(Expr::Call(CallExpr {
-                                callee: Callee::Expr(Box::new(Expr::Ident(Ident {
-                                    sym: "value".into(),
-                                    ..Ident::default()
-                                }))),
-                                args: vec![ExprOrSpread {
-                                    spread: None,
-                                    expr: Box::new(Expr::Ident(state_variable_name.clone())),
-                                }],
-                                ..CallExpr::default()
-                            })),
-                            alt: Box::new(Expr::Ident(Ident {
-                                sym: "value".into(),
-                                ..Ident::default()
-                            })),
-                            ..CondExpr::default()
-                        })),
-                        ..AssignExpr::default()
-                    })),
-                    ..ExprStmt::default()
-                }),
-                Stmt::Expr(ExprStmt {
-                    expr: Box::new(Expr::Call(CallExpr {
-                        callee: Callee::Expr(Box::new(Expr::Member(MemberExpr {
-                            obj: Box::new(Expr::Ident(Ident {
-                                sym: "Palta".into(),
-                                ..Ident::default()
-                            })),
-                            prop: MemberProp::Ident(IdentName {
-                                sym: "componentUpdate".into(),
-                                ..IdentName::default()
-                            }),
-                            ..MemberExpr::default()
-                        }))),
-                        args: vec![ExprOrSpread {
-                            spread: None,
-                            expr: Box::new(Expr::Arrow(ArrowExpr {
-                                params: vec![],
-                                body: Box::new(BlockStmtOrExpr::Expr(Box::new(Expr::Call(
-                                    CallExpr {
-                                        callee: Callee::Expr(Box::new(Expr::Ident(Ident {
-                                            sym: "__$update".into(),
-                                            ..Ident::default()
-                                        }))),
-                                        args: vec![ExprOrSpread {
-                                            spread: None,
-                                            expr: Box::new(Expr::Ident(Ident {
-                                                sym: "__$props".into(),
-                                                ..Ident::default()
-                                            })),
-                                        }],
-                                        ..CallExpr::default()
-                                    },
-                                )))),
-                                ..ArrowExpr::default()
-                            })),
-                        }],
-                        ..CallExpr::default()
-                    })),
-                    ..ExprStmt::default()
-                }),
-            ],
+            stmts,
             ..BlockStmt::default()
         })),
+        span,
         ..ArrowExpr::default()
     })
 }
@@ -558,12 +1006,13 @@ fn generate_state_updater_function(state_variable_name: &Ident) -> Expr {
 fn generate_state_statements(
     statements: &mut Vec<Stmt>,
     state: &StateDescriptor,
+    update_function: Option<String>,
     is_typescript: bool,
 ) {
     statements.push(Stmt::Decl(Decl::Var(Box::new(VarDecl {
         kind: VarDeclKind::Let,
         decls: vec![VarDeclarator {
-            span: DUMMY_SP,
+            span: state.span,
             name: Pat::Ident(BindingIdent {
                 id: state.variable_name.clone(),
                 ..BindingIdent::default()
@@ -578,7 +1027,7 @@ fn generate_state_statements(
         statements.push(Stmt::Decl(Decl::Var(Box::new(VarDecl {
             kind: VarDeclKind::Const,
             decls: vec![VarDeclarator {
-                span: DUMMY_SP,
+                span: state.span,
                 name: Pat::Ident(BindingIdent {
                     id: updater_name.clone(),
                     type_ann: if is_typescript {
@@ -589,10 +1038,7 @@ fn generate_state_statements(
                                 type_name: TsEntityName::TsQualifiedName(Box::new(
                                     TsQualifiedName {
                                         span: DUMMY_SP,
-                                        left: TsEntityName::Ident(Ident {
-                                            sym: "Palta".into(),
-                                            ..Ident::default()
-                                        }),
+                                        left: TsEntityName::Ident(runtime_ident()),
                                         right: IdentName {
                                             sym: "StateUpdater".into(),
                                             ..IdentName::default()
@@ -621,6 +1067,8 @@ fn generate_state_statements(
                 }),
                 init: Some(Box::new(generate_state_updater_function(
                     &state.variable_name,
+                    update_function,
+                    state.span,
                 ))),
                 definite: false,
             }],
@@ -629,51 +1077,317 @@ fn generate_state_statements(
     }
 }
 
-fn generate_effect_statement(index: usize) -> Stmt {
-    Stmt::Decl(Decl::Var(Box::new(VarDecl {
-        kind: VarDeclKind::Const,
-        decls: vec![VarDeclarator {
+fn generate_effect_statement(index: usize) -> Stmt {
+    Stmt::Decl(Decl::Var(Box::new(VarDecl {
+        kind: VarDeclKind::Const,
+        decls: vec![VarDeclarator {
+            span: DUMMY_SP,
+            name: Pat::Ident(BindingIdent {
+                id: effect_ident(index),
+                ..BindingIdent::default()
+            }),
+            init: Some(Box::new(Expr::Object(ObjectLit {
+                props: ["deps", "cleanup"]
+                    .into_iter()
+                    .map(|key| {
+                        PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                            key: PropName::Ident(IdentName {
+                                sym: key.into(),
+                                ..IdentName::default()
+                            }),
+                            value: Box::new(Expr::Lit(Lit::Null(Null { span: DUMMY_SP }))),
+                        })))
+                    })
+                    .collect(),
+                ..ObjectLit::default()
+            }))),
+            definite: false,
+        }],
+        ..VarDecl::default()
+    })))
+}
+
+fn generate_list_declaration(
+    index: usize,
+    list: &ListDescriptor,
+    is_typescript: bool,
+    templates: &mut Vec<Stmt>,
+) -> ComponentResult<Stmt> {
+    let mut var_decl = VarDecl {
+        kind: VarDeclKind::Const,
+        decls: vec![VarDeclarator {
+            span: list.span,
+            name: Pat::Ident(BindingIdent {
+                id: list_ident(index),
+                ..BindingIdent::default()
+            }),
+            init: Some(Box::new(Expr::Arrow(ArrowExpr {
+                params: vec![Pat::Array(ArrayPat {
+                    span: DUMMY_SP,
+                    elems: list
+                        .props
+                        .iter()
+                        .map(|ident| {
+                            Some(Pat::Ident(BindingIdent {
+                                id: ident.clone(),
+                                type_ann: None,
+                            }))
+                        })
+                        .collect(),
+                    optional: false,
+                    type_ann: generate_any_type_annotation(is_typescript),
+                })],
+                body: Box::new(list.body.clone()),
+                ..ArrowExpr::default()
+            }))),
+            definite: false,
+        }],
+        ..VarDecl::default()
+    };
+
+    let declarator = &mut var_decl.decls[0];
+
+    if let Some(arrow) = declarator
+        .init
+        .as_mut()
+        .and_then(|init| init.as_mut_arrow())
+    {
+        generate_arrow_function_component_declaration(
+            arrow,
+            ComponentOptions::default(),
+            templates,
+        )?;
+    }
+
+    if list.render_prop {
+        return Ok(Stmt::Decl(Decl::Var(Box::new(var_decl))));
+    }
+
+    declarator.init = Some(Box::new(Expr::Call(CallExpr {
+        callee: Callee::Expr(Box::new(Expr::Member(MemberExpr {
+            obj: Box::new(Expr::Ident(runtime_ident())),
+            prop: MemberProp::Ident(IdentName {
+                sym: "createList".into(),
+                ..IdentName::default()
+            }),
+            ..MemberExpr::default()
+        }))),
+        args: declarator
+            .init
+            .take()
+            .map(|init| ExprOrSpread {
+                spread: None,
+                expr: init,
+            })
+            .into_iter()
+            .collect(),
+        span: list.span,
+        ..CallExpr::default()
+    })));
+
+    Ok(Stmt::Decl(Decl::Var(Box::new(var_decl))))
+}
+
+pub fn generate_list_collect_call(index: usize, expr: Expr) -> Expr {
+    Expr::Call(CallExpr {
+        callee: Callee::Expr(Box::new(Expr::Member(MemberExpr {
+            obj: Box::new(Expr::Ident(list_ident(index))),
+            prop: MemberProp::Ident(IdentName {
+                sym: "collect".into(),
+                ..IdentName::default()
+            }),
+            ..MemberExpr::default()
+        }))),
+        args: vec![ExprOrSpread {
+            spread: None,
+            expr: Box::new(Expr::Arrow(ArrowExpr {
+                body: Box::new(BlockStmtOrExpr::Expr(Box::new(expr))),
+                ..ArrowExpr::default()
+            })),
+        }],
+        ..CallExpr::default()
+    })
+}
+
+pub fn generate_list_render_call(index: usize, props: &[Ident], key: Option<Expr>) -> Expr {
+    let mut args = vec![ExprOrSpread {
+        spread: None,
+        expr: Box::new(Expr::Array(ArrayLit {
+            elems: props
+                .iter()
+                .map(|ident| {
+                    Some(ExprOrSpread {
+                        spread: None,
+                        expr: Box::new(Expr::Ident(ident.clone())),
+                    })
+                })
+                .collect(),
+            ..ArrayLit::default()
+        })),
+    }];
+
+    if let Some(key) = key {
+        args.push(ExprOrSpread {
+            spread: None,
+            expr: Box::new(key),
+        });
+    }
+
+    Expr::Call(CallExpr {
+        callee: Callee::Expr(Box::new(Expr::Member(MemberExpr {
+            obj: Box::new(Expr::Ident(list_ident(index))),
+            prop: MemberProp::Ident(IdentName {
+                sym: "render".into(),
+                ..IdentName::default()
+            }),
+            ..MemberExpr::default()
+        }))),
+        args,
+        ..CallExpr::default()
+    })
+}
+
+// The render prop keeps the callback parameters, and gives the component the
+// props it is created with
+pub fn generate_render_prop_call(index: usize, params: Vec<Pat>, props: &[Ident]) -> Expr {
+    Expr::Call(CallExpr {
+        callee: Callee::Expr(Box::new(Expr::Member(MemberExpr {
+            obj: Box::new(Expr::Ident(runtime_ident())),
+            prop: MemberProp::Ident(IdentName {
+                sym: "createRenderProp".into(),
+                ..IdentName::default()
+            }),
+            ..MemberExpr::default()
+        }))),
+        args: vec![
+            ExprOrSpread {
+                spread: None,
+                expr: Box::new(Expr::Ident(list_ident(index))),
+            },
+            ExprOrSpread {
+                spread: None,
+                expr: Box::new(Expr::Arrow(ArrowExpr {
+                    params,
+                    body: Box::new(BlockStmtOrExpr::Expr(Box::new(Expr::Array(ArrayLit {
+                        elems: props
+                            .iter()
+                            .map(|ident| {
+                                Some(ExprOrSpread {
+                                    spread: None,
+                                    expr: Box::new(Expr::Ident(ident.clone())),
+                                })
+                            })
+                            .collect(),
+                        ..ArrayLit::default()
+                    })))),
+                    ..ArrowExpr::default()
+                })),
+            },
+        ],
+        ..CallExpr::default()
+    })
+}
+
+// Render props given as children are rendered by the default children element,
+// each expression calling `$children` keeping the elements of its own calls
+fn generate_children_method_call(
+    children: usize,
+    method: &str,
+    site: usize,
+    args: Vec<ExprOrSpread>,
+) -> Expr {
+    let site = ExprOrSpread {
+        spread: None,
+        expr: Box::new(Expr::Lit(Lit::Num(Number {
             span: DUMMY_SP,
-            name: Pat::Ident(BindingIdent {
-                id: Ident {
-                    sym: format!("__$effect${}", index).into(),
-                    ..Ident::default()
-                },
-                ..BindingIdent::default()
+            value: site as f64,
+            raw: None,
+        }))),
+    };
+
+    Expr::Call(CallExpr {
+        callee: Callee::Expr(Box::new(Expr::Member(MemberExpr {
+            obj: Box::new(Expr::Ident(element_ident(children))),
+            prop: MemberProp::Ident(IdentName {
+                sym: method.into(),
+                ..IdentName::default()
             }),
-            init: Some(Box::new(Expr::Object(ObjectLit {
-                props: vec![PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
-                    key: PropName::Ident(IdentName {
-                        sym: "deps".into(),
-                        ..IdentName::default()
-                    }),
-                    value: Box::new(Expr::Lit(Lit::Null(Null { span: DUMMY_SP }))),
-                })))],
-                ..ObjectLit::default()
-            }))),
-            definite: false,
+            ..MemberExpr::default()
+        }))),
+        args: std::iter::once(site).chain(args).collect(),
+        ..CallExpr::default()
+    })
+}
+
+pub fn generate_render_children_call(
+    children: usize,
+    site: usize,
+    args: Vec<ExprOrSpread>,
+) -> Expr {
+    generate_children_method_call(children, "render", site, args)
+}
+
+pub fn generate_render_children_collect_call(
+    children: usize,
+    site: usize,
+    expr: Box<Expr>,
+) -> Expr {
+    generate_children_method_call(
+        children,
+        "collect",
+        site,
+        vec![ExprOrSpread {
+            spread: None,
+            expr: Box::new(Expr::Arrow(ArrowExpr {
+                body: Box::new(BlockStmtOrExpr::Expr(expr)),
+                ..ArrowExpr::default()
+            })),
         }],
-        ..VarDecl::default()
-    })))
+    )
 }
 
 fn generate_component_statements(
     processor: &Processor,
     props: Pat,
     is_typescript: bool,
-) -> Vec<Stmt> {
+    options: ComponentOptions,
+    templates: &mut Vec<Stmt>,
+) -> ComponentResult<Vec<Stmt>> {
     let mut statements = vec![];
+    let nodes = ElementNodes::new(processor, options.hydrate, templates);
 
     for (index, element) in processor.get_elements().iter().enumerate() {
-        statements.push(generate_element_declaration(index, element));
+        if nodes.static_elements.contains(&index) {
+            continue;
+        }
+
+        statements.push(match processor.is_lazy_element(index) {
+            true => generate_lazy_element_declaration(index, is_typescript),
+            false => generate_element_declaration(index, element, &nodes),
+        });
+    }
+
+    for (index, list) in processor.get_lists().iter().enumerate() {
+        statements.push(generate_list_declaration(
+            index,
+            list,
+            is_typescript,
+            templates,
+        )?);
     }
 
     statements.reverse();
 
     statements.push(generate_props_variable_declaration(props.clone()));
 
-    for state in processor.get_states() {
-        generate_state_statements(&mut statements, state, is_typescript);
+    let state_update_functions = generate_state_update_functions(processor, props.clone());
+
+    for (index, state) in processor.get_states().iter().enumerate() {
+        let update_function = state_update_functions[index]
+            .as_ref()
+            .map(|_| format!("__$update${}", index));
+
+        generate_state_statements(&mut statements, state, update_function, is_typescript);
     }
 
     for index in 0..processor.get_effects().len() {
@@ -681,13 +1395,16 @@ fn generate_component_statements(
     }
 
     statements.push(generate_component_update_function(processor, props.clone()));
+    statements.extend(state_update_functions.into_iter().flatten());
     statements.push(generate_root_declaration_statement(processor));
     statements.push(generate_component_return_statement(
         processor,
         props.clone(),
+        options.hot,
+        &nodes,
     ));
 
-    statements
+    Ok(statements)
 }
 
 fn generate_type_annotation_from_props(pat: Pat) -> Option<Box<TsType>> {
@@ -700,7 +1417,11 @@ fn generate_type_annotation_from_props(pat: Pat) -> Option<Box<TsType>> {
     }
 }
 
-fn generate_function_component_declaration(function: &mut Function) {
+fn generate_function_component_declaration(
+    function: &mut Function,
+    options: ComponentOptions,
+    templates: &mut Vec<Stmt>,
+) -> ComponentResult<()> {
     let mut processor: Processor = Processor::new();
     let props = match function.params.first() {
         Some(param) => param.pat.clone(),
@@ -713,14 +1434,16 @@ fn generate_function_component_declaration(function: &mut Function) {
     };
     let props_type_annotation = generate_type_annotation_from_props(props.clone());
 
-    processor.process_function(function);
+    processor.process_function(function)?;
 
     function.body = Some(BlockStmt {
         stmts: generate_component_statements(
             &processor,
             props.clone(),
             props_type_annotation.is_some(),
-        ),
+            options,
+            templates,
+        )?,
         ..BlockStmt::default()
     });
     function.params = vec![];
@@ -731,10 +1454,7 @@ fn generate_function_component_declaration(function: &mut Function) {
                 span: DUMMY_SP,
                 type_name: TsEntityName::TsQualifiedName(Box::new(TsQualifiedName {
                     span: DUMMY_SP,
-                    left: TsEntityName::Ident(Ident {
-                        sym: "Palta".into(),
-                        ..Ident::default()
-                    }),
+                    left: TsEntityName::Ident(runtime_ident()),
                     right: IdentName {
                         sym: "ComponentDefinition".into(),
                         ..IdentName::default()
@@ -747,82 +1467,96 @@ fn generate_function_component_declaration(function: &mut Function) {
             })),
         })
     });
+
+    Ok(())
 }
 
-fn generate_arrow_function_component_declaration(var_decl: &mut VarDecl) {
-    // TODO: Error handling for the unwrap calls
-    match var_decl
-        .decls
-        .first_mut()
-        .unwrap()
-        .init
-        .as_mut()
-        .unwrap()
-        .as_mut_arrow()
-    {
-        Some(expression) => {
-            let mut processor: Processor = Processor::new();
-            let props = match expression.params.first() {
-                Some(param) => param.clone(),
-                None => Pat::Object(ObjectPat {
-                    span: DUMMY_SP,
-                    props: vec![],
-                    optional: false,
-                    type_ann: None,
-                }),
-            };
-            let props_type_annotation = generate_type_annotation_from_props(props.clone());
+fn generate_arrow_function_component_declaration(
+    expression: &mut ArrowExpr,
+    options: ComponentOptions,
+    templates: &mut Vec<Stmt>,
+) -> ComponentResult<()> {
+    let mut processor: Processor = Processor::new();
+    let props = match expression.params.first() {
+        Some(param) => param.clone(),
+        None => Pat::Object(ObjectPat {
+            span: DUMMY_SP,
+            props: vec![],
+            optional: false,
+            type_ann: None,
+        }),
+    };
+    let props_type_annotation = generate_type_annotation_from_props(props.clone());
 
-            processor.process_arrow_expression(expression);
+    processor.process_arrow_expression(expression)?;
 
-            expression.body = Box::new(BlockStmtOrExpr::BlockStmt(BlockStmt {
-                stmts: generate_component_statements(
-                    &processor,
-                    props.clone(),
-                    props_type_annotation.is_some(),
-                ),
-                ..BlockStmt::default()
-            }));
-            expression.params = vec![];
-            expression.return_type = props_type_annotation.map(|type_annotation| {
-                Box::new(TsTypeAnn {
+    *expression.body = BlockStmtOrExpr::BlockStmt(BlockStmt {
+        stmts: generate_component_statements(
+            &processor,
+            props.clone(),
+            props_type_annotation.is_some(),
+            options,
+            templates,
+        )?,
+        ..BlockStmt::default()
+    });
+    expression.params = vec![];
+    expression.return_type = props_type_annotation.map(|type_annotation| {
+        Box::new(TsTypeAnn {
+            span: DUMMY_SP,
+            type_ann: Box::new(TsType::TsTypeRef(TsTypeRef {
+                span: DUMMY_SP,
+                type_name: TsEntityName::TsQualifiedName(Box::new(TsQualifiedName {
                     span: DUMMY_SP,
-                    type_ann: Box::new(TsType::TsTypeRef(TsTypeRef {
-                        span: DUMMY_SP,
-                        type_name: TsEntityName::TsQualifiedName(Box::new(TsQualifiedName {
-                            span: DUMMY_SP,
-                            left: TsEntityName::Ident(Ident {
-                                sym: "Palta".into(),
-                                ..Ident::default()
-                            }),
-                            right: IdentName {
-                                sym: "ComponentDefinition".into(),
-                                ..IdentName::default()
-                            },
-                        })),
-                        type_params: Some(Box::new(TsTypeParamInstantiation {
-                            span: DUMMY_SP,
-                            params: vec![type_annotation],
-                        })),
-                    })),
-                })
-            });
-        }
-        None => {
-            panic!("Component declaration must be an arrow function");
-        }
-    }
+                    left: TsEntityName::Ident(runtime_ident()),
+                    right: IdentName {
+                        sym: "ComponentDefinition".into(),
+                        ..IdentName::default()
+                    },
+                })),
+                type_params: Some(Box::new(TsTypeParamInstantiation {
+                    span: DUMMY_SP,
+                    params: vec![type_annotation],
+                })),
+            })),
+        })
+    });
+
+    Ok(())
 }
 
-pub fn generate_component_declaration(node: ComponentDeclaration) {
-    match node {
+// Templates of the component are declared with the module, so that they are
+// parsed once for all the instances. The ones of a component failing to
+// compile are dropped with it.
+pub fn generate_component_declaration(
+    node: ComponentDeclaration,
+    config: &Config,
+    templates: &mut Vec<Stmt>,
+) -> ComponentResult<()> {
+    if config.target == Target::Ssr {
+        return generate_ssr_component_declaration(node);
+    }
+
+    let options = ComponentOptions {
+        hot: config.hot(),
+        hydrate: config.hydrating(),
+    };
+    let declared = templates.len();
+
+    let result = match node {
         ComponentDeclaration::Function(function) => {
-            generate_function_component_declaration(function);
+            generate_function_component_declaration(function, options, templates)
         }
-        ComponentDeclaration::VarDecl(var_decl) => {
-            generate_arrow_function_component_declaration(var_decl);
+        ComponentDeclaration::Arrow(arrow) => {
+            generate_arrow_function_component_declaration(arrow, options, templates)
         }
     };
+
+    if result.is_err() {
+        templates.truncate(declared);
+    }
+
+    result
 }
 
 pub fn generate_expression_function(expression: &Expr) -> Box<Expr> {
@@ -830,10 +1564,11 @@ pub fn generate_expression_function(expression: &Expr) -> Box<Expr> {
         body: Box::new(BlockStmtOrExpr::BlockStmt(BlockStmt {
             stmts: vec![Stmt::Return(ReturnStmt {
                 arg: Some(Box::new(expression.clone())),
-                ..ReturnStmt::default()
+                span: expression.span(),
             })],
             ..BlockStmt::default()
         })),
+        span: expression.span(),
         ..ArrowExpr::default()
     }))
 }
@@ -846,10 +1581,7 @@ pub fn generate_element_update_child_call(
     Stmt::Expr(ExprStmt {
         expr: Box::new(Expr::Call(CallExpr {
             callee: Callee::Expr(Box::new(Expr::Member(MemberExpr {
-                obj: Box::new(Expr::Ident(Ident {
-                    sym: format!("__$element${}", element_position).into(),
-                    ..Ident::default()
-                })),
+                obj: Box::new(Expr::Ident(element_ident(element_position))),
                 prop: MemberProp::Ident(IdentName {
                     sym: "updateChild".into(),
                     ..IdentName::default()
@@ -870,6 +1602,7 @@ pub fn generate_element_update_child_call(
                     expr: Box::new(expression.clone()),
                 },
             ],
+            span: expression.span(),
             ..CallExpr::default()
         })),
         ..ExprStmt::default()
@@ -880,10 +1613,7 @@ pub fn generate_element_update_props_call(element_position: usize, props: &Objec
     Stmt::Expr(ExprStmt {
         expr: Box::new(Expr::Call(CallExpr {
             callee: Callee::Expr(Box::new(Expr::Member(MemberExpr {
-                obj: Box::new(Expr::Ident(Ident {
-                    sym: format!("__$element${}", element_position).into(),
-                    ..Ident::default()
-                })),
+                obj: Box::new(Expr::Ident(element_ident(element_position))),
                 prop: MemberProp::Ident(IdentName {
                     sym: "updateProps".into(),
                     ..IdentName::default()
@@ -894,12 +1624,30 @@ pub fn generate_element_update_props_call(element_position: usize, props: &Objec
                 spread: None,
                 expr: Box::new(Expr::Object(props.clone())),
             }],
+            span: props.span,
             ..CallExpr::default()
         })),
         ..ExprStmt::default()
     })
 }
 
+fn is_element_initialize_call(stmt: &Stmt, element_position: usize) -> bool {
+    let Some(Expr::Call(CallExpr {
+        callee: Callee::Expr(callee),
+        ..
+    })) = stmt.as_expr().map(|stmt| stmt.expr.as_ref())
+    else {
+        return false;
+    };
+
+    matches!(callee.as_ref(), Expr::Member(MemberExpr {
+        obj,
+        prop: MemberProp::Ident(prop),
+        ..
+    }) if prop.sym == "initialize"
+        && obj.as_ident().map(Ident::to_id) == Some(element_ident(element_position).to_id()))
+}
+
 pub fn generate_element_initialize_call(
     element_position: usize,
     props: &Option<ObjectLit>,
@@ -907,10 +1655,7 @@ pub fn generate_element_initialize_call(
     Stmt::Expr(ExprStmt {
         expr: Box::new(Expr::Call(CallExpr {
             callee: Callee::Expr(Box::new(Expr::Member(MemberExpr {
-                obj: Box::new(Expr::Ident(Ident {
-                    sym: format!("__$element${}", element_position).into(),
-                    ..Ident::default()
-                })),
+                obj: Box::new(Expr::Ident(element_ident(element_position))),
                 prop: MemberProp::Ident(IdentName {
                     sym: "initialize".into(),
                     ..IdentName::default()
@@ -927,8 +1672,237 @@ pub fn generate_element_initialize_call(
                     },
                 })),
             }],
+            span: props.as_ref().map_or(DUMMY_SP, |props| props.span),
+            ..CallExpr::default()
+        })),
+        ..ExprStmt::default()
+    })
+}
+
+pub fn generate_element_update_branch_call(
+    element_position: usize,
+    branch_index: usize,
+    initialize: &[Stmt],
+    update: &[Stmt],
+    effects: &[usize],
+) -> Stmt {
+    let mut args = vec![ExprOrSpread {
+        spread: None,
+        expr: Box::new(Expr::Lit(Lit::Num(Number {
+            span: DUMMY_SP,
+            value: branch_index as f64,
+            raw: None,
+        }))),
+    }];
+
+    if !initialize.is_empty() || !update.is_empty() || !effects.is_empty() {
+        for stmts in [initialize, update] {
+            args.push(ExprOrSpread {
+                spread: None,
+                expr: Box::new(Expr::Arrow(ArrowExpr {
+                    body: Box::new(BlockStmtOrExpr::BlockStmt(BlockStmt {
+                        stmts: stmts.to_vec(),
+                        ..BlockStmt::default()
+                    })),
+                    ..ArrowExpr::default()
+                })),
+            });
+        }
+    }
+
+    if !effects.is_empty() {
+        args.push(ExprOrSpread {
+            spread: None,
+            expr: Box::new(Expr::Array(ArrayLit {
+                elems: effects
+                    .iter()
+                    .map(|index| {
+                        Some(ExprOrSpread {
+                            spread: None,
+                            expr: Box::new(Expr::Ident(effect_ident(*index))),
+                        })
+                    })
+                    .collect(),
+                ..ArrayLit::default()
+            })),
+        });
+    }
+
+    Stmt::Expr(ExprStmt {
+        expr: Box::new(Expr::Call(CallExpr {
+            callee: Callee::Expr(Box::new(Expr::Member(MemberExpr {
+                obj: Box::new(Expr::Ident(element_ident(element_position))),
+                prop: MemberProp::Ident(IdentName {
+                    sym: "updateBranch".into(),
+                    ..IdentName::default()
+                }),
+                ..MemberExpr::default()
+            }))),
+            args,
+            ..CallExpr::default()
+        })),
+        ..ExprStmt::default()
+    })
+}
+
+pub fn generate_if_statement(
+    span: Span,
+    test: &Expr,
+    cons: Vec<Stmt>,
+    alt: Option<Vec<Stmt>>,
+) -> Stmt {
+    Stmt::If(IfStmt {
+        span,
+        test: Box::new(test.clone()),
+        cons: Box::new(Stmt::Block(BlockStmt {
+            stmts: cons,
+            ..BlockStmt::default()
+        })),
+        alt: alt.map(|alt| {
+            Box::new(Stmt::Block(BlockStmt {
+                stmts: alt,
+                ..BlockStmt::default()
+            }))
+        }),
+    })
+}
+
+// The statements following the first early return of a component are held
+// by a labeled block, which the returns of its if statements break out of
+pub fn generate_guard_block(stmts: Vec<Stmt>) -> Stmt {
+    Stmt::Labeled(LabeledStmt {
+        span: DUMMY_SP,
+        label: generated_ident("__$guard"),
+        body: Box::new(Stmt::Block(BlockStmt {
+            stmts,
+            ..BlockStmt::default()
+        })),
+    })
+}
+
+pub fn generate_guard_break() -> Stmt {
+    Stmt::Break(BreakStmt {
+        span: DUMMY_SP,
+        label: Some(generated_ident("__$guard")),
+    })
+}
+
+fn generate_template_declaration(ident: &Ident, html: String) -> Stmt {
+    Stmt::Decl(Decl::Var(Box::new(VarDecl {
+        kind: VarDeclKind::Const,
+        decls: vec![VarDeclarator {
+            span: DUMMY_SP,
+            name: Pat::Ident(BindingIdent {
+                id: ident.clone(),
+                ..BindingIdent::default()
+            }),
+            init: Some(Box::new(Expr::Call(CallExpr {
+                callee: Callee::Expr(Box::new(Expr::Member(MemberExpr {
+                    obj: Box::new(Expr::Ident(runtime_ident())),
+                    prop: MemberProp::Ident(IdentName {
+                        sym: "createTemplate".into(),
+                        ..IdentName::default()
+                    }),
+                    ..MemberExpr::default()
+                }))),
+                args: vec![ExprOrSpread {
+                    spread: None,
+                    expr: Box::new(Expr::Lit(Lit::Str(Str {
+                        span: DUMMY_SP,
+                        value: html.into(),
+                        raw: None,
+                    }))),
+                }],
+                ..CallExpr::default()
+            }))),
+            definite: false,
+        }],
+        ..VarDecl::default()
+    })))
+}
+
+pub fn generate_hot_registration(component: &Ident, id: String) -> Stmt {
+    Stmt::Expr(ExprStmt {
+        expr: Box::new(Expr::Call(CallExpr {
+            callee: Callee::Expr(Box::new(Expr::Member(MemberExpr {
+                obj: Box::new(Expr::Ident(runtime_ident())),
+                prop: MemberProp::Ident(IdentName {
+                    sym: "registerHotComponent".into(),
+                    ..IdentName::default()
+                }),
+                ..MemberExpr::default()
+            }))),
+            args: vec![
+                ExprOrSpread {
+                    spread: None,
+                    expr: Box::new(Expr::Ident(component.clone())),
+                },
+                ExprOrSpread {
+                    spread: None,
+                    expr: Box::new(Expr::Lit(Lit::Str(id.into()))),
+                },
+            ],
+            span: component.span,
             ..CallExpr::default()
         })),
         ..ExprStmt::default()
     })
 }
+
+fn generate_import_meta_hot() -> Box<Expr> {
+    Box::new(Expr::Member(MemberExpr {
+        obj: Box::new(Expr::MetaProp(MetaPropExpr {
+            span: DUMMY_SP,
+            kind: MetaPropKind::ImportMeta,
+        })),
+        prop: MemberProp::Ident(IdentName {
+            sym: "hot".into(),
+            ..IdentName::default()
+        }),
+        ..MemberExpr::default()
+    }))
+}
+
+// The module accepts its own updates, the registered components being
+// reloaded once the new version of the module has been evaluated
+pub fn generate_hot_accept() -> Stmt {
+    Stmt::If(IfStmt {
+        test: generate_import_meta_hot(),
+        cons: Box::new(Stmt::Block(BlockStmt {
+            stmts: vec![Stmt::Expr(ExprStmt {
+                expr: Box::new(Expr::Call(CallExpr {
+                    callee: Callee::Expr(Box::new(Expr::Member(MemberExpr {
+                        obj: generate_import_meta_hot(),
+                        prop: MemberProp::Ident(IdentName {
+                            sym: "accept".into(),
+                            ..IdentName::default()
+                        }),
+                        ..MemberExpr::default()
+                    }))),
+                    args: vec![ExprOrSpread {
+                        spread: None,
+                        expr: Box::new(Expr::Arrow(ArrowExpr {
+                            body: Box::new(BlockStmtOrExpr::Expr(Box::new(Expr::Call(CallExpr {
+                                callee: Callee::Expr(Box::new(Expr::Member(MemberExpr {
+                                    obj: Box::new(Expr::Ident(runtime_ident())),
+                                    prop: MemberProp::Ident(IdentName {
+                                        sym: "reloadHotComponents".into(),
+                                        ..IdentName::default()
+                                    }),
+                                    ..MemberExpr::default()
+                                }))),
+                                ..CallExpr::default()
+                            })))),
+                            ..ArrowExpr::default()
+                        })),
+                    }],
+                    ..CallExpr::default()
+                })),
+                ..ExprStmt::default()
+            })],
+            ..BlockStmt::default()
+        })),
+        alt: None,
+        ..IfStmt::default()
+    })
+}
@@ -13,18 +13,37 @@
  * See the License for the specific language governing permissions and
  * limitations under the License.
  */
+mod config;
+mod dependencies;
+mod error;
 mod generators;
+mod lowering;
+mod names;
 mod processor;
+mod ssr;
+mod template;
 mod utils;
 
+use std::collections::HashSet;
 use std::ops::Deref;
 
+use config::{Config, Detection};
 use generators::ComponentDeclaration;
-use swc_core::common::comments::CommentKind;
+use names::{generated_ident, Names, RuntimeHelper};
 use swc_core::common::comments::Comments;
+use swc_core::common::errors::HANDLER;
 use swc_core::common::BytePos;
+use swc_core::common::Span;
 use swc_core::common::Spanned;
 use swc_core::common::DUMMY_SP;
+use swc_core::common::{chain, Mark};
+use swc_core::ecma::ast::CallExpr;
+use swc_core::ecma::ast::Decl;
+use swc_core::ecma::ast::ExportDecl;
+use swc_core::ecma::ast::ExportDefaultExpr;
+use swc_core::ecma::ast::Expr;
+use swc_core::ecma::ast::FnDecl;
+use swc_core::ecma::ast::Id;
 use swc_core::ecma::ast::Ident;
 use swc_core::ecma::ast::ImportDecl;
 use swc_core::ecma::ast::ImportDefaultSpecifier;
@@ -32,69 +51,280 @@ use swc_core::ecma::ast::ImportPhase;
 use swc_core::ecma::ast::ImportSpecifier;
 use swc_core::ecma::ast::ModuleDecl;
 use swc_core::ecma::ast::ModuleItem;
+use swc_core::ecma::ast::Pat;
 use swc_core::ecma::ast::Program;
-use swc_core::ecma::visit::{as_folder, FoldWith, VisitMut, VisitMutWith};
+use swc_core::ecma::ast::Stmt;
+use swc_core::ecma::ast::VarDecl;
+use swc_core::ecma::ast::VarDeclKind;
+use swc_core::ecma::ast::VarDeclarator;
+use swc_core::ecma::transforms::base::resolver;
+use swc_core::ecma::visit::{as_folder, Fold, FoldWith, VisitMut, VisitMutWith};
+use swc_core::plugin::metadata::TransformPluginMetadataContextKind;
 use swc_core::plugin::plugin_transform;
 use swc_core::plugin::proxies::TransformPluginProgramMetadata;
 
-use generators::generate_component_declaration;
+use error::ComponentError;
+use generators::{generate_component_declaration, generate_hot_accept, generate_hot_registration};
+use utils::{
+    comment_has_marker, exported_names, function_returns_jsx, is_component_name,
+    is_palta_component_call, module_item_idents, returns_jsx, unwrap_component_call,
+    unwrap_component_declaration, JSDOC_COMPONENT_TAG,
+};
 
 pub struct TransformVisitor {
+    config: Config,
+    filename: String,
+    names: Names,
     comments: Option<Box<dyn Comments>>,
     has_component: bool,
     has_palta_import: bool,
+    // Bindings of the compiled components, registered for hot reloads
+    components: HashSet<Id>,
+    // Template declarations of the compiled components
+    templates: Vec<Stmt>,
 }
 
 impl TransformVisitor {
-    fn is_component(&self, pos: BytePos) -> bool {
-        if self.comments.is_none() {
+    fn new(config: Config, filename: String, comments: Option<Box<dyn Comments>>) -> Self {
+        TransformVisitor {
+            config,
+            filename,
+            names: Names::new(),
+            comments,
+            has_component: false,
+            has_palta_import: false,
+            components: HashSet::new(),
+            templates: vec![],
+        }
+    }
+
+    fn find_marker(&self, pos: BytePos, marker: &str, jsdoc_tag: Option<&str>) -> bool {
+        let Some(comments) = self.comments.as_ref() else {
             return false;
+        };
+
+        comments.get_leading(pos).is_some_and(|leading_comments| {
+            leading_comments
+                .iter()
+                .any(|comment| comment_has_marker(comment, marker, jsdoc_tag))
+        })
+    }
+
+    fn has_marker(&self, pos: BytePos, marker: &str, jsdoc_tag: Option<&str>) -> bool {
+        let has_marker = self.find_marker(pos, marker, jsdoc_tag);
+
+        if has_marker {
+            if let Some(comments) = self.comments.as_ref() {
+                comments.take_leading(pos);
+            }
         }
 
-        let comments = self.comments.as_ref().unwrap();
+        has_marker
+    }
+
+    fn has_component_marker(&self, pos: BytePos) -> bool {
+        self.has_marker(pos, &self.config.marker, Some(JSDOC_COMPONENT_TAG))
+    }
 
-        if !comments.has_leading(pos) {
+    // Markers left once a statement has been visited were not attached to
+    // anything compiled as a component
+    fn report_pending_marker(&self, span: Span) {
+        if self.find_marker(span.lo, &self.config.marker, Some(JSDOC_COMPONENT_TAG)) {
+            if let Some(comments) = self.comments.as_ref() {
+                comments.take_leading(span.lo);
+            }
+
+            ComponentError::MarkerNotApplied(span).emit();
+        }
+    }
+
+    fn is_component(
+        &self,
+        pos: BytePos,
+        name: Option<&Ident>,
+        returns_jsx: impl FnOnce() -> bool,
+    ) -> bool {
+        if self.has_marker(pos, &self.config.ignore_marker, None) {
             return false;
         }
 
-        let leading_comments = comments.to_owned().get_leading(pos).unwrap();
+        if self.has_component_marker(pos) {
+            return true;
+        }
 
-        let is_component = leading_comments.iter().any(|comment| {
-            comment.kind == CommentKind::Line && comment.text.trim() == "@Palta.component"
-        });
+        self.config.detection == Detection::Convention
+            && name.is_some_and(|name| is_component_name(&name.sym))
+            && returns_jsx()
+    }
+
+    fn transform_var_decl(&mut self, node: &mut VarDecl, is_component: bool) {
+        let name = node
+            .decls
+            .first()
+            .and_then(|decl| decl.name.as_ident())
+            .map(|binding| binding.id.clone());
+
+        match unwrap_component_declaration(node) {
+            Some(Err(error)) => error.emit(),
+            Some(Ok(())) => self.generate_declared_component(node, name),
+            None if is_component => self.generate_declared_component(node, name),
+            None => node.visit_mut_children_with(self),
+        }
+    }
+
+    fn generate_declared_component(&mut self, node: &mut VarDecl, name: Option<Ident>) {
+        let span = node.span;
 
-        if is_component {
-            comments.take_leading(pos);
+        match node
+            .decls
+            .first_mut()
+            .and_then(|decl| decl.init.as_deref_mut())
+        {
+            Some(Expr::Arrow(arrow)) => {
+                self.generate_component(ComponentDeclaration::Arrow(arrow), name)
+            }
+            Some(Expr::Fn(fn_expr)) => {
+                self.generate_component(ComponentDeclaration::Function(&mut fn_expr.function), name)
+            }
+            _ => ComponentError::ComponentNotArrowFunction(span).emit(),
         }
+    }
 
-        is_component
+    fn generate_component(&mut self, node: ComponentDeclaration, name: Option<Ident>) {
+        match generate_component_declaration(node, &self.config, &mut self.templates) {
+            Ok(()) => {
+                self.has_component = true;
+                self.components.extend(name.map(|name| name.to_id()));
+            }
+            Err(error) => error.emit(),
+        }
+    }
+
+    // Components are identified by their file and the name they are exported
+    // under, so the identity survives edits of the rest of the module
+    fn register_hot_components(&self, items: &mut Vec<ModuleItem>) {
+        let exports = exported_names(items);
+        let mut registered = false;
+
+        for item in std::mem::take(items) {
+            let components: Vec<Ident> = module_item_idents(&item)
+                .into_iter()
+                .filter(|ident| self.components.contains(&ident.to_id()))
+                .collect();
+
+            items.push(item);
+
+            for component in components {
+                let name = exports.get(&component.to_id()).unwrap_or(&component.sym);
+
+                items.push(ModuleItem::Stmt(generate_hot_registration(
+                    &component,
+                    format!("{}#{}", self.filename, name),
+                )));
+                registered = true;
+            }
+        }
+
+        if registered {
+            items.push(ModuleItem::Stmt(generate_hot_accept()));
+        }
     }
 }
 
 impl VisitMut for TransformVisitor {
-    fn visit_mut_function(&mut self, node: &mut swc_core::ecma::ast::Function) {
-        if self.is_component(node.span_lo()) {
-            generate_component_declaration(ComponentDeclaration::Function(node));
-            self.has_component = true;
+    fn visit_mut_fn_decl(&mut self, node: &mut FnDecl) {
+        if self.is_component(node.function.span_lo(), Some(&node.ident), || {
+            function_returns_jsx(&node.function)
+        }) {
+            self.generate_component(
+                ComponentDeclaration::Function(&mut node.function),
+                Some(node.ident.clone()),
+            );
         } else {
             node.visit_mut_children_with(self);
         }
     }
 
-    fn visit_mut_var_decl(&mut self, node: &mut swc_core::ecma::ast::VarDecl) {
-        if self.is_component(node.span_lo()) {
-            generate_component_declaration(ComponentDeclaration::VarDecl(node));
-            self.has_component = true;
+    // Function expressions declared in a variable are named after it, so they
+    // are detected with the declaration. Any other one needs a marker.
+    fn visit_mut_function(&mut self, node: &mut swc_core::ecma::ast::Function) {
+        if self.is_component(node.span_lo(), None, || false) {
+            self.generate_component(ComponentDeclaration::Function(node), None);
         } else {
             node.visit_mut_children_with(self);
         }
     }
 
+    fn visit_mut_var_decl(&mut self, node: &mut VarDecl) {
+        let declarator = node.decls.first();
+        let name = declarator.and_then(|decl| decl.name.as_ident());
+        let init = declarator.and_then(|decl| decl.init.as_deref());
+
+        let is_component =
+            self.is_component(node.span_lo(), name.map(|name| &name.id), || match init {
+                Some(Expr::Arrow(arrow)) => returns_jsx(&arrow.body),
+                Some(Expr::Fn(fn_expr)) => function_returns_jsx(&fn_expr.function),
+                _ => false,
+            });
+
+        self.transform_var_decl(node, is_component);
+    }
+
+    // Comments before `export` are not attached to the exported declaration
+    fn visit_mut_export_decl(&mut self, node: &mut ExportDecl) {
+        if self.has_marker(node.span.lo, &self.config.ignore_marker, None) {
+            return;
+        }
+
+        if !self.has_component_marker(node.span.lo) {
+            node.visit_mut_children_with(self);
+            return;
+        }
+
+        match &mut node.decl {
+            Decl::Var(var_decl) => self.transform_var_decl(var_decl, true),
+            Decl::Fn(fn_decl) => self.generate_component(
+                ComponentDeclaration::Function(&mut fn_decl.function),
+                Some(fn_decl.ident.clone()),
+            ),
+            _ => ComponentError::MarkerNotApplied(node.span).emit(),
+        }
+    }
+
+    fn visit_mut_call_expr(&mut self, node: &mut CallExpr) {
+        if is_palta_component_call(node) {
+            ComponentError::MarkerNotApplied(node.span).emit();
+        }
+
+        node.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_stmt(&mut self, node: &mut Stmt) {
+        node.visit_mut_children_with(self);
+
+        self.report_pending_marker(node.span());
+    }
+
+    fn visit_mut_module_item(&mut self, node: &mut ModuleItem) {
+        node.visit_mut_children_with(self);
+
+        self.report_pending_marker(node.span());
+    }
+
     fn visit_mut_export_default_decl(&mut self, node: &mut swc_core::ecma::ast::ExportDefaultDecl) {
-        if self.is_component(node.span_lo()) {
-            if let swc_core::ecma::ast::DefaultDecl::Fn(func) = &mut node.decl {
-                generate_component_declaration(ComponentDeclaration::Function(&mut func.function));
-                self.has_component = true;
+        if let swc_core::ecma::ast::DefaultDecl::Fn(func) = &mut node.decl {
+            if self.is_component(node.span.lo, func.ident.as_ref(), || {
+                function_returns_jsx(&func.function)
+            }) {
+                // Anonymous components are named to be registered for hot reloads
+                if self.config.hot() && func.ident.is_none() {
+                    func.ident = Some(generated_ident("__$component"));
+                }
+
+                self.generate_component(
+                    ComponentDeclaration::Function(&mut func.function),
+                    func.ident.clone(),
+                );
                 return;
             }
         }
@@ -102,104 +332,346 @@ impl VisitMut for TransformVisitor {
         node.visit_mut_children_with(self);
     }
 
+    fn visit_mut_export_default_expr(&mut self, node: &mut ExportDefaultExpr) {
+        match unwrap_component_call(&mut node.expr) {
+            Some(Err(error)) => error.emit(),
+            Some(Ok(())) => {
+                if let Some(arrow) = node.expr.as_mut_arrow() {
+                    self.generate_component(ComponentDeclaration::Arrow(arrow), None);
+                }
+            }
+            None => node.visit_mut_children_with(self),
+        }
+    }
+
     fn visit_mut_import_decl(&mut self, node: &mut ImportDecl) {
         node.visit_mut_children_with(self);
 
-        if node.src.deref().value == "palta" {
-            node.specifiers = node
-                .specifiers
-                .iter()
-                .filter_map(|specifier| match specifier {
-                    ImportSpecifier::Default(import) => {
-                        let mut new_import = import.clone();
-                        new_import.local.sym = "Palta".into();
-                        self.has_palta_import = true;
-                        Some(ImportSpecifier::Default(new_import))
-                    }
-                    ImportSpecifier::Named(import) => {
-                        if import.local.sym == "$effect"
-                            || import.local.sym == "$state"
-                            || import.local.sym == "Children"
-                        {
-                            None
-                        } else {
-                            Some(specifier.clone())
-                        }
-                    }
-                    _ => None,
-                })
-                .collect();
+        if node.src.deref().value == *self.config.import_source {
+            node.specifiers.retain(|specifier| match specifier {
+                ImportSpecifier::Named(import) => RuntimeHelper::from_specifier(import).is_none(),
+                _ => true,
+            });
 
-            if node.specifiers.is_empty() {
+            if node.specifiers.is_empty() && !self.has_palta_import {
                 self.has_palta_import = true;
                 node.specifiers
                     .push(ImportSpecifier::Default(ImportDefaultSpecifier {
                         span: DUMMY_SP,
-                        local: Ident {
-                            sym: "Palta".into(),
-                            ..Ident::default()
-                        },
+                        local: self.names.runtime().clone(),
                     }));
             }
         }
     }
 
     fn visit_mut_module(&mut self, node: &mut swc_core::ecma::ast::Module) {
-        node.visit_mut_children_with(self);
+        for item in node.body.iter() {
+            if let ModuleItem::ModuleDecl(ModuleDecl::Import(import)) = item {
+                if import.src.value == *self.config.import_source
+                    && self.names.add_runtime_import(import)
+                {
+                    self.has_palta_import = true;
+                }
+            }
+        }
 
-        if !self.has_component || self.has_palta_import {
-            return;
+        let names = self.names.clone();
+
+        names.enter(|| {
+            if self.config.hot() {
+                name_default_components(&mut node.body);
+            }
+
+            node.visit_mut_children_with(self);
+
+            let imports = node
+                .body
+                .iter()
+                .take_while(|item| matches!(item, ModuleItem::ModuleDecl(ModuleDecl::Import(_))))
+                .count();
+
+            node.body.splice(
+                imports..imports,
+                self.templates.drain(..).map(ModuleItem::Stmt),
+            );
+
+            if self.config.hot() {
+                self.register_hot_components(&mut node.body);
+            }
+        });
+
+        if self.has_component && !self.has_palta_import {
+            let mut new_imports = vec![ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+                span: Default::default(),
+                specifiers: vec![ImportSpecifier::Default(ImportDefaultSpecifier {
+                    span: DUMMY_SP,
+                    local: self.names.runtime().clone(),
+                })],
+                src: Box::new(self.config.import_source.as_str().into()),
+                type_only: false,
+                with: None,
+                phase: ImportPhase::Evaluation,
+            }))];
+
+            new_imports.append(&mut node.body);
+
+            node.body = new_imports;
         }
 
-        let mut new_imports = vec![ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
-            span: Default::default(),
-            specifiers: vec![ImportSpecifier::Default(ImportDefaultSpecifier {
-                span: DUMMY_SP,
-                local: Ident {
-                    sym: "Palta".into(),
-                    ..Ident::default()
-                },
-            })],
-            src: Box::new("palta".into()),
-            type_only: false,
-            with: None,
-            phase: ImportPhase::Evaluation,
-        }))];
+        self.names.rename_generated_idents(node);
+    }
+}
+
+// Anonymous components are named to be registered for hot reloads, the
+// wrapped component exported by default being declared before its export
+fn name_default_components(items: &mut Vec<ModuleItem>) {
+    for item in std::mem::take(items) {
+        let ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(export)) = &item else {
+            items.push(item);
+            continue;
+        };
+
+        if !export.expr.as_call().is_some_and(is_palta_component_call) {
+            items.push(item);
+            continue;
+        }
 
-        new_imports.append(&mut node.body);
+        let name = generated_ident("__$component");
 
-        node.body = new_imports;
+        items.push(ModuleItem::Stmt(Stmt::Decl(Decl::Var(Box::new(VarDecl {
+            span: export.span,
+            kind: VarDeclKind::Const,
+            decls: vec![VarDeclarator {
+                span: export.span,
+                name: Pat::Ident(name.clone().into()),
+                init: Some(export.expr.clone()),
+                definite: false,
+            }],
+            ..VarDecl::default()
+        })))));
+        items.push(ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(
+            ExportDefaultExpr {
+                span: export.span,
+                expr: Box::new(Expr::Ident(name)),
+            },
+        )));
     }
 }
 
+// User bindings are resolved before the transform, and only the generated
+// ones are renamed after it when they collide with them
+pub fn transform(
+    config: Config,
+    filename: String,
+    comments: Option<Box<dyn Comments>>,
+    unresolved_mark: Mark,
+    typescript: bool,
+) -> impl Fold {
+    chain!(
+        resolver(unresolved_mark, Mark::new(), typescript),
+        as_folder(TransformVisitor::new(config, filename, comments))
+    )
+}
+
+// Invalid options are reported like compilation errors instead of aborting
+// the host, the module being compiled with the default configuration
+fn read_plugin_config(config: Option<&str>) -> Config {
+    Config::parse(config).unwrap_or_else(|error| {
+        HANDLER.with(|handler| handler.err(&error));
+        Config::default()
+    })
+}
+
 #[plugin_transform]
 pub fn process_transform(program: Program, metadata: TransformPluginProgramMetadata) -> Program {
-    program.fold_with(&mut as_folder(TransformVisitor {
-        comments: match metadata.comments {
-            Some(comments) => Some(Box::new(comments)),
-            None => None,
-        },
-        has_component: false,
-        has_palta_import: false,
-    }))
+    let config = read_plugin_config(metadata.get_transform_plugin_config().as_deref());
+    let filename = metadata
+        .get_context(&TransformPluginMetadataContextKind::Filename)
+        .unwrap_or_default();
+    let comments: Option<Box<dyn Comments>> = match metadata.comments {
+        Some(comments) => Some(Box::new(comments)),
+        None => None,
+    };
+
+    program.fold_with(&mut transform(
+        config,
+        filename,
+        comments,
+        metadata.unresolved_mark,
+        true,
+    ))
 }
 
 #[cfg(test)]
 mod tests {
-    use std::path::PathBuf;
+    use std::fs;
+    use std::path::{Path, PathBuf};
 
     use swc_core::{
         ecma::{
-            parser::{EsSyntax, Syntax, TsSyntax},
+            ast::EsVersion,
+            codegen::{text_writer::JsWriter, Emitter},
+            parser::{parse_file_as_module, EsSyntax, Syntax, TsSyntax},
             transforms::testing::{test_fixture, FixtureTestConfig},
-            visit::as_folder,
+            visit::FoldWith,
         },
-        testing,
+        testing::{self, NormalizedOutput},
+    };
+
+    use swc_core::common::{
+        comments::SingleThreadedComments,
+        errors::{Level, HANDLER},
+        FileName, Mark,
     };
 
-    use crate::TransformVisitor;
+    use crate::config::Target;
+    use crate::{read_plugin_config, transform, Config};
+
+    fn read_config(input: &Path) -> Config {
+        let config = fs::read_to_string(input.with_file_name("config.json")).ok();
+
+        read_plugin_config(config.as_deref())
+    }
+
+    fn filename(input: &Path) -> String {
+        let input = input
+            .strip_prefix(env!("CARGO_MANIFEST_DIR"))
+            .unwrap_or(input);
+
+        input.display().to_string()
+    }
+
+    #[test]
+    fn config_unknown_key() {
+        let error = Config::parse(Some(r#"{ "importSorce": "palta" }"#)).unwrap_err();
+
+        assert!(error.contains("unknown field `importSorce`"));
+    }
+
+    // The statements following an early return are compiled once, however
+    // many if statements return before them
+    #[test]
+    fn sequential_guards_size() {
+        let output = fs::read_to_string(
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/sequential_guards/output.js"),
+        )
+        .unwrap();
+
+        assert_eq!(output.matches("Palta.createElement(\"div\"").count(), 2);
+        assert_eq!(output.matches("Palta.createElement(").count(), 12);
+        assert!(!output.contains("__$element$7"));
+    }
+
+    // Markers left unapplied are only reported as warnings, the rest of the
+    // module being compiled
+    #[test]
+    fn marker_not_applied_warnings() {
+        let input =
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/errors/marker_not_applied/input.tsx");
+        let diagnostics = testing::Tester::new()
+            .errors(|cm, handler| {
+                let fm = cm.load_file(&input).unwrap();
+                let comments = SingleThreadedComments::default();
+                let module = parse_file_as_module(
+                    &fm,
+                    Syntax::Typescript(TsSyntax {
+                        tsx: true,
+                        ..Default::default()
+                    }),
+                    EsVersion::latest(),
+                    Some(&comments),
+                    &mut vec![],
+                )
+                .unwrap();
+
+                HANDLER.set(&handler, || {
+                    module.fold_with(&mut transform(
+                        read_config(&input),
+                        filename(&input),
+                        Some(Box::new(comments.clone())),
+                        Mark::new(),
+                        true,
+                    ))
+                });
 
-    #[testing::fixture("tests/**/input.tsx")]
+                Err::<(), ()>(())
+            })
+            .unwrap_err();
+
+        assert_eq!(diagnostics.len(), 2);
+        assert!(diagnostics
+            .iter()
+            .all(|diagnostic| diagnostic.level == Level::Warning));
+    }
+
+    // The fixtures are printed after a hygiene pass renaming shadowed
+    // bindings, so the transformed module is printed as is here
+    #[test]
+    fn user_bindings_kept() {
+        let output = testing::run_test(false, |cm, _| {
+            let fm = cm.new_source_file(
+                FileName::Anon.into(),
+                r#"
+                // @Palta.component
+                const Grid = ({ rows }) => {
+                  const Palta = "runtime";
+                  const __$root = "root";
+
+                  return (
+                    <table title={Palta + __$root}>
+                      {rows.map((row, i) => (
+                        <tr key={i}>{row.map((cell, i) => <td key={i}>{cell}</td>)}</tr>
+                      ))}
+                    </table>
+                  );
+                };
+                "#
+                .into(),
+            );
+            let comments = SingleThreadedComments::default();
+            let module = parse_file_as_module(
+                &fm,
+                Syntax::Es(EsSyntax {
+                    jsx: true,
+                    ..Default::default()
+                }),
+                EsVersion::latest(),
+                Some(&comments),
+                &mut vec![],
+            )
+            .unwrap()
+            .fold_with(&mut transform(
+                Config::default(),
+                "grid.jsx".into(),
+                Some(Box::new(comments.clone())),
+                Mark::new(),
+                false,
+            ));
+
+            let mut code = vec![];
+            Emitter {
+                cfg: Default::default(),
+                cm: cm.clone(),
+                comments: None,
+                wr: JsWriter::new(cm.clone(), "\n", &mut code, None),
+            }
+            .emit_module(&module)
+            .unwrap();
+
+            Ok(String::from_utf8(code).unwrap())
+        })
+        .unwrap();
+
+        assert!(output.contains("const Palta = \"runtime\""));
+        assert!(output.contains("const __$root = \"root\""));
+        assert!(output.contains("(row, i)=>"));
+        assert!(output.contains("(cell, i)=>"));
+        assert!(!output.contains("i1"));
+        assert!(output.contains("import Palta1 from \"palta\""));
+        assert!(output.contains("let __$root1 = "));
+    }
+
+    #[testing::fixture("tests/**/input.tsx", exclude("tests/errors/"))]
     fn typescript(input: PathBuf) {
         let output = input.with_file_name("output.ts");
         test_fixture(
@@ -208,11 +680,13 @@ mod tests {
                 ..Default::default()
             }),
             &|t| {
-                as_folder(TransformVisitor {
-                    comments: Some(Box::new(t.comments.clone())),
-                    has_component: false,
-                    has_palta_import: false,
-                })
+                transform(
+                    read_config(&input),
+                    filename(&input),
+                    Some(Box::new(t.comments.clone())),
+                    Mark::new(),
+                    true,
+                )
             },
             &input,
             &output,
@@ -223,7 +697,7 @@ mod tests {
         );
     }
 
-    #[testing::fixture("tests/**/input.jsx")]
+    #[testing::fixture("tests/**/input.jsx", exclude("tests/errors/"))]
     fn javascript(input: PathBuf) {
         let output = input.with_file_name("output.js");
         test_fixture(
@@ -232,11 +706,13 @@ mod tests {
                 ..Default::default()
             }),
             &|t| {
-                as_folder(TransformVisitor {
-                    comments: Some(Box::new(t.comments.clone())),
-                    has_component: false,
-                    has_palta_import: false,
-                })
+                transform(
+                    read_config(&input),
+                    filename(&input),
+                    Some(Box::new(t.comments.clone())),
+                    Mark::new(),
+                    false,
+                )
             },
             &input,
             &output,
@@ -246,4 +722,135 @@ mod tests {
             },
         );
     }
+
+    // Every input is compiled for the server as well
+    #[testing::fixture("tests/**/input.jsx", exclude("tests/errors/"))]
+    fn ssr(input: PathBuf) {
+        let output = input.with_file_name("output.ssr.js");
+        test_fixture(
+            Syntax::Es(EsSyntax {
+                jsx: true,
+                ..Default::default()
+            }),
+            &|t| {
+                transform(
+                    Config {
+                        target: Target::Ssr,
+                        ..read_config(&input)
+                    },
+                    filename(&input),
+                    Some(Box::new(t.comments.clone())),
+                    Mark::new(),
+                    false,
+                )
+            },
+            &input,
+            &output,
+            FixtureTestConfig {
+                sourcemap: false,
+                allow_error: false,
+            },
+        );
+    }
+
+    #[testing::fixture("tests/errors/**/input.tsx")]
+    fn errors(input: PathBuf) {
+        let output = input.with_file_name("output.ts");
+        test_fixture(
+            Syntax::Typescript(TsSyntax {
+                tsx: true,
+                ..Default::default()
+            }),
+            &|t| {
+                transform(
+                    read_config(&input),
+                    filename(&input),
+                    Some(Box::new(t.comments.clone())),
+                    Mark::new(),
+                    true,
+                )
+            },
+            &input,
+            &output,
+            FixtureTestConfig {
+                sourcemap: false,
+                allow_error: true,
+            },
+        );
+    }
+
+    fn snippet(line: &str, col: usize) -> String {
+        line.chars()
+            .skip(col)
+            .take_while(|c| !c.is_whitespace())
+            .take(24)
+            .collect()
+    }
+
+    // Renders every mapping produced for the transformed module as the
+    // generated token next to the source token it points back to.
+    #[testing::fixture("tests/sourcemaps/**/input.tsx")]
+    fn sourcemaps(input: PathBuf) {
+        let output = input.with_file_name("output.mappings");
+        let mappings = testing::run_test(false, |cm, _| {
+            let fm = cm.load_file(&input).unwrap();
+            let comments = SingleThreadedComments::default();
+            let module = parse_file_as_module(
+                &fm,
+                Syntax::Typescript(TsSyntax {
+                    tsx: true,
+                    ..Default::default()
+                }),
+                EsVersion::latest(),
+                Some(&comments),
+                &mut vec![],
+            )
+            .unwrap()
+            .fold_with(&mut transform(
+                read_config(&input),
+                filename(&input),
+                Some(Box::new(comments.clone())),
+                Mark::new(),
+                true,
+            ));
+
+            let mut code = vec![];
+            let mut srcmap = vec![];
+            Emitter {
+                cfg: Default::default(),
+                cm: cm.clone(),
+                comments: None,
+                wr: JsWriter::new(cm.clone(), "\n", &mut code, Some(&mut srcmap)),
+            }
+            .emit_module(&module)
+            .unwrap();
+
+            let code = String::from_utf8(code).unwrap();
+            let generated: Vec<&str> = code.lines().collect();
+
+            Ok(srcmap
+                .iter()
+                .map(|(pos, line_col)| {
+                    let loc = cm.lookup_char_pos(*pos);
+                    let source = loc.file.get_line(loc.line - 1).unwrap_or_default();
+                    let line = line_col.line as usize;
+
+                    format!(
+                        "{}:{} {} <- {}:{} {}\n",
+                        line + 1,
+                        line_col.col,
+                        snippet(generated.get(line).unwrap_or(&""), line_col.col as usize),
+                        loc.line,
+                        loc.col.0,
+                        snippet(&source, loc.col.0),
+                    )
+                })
+                .collect::<String>())
+        })
+        .unwrap();
+
+        NormalizedOutput::from(mappings)
+            .compare_to_file(output)
+            .unwrap();
+    }
 }
@@ -0,0 +1,201 @@
+/**
+ * Copyright 2024 Aubin REBILLAT
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+use swc_core::common::{Span, Spanned};
+use swc_core::ecma::ast::{
+    ArrowExpr, BinaryOp, BlockStmtOrExpr, Bool, Expr, Function, JSXElement, JSXFragment, Lit, Pat,
+    UnaryOp,
+};
+use swc_core::ecma::visit::{VisitMut, VisitMutWith};
+
+use crate::dependencies::{get_declared_identifiers, get_free_identifiers};
+use crate::utils::{get_jsx_callback, take_jsx_keys};
+
+// Path of a conditional child choosing between JSX elements. The DOM renders
+// it through a branch element, so that only the active elements are created,
+// and the server renders the same paths.
+pub enum BranchPath<'a> {
+    Condition {
+        span: Span,
+        test: &'a Expr,
+        cons: Box<BranchPath<'a>>,
+        alt: Box<BranchPath<'a>>,
+    },
+    // A falsy test is rendered like React does, so `count && <p/>` renders
+    // `0` when count is zero, and conditions always evaluating to a boolean
+    // render nothing
+    And {
+        span: Span,
+        test: &'a Expr,
+        cons: Box<BranchPath<'a>>,
+        renders_test: bool,
+    },
+    Leaf(&'a Expr),
+}
+
+pub fn get_branch_path(expr: &Expr) -> Option<BranchPath<'_>> {
+    is_branch_expression(expr).then(|| branch_path(expr))
+}
+
+fn branch_path(expr: &Expr) -> BranchPath<'_> {
+    match expr {
+        Expr::Paren(paren_expr) => branch_path(&paren_expr.expr),
+        Expr::Cond(cond_expr) => BranchPath::Condition {
+            span: cond_expr.span,
+            test: &cond_expr.test,
+            cons: Box::new(branch_path(&cond_expr.cons)),
+            alt: Box::new(branch_path(&cond_expr.alt)),
+        },
+        Expr::Bin(bin_expr) if bin_expr.op == BinaryOp::LogicalAnd => BranchPath::And {
+            span: bin_expr.span,
+            test: &bin_expr.left,
+            cons: Box::new(branch_path(&bin_expr.right)),
+            renders_test: !is_boolean_expression(&bin_expr.left),
+        },
+        expr => BranchPath::Leaf(expr),
+    }
+}
+
+fn is_branch_expression(expr: &Expr) -> bool {
+    match expr {
+        Expr::Paren(paren_expr) => is_branch_expression(&paren_expr.expr),
+        Expr::Cond(cond_expr) => is_branch_leaf(&cond_expr.cons) && is_branch_leaf(&cond_expr.alt),
+        Expr::Bin(bin_expr) if bin_expr.op == BinaryOp::LogicalAnd => {
+            is_branch_leaf(&bin_expr.right)
+        }
+        _ => false,
+    }
+}
+
+fn is_branch_leaf(expr: &Expr) -> bool {
+    match expr {
+        Expr::Paren(paren_expr) => is_branch_leaf(&paren_expr.expr),
+        Expr::JSXElement(_) | Expr::JSXFragment(_) => true,
+        Expr::Lit(Lit::Null(_)) | Expr::Lit(Lit::Bool(Bool { value: false, .. })) => true,
+        Expr::Ident(ident) => ident.sym == "undefined",
+        expr => is_branch_expression(expr),
+    }
+}
+
+// Conditions which always evaluate to a boolean, and render nothing when false
+fn is_boolean_expression(expr: &Expr) -> bool {
+    match expr {
+        Expr::Paren(paren_expr) => is_boolean_expression(&paren_expr.expr),
+        Expr::Lit(Lit::Bool(_)) => true,
+        Expr::Unary(unary_expr) => unary_expr.op == UnaryOp::Bang,
+        Expr::Bin(bin_expr) => match bin_expr.op {
+            BinaryOp::EqEq
+            | BinaryOp::NotEq
+            | BinaryOp::EqEqEq
+            | BinaryOp::NotEqEq
+            | BinaryOp::Lt
+            | BinaryOp::LtEq
+            | BinaryOp::Gt
+            | BinaryOp::GtEq
+            | BinaryOp::In
+            | BinaryOp::InstanceOf => true,
+            BinaryOp::LogicalAnd | BinaryOp::LogicalOr => {
+                is_boolean_expression(&bin_expr.left) && is_boolean_expression(&bin_expr.right)
+            }
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+// A JSX returning callback given to a call in children, rendering the items
+// of a list
+pub struct ListCallback {
+    pub span: Span,
+    pub params: Vec<Pat>,
+    pub body: BlockStmtOrExpr,
+    // Key attribute of the returned elements, removed from the body
+    pub key: Option<Expr>,
+}
+
+fn get_list_callback(expr: &Expr) -> Option<ListCallback> {
+    let (params, mut body) = get_jsx_callback(expr)?;
+
+    // The key is evaluated by the callback, so it cannot use the
+    // declarations of its body.
+    let declarations = get_declared_identifiers(&body);
+    let key = take_jsx_keys(&mut body).into_iter().find(|key| {
+        get_free_identifiers(&[], &BlockStmtOrExpr::Expr(Box::new(key.clone())))
+            .iter()
+            .all(|ident| !declarations.contains(&ident.to_id()))
+    });
+
+    Some(ListCallback {
+        span: expr.span(),
+        params,
+        body,
+        key,
+    })
+}
+
+pub trait ListLowering {
+    // Expression replacing the callback given to the call
+    fn lower_list(&mut self, list: ListCallback) -> Expr;
+
+    // Expression replacing the call given the number of its lowered callbacks
+    fn lower_list_call(&mut self, call: Expr, _lists: usize) -> Expr {
+        call
+    }
+}
+
+pub fn lower_lists<L: ListLowering>(expr: &mut Expr, lowering: &mut L) {
+    expr.visit_mut_with(&mut ListCallbackReplacer { lowering });
+}
+
+struct ListCallbackReplacer<'a, L> {
+    lowering: &'a mut L,
+}
+
+impl<L: ListLowering> VisitMut for ListCallbackReplacer<'_, L> {
+    fn visit_mut_expr(&mut self, expr: &mut Expr) {
+        expr.visit_mut_children_with(self);
+
+        let Expr::Call(call_expression) = expr else {
+            return;
+        };
+
+        let mut lists = 0;
+
+        for arg in call_expression
+            .args
+            .iter_mut()
+            .filter(|arg| arg.spread.is_none())
+        {
+            if let Some(list) = get_list_callback(&arg.expr) {
+                *arg.expr = self.lowering.lower_list(list);
+                lists += 1;
+            }
+        }
+
+        if lists > 0 {
+            *expr = self.lowering.lower_list_call(std::mem::take(expr), lists);
+        }
+    }
+
+    // Callbacks nested in other functions may run several times per update,
+    // and elements are processed with their own expressions.
+    fn visit_mut_arrow_expr(&mut self, _: &mut ArrowExpr) {}
+
+    fn visit_mut_function(&mut self, _: &mut Function) {}
+
+    fn visit_mut_jsx_element(&mut self, _: &mut JSXElement) {}
+
+    fn visit_mut_jsx_fragment(&mut self, _: &mut JSXFragment) {}
+}
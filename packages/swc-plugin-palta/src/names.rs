@@ -0,0 +1,280 @@
+/**
+ * Copyright 2024 Aubin REBILLAT
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+
+use swc_core::atoms::Atom;
+use swc_core::common::{Mark, SyntaxContext, DUMMY_SP};
+use swc_core::ecma::ast::{
+    Expr, Id, Ident, ImportDecl, ImportNamedSpecifier, ImportSpecifier, JSXElementName, JSXObject,
+    MemberExpr, MemberProp, Module, ModuleExportName,
+};
+use swc_core::ecma::visit::{Visit, VisitMut, VisitMutWith, VisitWith};
+
+// Exports of the runtime which are compiled away instead of being called
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum RuntimeHelper {
+    State,
+    Effect,
+    Children,
+    Slot,
+    RenderChildren,
+}
+
+impl RuntimeHelper {
+    fn from_export(name: &str) -> Option<Self> {
+        match name {
+            "$state" => Some(RuntimeHelper::State),
+            "$effect" => Some(RuntimeHelper::Effect),
+            "Children" => Some(RuntimeHelper::Children),
+            "Slot" => Some(RuntimeHelper::Slot),
+            "$children" => Some(RuntimeHelper::RenderChildren),
+            _ => None,
+        }
+    }
+
+    pub fn from_specifier(specifier: &ImportNamedSpecifier) -> Option<Self> {
+        match &specifier.imported {
+            Some(ModuleExportName::Ident(ident)) => Self::from_export(&ident.sym),
+            Some(ModuleExportName::Str(name)) => Self::from_export(&name.value),
+            None => Self::from_export(&specifier.local.sym),
+        }
+    }
+}
+
+// Generated bindings share a syntax context of their own, so that the
+// hygiene pass renames them instead of letting them collide with user code.
+#[derive(Clone)]
+pub struct Names {
+    ctxt: SyntaxContext,
+    runtime: Ident,
+    helpers: HashMap<Id, RuntimeHelper>,
+    namespaces: HashSet<Id>,
+}
+
+thread_local! {
+    static NAMES: RefCell<Option<Names>> = const { RefCell::new(None) };
+}
+
+impl Names {
+    pub fn new() -> Self {
+        let ctxt = SyntaxContext::empty().apply_mark(Mark::new());
+
+        Names {
+            ctxt,
+            runtime: Ident::new("Palta".into(), DUMMY_SP, ctxt),
+            helpers: HashMap::new(),
+            namespaces: HashSet::new(),
+        }
+    }
+
+    // Records the bindings of an import of the runtime, returning whether it
+    // has a default import. The runtime is referenced through that import
+    // when there is one, and through a generated import otherwise.
+    pub fn add_runtime_import(&mut self, import: &ImportDecl) -> bool {
+        let mut has_default = false;
+
+        for specifier in import.specifiers.iter() {
+            match specifier {
+                ImportSpecifier::Default(default) => {
+                    self.runtime = default.local.clone();
+                    has_default = true;
+                }
+                ImportSpecifier::Named(named) => {
+                    if let Some(helper) = RuntimeHelper::from_specifier(named) {
+                        self.helpers.insert(named.local.to_id(), helper);
+                    }
+                }
+                ImportSpecifier::Namespace(namespace) => {
+                    self.namespaces.insert(namespace.local.to_id());
+                }
+            }
+        }
+
+        has_default
+    }
+
+    fn is_namespace_helper(&self, namespace: &Ident, name: &Atom, helper: RuntimeHelper) -> bool {
+        self.namespaces.contains(&namespace.to_id())
+            && RuntimeHelper::from_export(name) == Some(helper)
+    }
+
+    pub fn runtime(&self) -> &Ident {
+        &self.runtime
+    }
+
+    // Generated bindings sharing a name with user ones are renamed, the user
+    // code being left as written
+    pub fn rename_generated_idents(&self, module: &mut Module) {
+        let mut collector = SymbolCollector {
+            ctxt: self.ctxt,
+            user: HashSet::new(),
+            generated: HashSet::new(),
+        };
+
+        module.visit_with(&mut collector);
+
+        let mut taken: HashSet<Atom> = collector
+            .user
+            .union(&collector.generated)
+            .cloned()
+            .collect();
+        let mut renamed = HashMap::new();
+
+        let mut colliding: Vec<&Atom> = collector.generated.intersection(&collector.user).collect();
+
+        colliding.sort();
+
+        for sym in colliding {
+            let new_sym = (1..)
+                .map(|n| Atom::from(format!("{}{}", sym, n)))
+                .find(|new_sym| !taken.contains(new_sym))
+                .unwrap();
+
+            taken.insert(new_sym.clone());
+            renamed.insert(sym.clone(), new_sym);
+        }
+
+        if !renamed.is_empty() {
+            module.visit_mut_with(&mut GeneratedIdentRenamer {
+                ctxt: self.ctxt,
+                renamed,
+            });
+        }
+    }
+
+    pub fn enter<R>(&self, f: impl FnOnce() -> R) -> R {
+        let previous = NAMES.with(|names| names.replace(Some(self.clone())));
+        let result = f();
+
+        NAMES.with(|names| *names.borrow_mut() = previous);
+
+        result
+    }
+}
+
+struct SymbolCollector {
+    ctxt: SyntaxContext,
+    user: HashSet<Atom>,
+    generated: HashSet<Atom>,
+}
+
+impl Visit for SymbolCollector {
+    fn visit_ident(&mut self, ident: &Ident) {
+        if ident.ctxt == self.ctxt {
+            self.generated.insert(ident.sym.clone());
+        } else {
+            self.user.insert(ident.sym.clone());
+        }
+    }
+}
+
+struct GeneratedIdentRenamer {
+    ctxt: SyntaxContext,
+    renamed: HashMap<Atom, Atom>,
+}
+
+impl VisitMut for GeneratedIdentRenamer {
+    fn visit_mut_ident(&mut self, ident: &mut Ident) {
+        if ident.ctxt != self.ctxt {
+            return;
+        }
+
+        if let Some(sym) = self.renamed.get(&ident.sym) {
+            ident.sym = sym.clone();
+        }
+    }
+}
+
+fn with_names<R>(f: impl FnOnce(&Names) -> R) -> R {
+    NAMES.with(|names| {
+        f(names
+            .borrow()
+            .as_ref()
+            .expect("Generated names are only available during the transform"))
+    })
+}
+
+pub fn generated_ident(sym: impl Into<Atom>) -> Ident {
+    with_names(|names| Ident::new(sym.into(), DUMMY_SP, names.ctxt))
+}
+
+pub fn runtime_ident() -> Ident {
+    with_names(|names| names.runtime.clone())
+}
+
+pub fn element_ident(index: usize) -> Ident {
+    generated_ident(format!("__$element${}", index))
+}
+
+pub fn effect_ident(index: usize) -> Ident {
+    generated_ident(format!("__$effect${}", index))
+}
+
+pub fn template_ident(index: usize) -> Ident {
+    generated_ident(format!("__$template${}", index))
+}
+
+pub fn list_ident(index: usize) -> Ident {
+    generated_ident(format!("__$list${}", index))
+}
+
+// Bindings of the compiled code, whose method calls only update elements
+pub fn is_generated_ident(ident: &Ident) -> bool {
+    with_names(|names| ident.ctxt == names.ctxt || ident.to_id() == names.runtime.to_id())
+}
+
+pub fn is_runtime_helper(expr: &Expr, helper: RuntimeHelper) -> bool {
+    with_names(|names| match expr {
+        Expr::Ident(ident) => names.helpers.get(&ident.to_id()) == Some(&helper),
+        Expr::Member(MemberExpr {
+            obj,
+            prop: MemberProp::Ident(prop),
+            ..
+        }) => obj
+            .as_ident()
+            .is_some_and(|namespace| names.is_namespace_helper(namespace, &prop.sym, helper)),
+        _ => false,
+    })
+}
+
+// The component wrapper is reached through the default import of the
+// runtime, or through a namespace import of its module
+pub fn is_runtime_component(expr: &Expr) -> bool {
+    with_names(|names| match expr {
+        Expr::Member(MemberExpr {
+            obj,
+            prop: MemberProp::Ident(prop),
+            ..
+        }) if prop.sym == "component" => obj.as_ident().is_some_and(|obj| {
+            obj.to_id() == names.runtime.to_id() || names.namespaces.contains(&obj.to_id())
+        }),
+        _ => false,
+    })
+}
+
+pub fn is_runtime_element(name: &JSXElementName, helper: RuntimeHelper) -> bool {
+    with_names(|names| match name {
+        JSXElementName::Ident(ident) => names.helpers.get(&ident.to_id()) == Some(&helper),
+        JSXElementName::JSXMemberExpr(member) => match &member.obj {
+            JSXObject::Ident(namespace) => {
+                names.is_namespace_helper(namespace, &member.prop.sym, helper)
+            }
+            _ => false,
+        },
+        _ => false,
+    })
+}
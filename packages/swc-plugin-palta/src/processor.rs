@@ -13,25 +13,35 @@
  * See the License for the specific language governing permissions and
  * limitations under the License.
  */
-use core::panic;
-use std::collections::VecDeque;
-use std::ops::Deref;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::ops::{Deref, DerefMut};
 
-use swc_core::common::DUMMY_SP;
+use swc_core::common::{Span, Spanned, DUMMY_SP};
 use swc_core::ecma::ast::{
     ArrowExpr, BlockStmt, BlockStmtOrExpr, Bool, CallExpr, Callee, Decl, Expr, ExprOrSpread,
-    ExprStmt, Function, Ident, JSXAttrName, JSXAttrOrSpread, JSXAttrValue, JSXElement,
+    ExprStmt, Function, Ident, IfStmt, JSXAttrName, JSXAttrOrSpread, JSXAttrValue, JSXElement,
     JSXElementChild, JSXElementName, JSXExpr, JSXExprContainer, JSXFragment, JSXOpeningElement,
-    JSXText, KeyValueProp, Lit, MemberExpr, ObjectLit, ParenExpr, Pat, Prop, PropName,
-    PropOrSpread, ReturnStmt, Stmt, TsTypeAnn, VarDecl, VarDeclarator,
+    JSXSpreadChild, JSXText, KeyValueProp, Lit, MemberExpr, ObjectLit, ParenExpr, Pat, Prop,
+    PropName, PropOrSpread, ReturnStmt, Stmt, TsTypeAnn, VarDecl, VarDeclarator,
 };
+use swc_core::ecma::visit::{VisitMut, VisitMutWith};
 
+use crate::dependencies::{get_free_identifiers, get_pat_identifiers};
+use crate::error::{ComponentError, ComponentResult};
 use crate::generators::{
-    generate_element_initialize_call, generate_element_update_child_call,
-    generate_element_update_props_call, generate_expression_function,
+    generate_element_assignment, generate_element_initialize_call,
+    generate_element_update_branch_call, generate_element_update_child_call,
+    generate_element_update_props_call, generate_expression_function, generate_guard_block,
+    generate_guard_break, generate_if_statement, generate_list_collect_call,
+    generate_list_render_call, generate_mount_effect_call, generate_render_children_call,
+    generate_render_children_collect_call, generate_render_prop_call, generate_run_effect_call,
 };
+use crate::lowering::{get_branch_path, lower_lists, BranchPath, ListCallback, ListLowering};
+use crate::names::{element_ident, is_runtime_element, is_runtime_helper, RuntimeHelper};
 use crate::utils::{
-    jsx_expr_to_expr, jsx_member_expr_to_member_expr, replace_jsx_elements_in_expression,
+    get_jsx_callback, is_static_expression, is_static_prop, jsx_expr_to_expr,
+    jsx_member_expr_to_member_expr, replace_jsx_elements_in_expression, spread_child_to_array,
+    stmt_contains_jsx, stmt_contains_return,
 };
 
 const HTML_ELEMENT_TAGS: &[&str] = &[
@@ -215,7 +225,11 @@ const HTML_ELEMENT_TAGS: &[&str] = &[
     "view",
 ];
 
-#[derive(Debug)]
+pub fn is_html_tag(tag: &str) -> bool {
+    HTML_ELEMENT_TAGS.contains(&tag)
+}
+
+#[derive(Debug, Clone)]
 pub enum ElementChildren {
     Text(String),
     Element(usize),
@@ -229,6 +243,7 @@ pub enum ComponentName {
 
 #[derive(Debug)]
 pub struct TagElementDescriptor {
+    pub span: Span,
     pub tag: String,
     pub children: Vec<ElementChildren>,
     pub props: Option<ObjectLit>,
@@ -236,19 +251,75 @@ pub struct TagElementDescriptor {
 
 #[derive(Debug)]
 pub struct ComponentElementDescriptor {
+    pub span: Span,
     pub component: ComponentName,
     pub children: Vec<ElementChildren>,
     pub props: Option<ObjectLit>,
 }
 
+#[derive(Debug)]
+pub struct FragmentElementDescriptor {
+    pub span: Span,
+    pub children: Vec<ElementChildren>,
+}
+
+// Children given to a component for one of its named slots
+#[derive(Debug)]
+pub struct SlotElementDescriptor {
+    pub span: Span,
+    pub name: String,
+    pub children: Vec<ElementChildren>,
+}
+
+#[derive(Debug)]
+pub struct ChildrenElementDescriptor {
+    pub name: String,
+    // Fragment rendered while the component is given no children for the slot
+    pub fallback: Option<usize>,
+}
+
+#[derive(Debug)]
+pub struct BranchElementDescriptor {
+    pub span: Span,
+    pub branches: Vec<Vec<ElementChildren>>,
+}
+
 #[derive(Debug)]
 pub enum ElementDescriptor {
     Tag(TagElementDescriptor),
     Component(ComponentElementDescriptor),
-    Children,
+    Fragment(FragmentElementDescriptor),
+    Branch(BranchElementDescriptor),
+    Slot(SlotElementDescriptor),
+    Children(ChildrenElementDescriptor),
+}
+
+impl ElementDescriptor {
+    pub fn span(&self) -> Span {
+        match self {
+            ElementDescriptor::Tag(tag) => tag.span,
+            ElementDescriptor::Component(component) => component.span,
+            ElementDescriptor::Fragment(fragment) => fragment.span,
+            ElementDescriptor::Branch(branch) => branch.span,
+            ElementDescriptor::Slot(slot) => slot.span,
+            ElementDescriptor::Children(_) => DUMMY_SP,
+        }
+    }
+}
+
+// A JSX returning callback given to a call in children, compiled into a
+// component receiving the callback bindings and the identifiers it captures.
+// Render props are callbacks given to a component, which creates their
+// elements itself.
+pub struct ListDescriptor {
+    pub span: Span,
+    pub props: Vec<Ident>,
+    pub body: BlockStmtOrExpr,
+    pub render_prop: bool,
 }
 
 pub struct StateDescriptor {
+    pub span: Span,
     pub variable_name: Ident,
     pub updater_name: Option<Ident>,
     pub initial_value: Option<Expr>,
@@ -256,49 +327,91 @@ pub struct StateDescriptor {
 }
 
 pub struct EffectDescriptor {
+    pub span: Span,
     pub deps: Vec<ExprOrSpread>,
     pub callback: Vec<Stmt>,
-    pub cleanup: Option<Vec<Stmt>>,
+    pub in_branch: bool,
+}
+
+// Position of a hydrated element in the server-rendered children of its
+// parent element, the component root having no parent
+#[derive(Debug, Clone, Copy)]
+pub struct HydrationClaim {
+    pub parent: Option<usize>,
+    pub position: usize,
 }
 
 pub struct Processor {
     elements: Vec<ElementDescriptor>,
-    children_element: Option<usize>,
+    slots: Vec<(String, usize)>,
+    // Expressions rendering the render prop given as children
+    render_sites: usize,
     initialize_statements: Vec<Stmt>,
     update_statements: Vec<Stmt>,
     root_element: Option<usize>,
+    branch_element: Option<usize>,
+    // Effects reached by the statements following the first early return
+    branch_effects: Option<Vec<usize>>,
+    // If statements whose branches are being processed, whose returns break
+    // out of the statements following them
+    guard_depth: usize,
+    // Branch element creating the elements assigned in the current if statement
+    assignment_branch: Option<usize>,
+    lazy_elements: HashSet<usize>,
+    lists: Vec<ListDescriptor>,
     states: Vec<StateDescriptor>,
     effects: Vec<EffectDescriptor>,
 }
 
-fn is_palta_state_call(call_expression: &CallExpr) -> bool {
-    if let Callee::Expr(callee) = &call_expression.callee {
-        if let Expr::Ident(ident) = callee.deref() {
-            return ident.sym == "$state";
-        }
+pub fn is_palta_state_call(call_expression: &CallExpr) -> bool {
+    match &call_expression.callee {
+        Callee::Expr(callee) => is_runtime_helper(callee, RuntimeHelper::State),
+        _ => false,
     }
-
-    false
 }
 
-fn is_palta_effect_call(call_expression: &CallExpr) -> bool {
-    if let Callee::Expr(callee) = &call_expression.callee {
-        if let Expr::Ident(ident) = callee.deref() {
-            return ident.sym == "$effect";
+// Name given by the `name` attribute of a `<Children>` or `<Slot>` element
+pub fn get_slot_name(element: &JSXOpeningElement) -> ComponentResult<Option<String>> {
+    for attr in element.attrs.iter() {
+        let JSXAttrOrSpread::JSXAttr(attr) = attr else {
+            continue;
+        };
+
+        if !matches!(&attr.name, JSXAttrName::Ident(name) if name.sym == "name") {
+            continue;
         }
+
+        return match &attr.value {
+            Some(JSXAttrValue::Lit(Lit::Str(name))) => Ok(Some(name.value.to_string())),
+            _ => Err(ComponentError::SlotNameNotString(attr.span)),
+        };
     }
 
-    false
+    Ok(None)
+}
+
+pub fn is_palta_effect_call(call_expression: &CallExpr) -> bool {
+    match &call_expression.callee {
+        Callee::Expr(callee) => is_runtime_helper(callee, RuntimeHelper::Effect),
+        _ => false,
+    }
 }
 
 impl Processor {
     pub fn new() -> Self {
         Processor {
             elements: vec![],
-            children_element: None,
+            slots: vec![],
+            render_sites: 0,
             initialize_statements: vec![],
             update_statements: vec![],
             root_element: None,
+            branch_element: None,
+            branch_effects: None,
+            guard_depth: 0,
+            assignment_branch: None,
+            lazy_elements: HashSet::new(),
+            lists: vec![],
             states: vec![],
             effects: vec![],
         }
@@ -312,8 +425,73 @@ impl Processor {
         &self.elements
     }
 
-    pub fn get_children_element(&self) -> Option<usize> {
-        self.children_element
+    pub fn is_lazy_element(&self, index: usize) -> bool {
+        self.lazy_elements.contains(&index)
+    }
+
+    // Only elements created with the component and nested in elements from the
+    // root claim their node, the others being rendered in place of the
+    // server-rendered nodes at their position.
+    pub fn get_hydration_claims(&self) -> HashMap<usize, HydrationClaim> {
+        let mut claims = HashMap::new();
+        let mut parents = vec![];
+
+        if let Some(root) = self
+            .root_element
+            .filter(|root| !self.is_lazy_element(*root))
+        {
+            match &self.elements[root] {
+                ElementDescriptor::Tag(_) => parents.push(root),
+                ElementDescriptor::Component(_) => {}
+                _ => return claims,
+            }
+
+            claims.insert(
+                root,
+                HydrationClaim {
+                    parent: None,
+                    position: 0,
+                },
+            );
+        }
+
+        while let Some(parent) = parents.pop() {
+            let ElementDescriptor::Tag(tag) = &self.elements[parent] else {
+                continue;
+            };
+
+            for (position, child) in tag.children.iter().enumerate() {
+                let ElementChildren::Element(index) = child else {
+                    continue;
+                };
+
+                if self.is_lazy_element(*index) {
+                    continue;
+                }
+
+                match &self.elements[*index] {
+                    ElementDescriptor::Tag(_) => parents.push(*index),
+                    ElementDescriptor::Component(_) => {}
+                    _ => continue,
+                }
+
+                claims.insert(
+                    *index,
+                    HydrationClaim {
+                        parent: Some(parent),
+                        position,
+                    },
+                );
+            }
+        }
+
+        claims
+    }
+
+    // Children elements of the component by slot name, the unnamed one
+    // being the `children` slot
+    pub fn get_slots(&self) -> &Vec<(String, usize)> {
+        &self.slots
     }
 
     pub fn get_initialze_statements(&self) -> &Vec<Stmt> {
@@ -324,6 +502,10 @@ impl Processor {
         &self.update_statements
     }
 
+    pub fn get_lists(&self) -> &Vec<ListDescriptor> {
+        &self.lists
+    }
+
     pub fn get_states(&self) -> &Vec<StateDescriptor> {
         &self.states
     }
@@ -332,159 +514,465 @@ impl Processor {
         &self.effects
     }
 
-    pub fn process_function(&mut self, node: &Function) {
-        match &node.body {
-            Some(block) => {
-                self.process_block_statement(block);
-            }
-            None => {}
+    pub fn process_function(&mut self, node: &Function) -> ComponentResult<()> {
+        if let Some(block) = &node.body {
+            self.process_block_statement(block)?;
         }
+
+        Ok(())
     }
 
-    pub fn process_arrow_expression(&mut self, node: &ArrowExpr) {
+    pub fn process_arrow_expression(&mut self, node: &ArrowExpr) -> ComponentResult<()> {
         match node.body.deref() {
             BlockStmtOrExpr::BlockStmt(ref block) => {
-                self.process_block_statement(block);
+                self.process_block_statement(block)?;
             }
             BlockStmtOrExpr::Expr(expression) => match expression.deref() {
-                Expr::JSXElement(element) => {
-                    self.process_jsx_element(element.deref());
+                Expr::JSXElement(_) | Expr::JSXFragment(_) | Expr::Paren(_) => {
+                    // An expression body is processed as the argument of a
+                    // return statement, so that its element becomes the root.
+                    self.process_return_statement(&ReturnStmt {
+                        span: expression.span(),
+                        arg: Some(expression.clone()),
+                    })?;
                 }
-                Expr::JSXFragment(fragment) => {
-                    self.process_jsx_fragment(fragment);
-                }
-                _ => {
-                    panic!("Arrow expression body is not JSX Element");
+                expression => {
+                    return Err(ComponentError::ArrowBodyNotJsx(expression.span()));
                 }
             },
         }
+
+        Ok(())
+    }
+
+    fn process_block_statement(&mut self, block: &BlockStmt) -> ComponentResult<()> {
+        self.process_statements(&block.stmts.iter().collect::<Vec<_>>())?;
+
+        Ok(())
     }
 
-    fn process_block_statement(&mut self, block: &BlockStmt) {
-        for stmt in &block.stmts {
+    // Returns true when every path through the statements has been terminated,
+    // either by a return statement or by a conditional branch consuming the
+    // remaining statements.
+    fn process_statements(&mut self, stmts: &[&Stmt]) -> ComponentResult<bool> {
+        for (index, stmt) in stmts.iter().enumerate() {
             match stmt {
                 Stmt::Block(block) => {
-                    self.process_block_statement(block);
+                    let mut block_stmts = block.stmts.iter().collect::<Vec<_>>();
+
+                    block_stmts.extend_from_slice(&stmts[index + 1..]);
+
+                    return self.process_statements(&block_stmts);
                 }
                 Stmt::Return(stmt) => {
-                    self.process_return_statement(stmt);
+                    self.process_return_statement(stmt)?;
+                    return Ok(true);
                 }
                 Stmt::Decl(decl) => {
-                    self.processs_declaration(decl);
+                    self.processs_declaration(decl)?;
+                }
+                Stmt::If(if_stmt) if stmt_contains_return(stmt) => {
+                    return self.process_if_statement(if_stmt, &stmts[index + 1..]);
                 }
-                Stmt::If(_) => {}
-                Stmt::Expr(expr) => {
-                    if let Expr::Call(call_expression) = expr.expr.deref() {
-                        if is_palta_effect_call(&call_expression.clone()) {
-                            self.process_palta_effect_call(call_expression);
-                        }
-                    } else {
-                        self.update_statements.push(Stmt::Expr(expr.clone()));
-                        self.initialize_statements.push(Stmt::Expr(expr.clone()));
+                Stmt::Expr(expr) => match expr.expr.deref() {
+                    Expr::Call(call_expression) if is_palta_effect_call(call_expression) => {
+                        self.process_palta_effect_call(call_expression)?;
                     }
+                    _ => self.process_expression_statement(expr)?,
+                },
+                stmt if stmt_contains_return(stmt) => {
+                    return Err(ComponentError::UnsupportedReturn(stmt.span()));
+                }
+                Stmt::If(if_stmt) if stmt_contains_jsx(stmt) => {
+                    self.process_jsx_if_statement(if_stmt)?;
                 }
                 stmt => {
-                    self.update_statements.push(stmt.clone());
-                    self.initialize_statements.push(stmt.clone());
+                    self.update_statements.push((*stmt).clone());
+                    self.initialize_statements.push((*stmt).clone());
                 }
             }
         }
+
+        Ok(false)
     }
 
-    fn process_return_statement(&mut self, stmt: &ReturnStmt) {
-        match &stmt.arg {
-            Some(arg) => match arg.deref() {
-                Expr::JSXElement(element) => {
-                    self.root_element = match self.process_jsx_element(element.deref())[0] {
-                        ElementChildren::Element(index) => Some(index),
-                        _ => None,
-                    }
-                }
-                Expr::JSXFragment(fragment) => {
-                    self.process_jsx_fragment(fragment);
-                }
-                Expr::Paren(expr) => {
-                    let children = self.process_parenthesis_expression(expr);
+    // The statements following the first if statement returning early are
+    // only compiled once, in a block which its returns break out of. Every
+    // path reaching the end of the component renders nothing.
+    fn process_if_statement(&mut self, if_stmt: &IfStmt, rest: &[&Stmt]) -> ComponentResult<bool> {
+        if self.branch_effects.is_some() {
+            return self.process_guard_statement(if_stmt, rest);
+        }
 
-                    if children.len() != 1 {
-                        return;
-                    }
+        if self.branch_element.is_none() {
+            self.elements
+                .push(ElementDescriptor::Branch(BranchElementDescriptor {
+                    span: if_stmt.span,
+                    branches: vec![],
+                }));
+            self.branch_element = Some(self.elements.len() - 1);
+            self.root_element = self.branch_element;
+        }
 
-                    if let ElementChildren::Element(index) = children[0] {
-                        self.root_element = Some(index);
-                    }
-                }
-                _ => {
-                    panic!("Return statement argument is not JSX Element");
-                }
+        self.branch_effects = Some(vec![]);
+
+        let (initialize, update, result) = self.capture_statements(|processor| {
+            if !processor.process_guard_statement(if_stmt, rest)? {
+                let first_element = processor.elements.len();
+
+                processor.add_update_branch_statement(vec![], vec![], vec![], first_element);
+            }
+
+            Ok(())
+        });
+
+        self.branch_effects = None;
+        result?;
+
+        self.initialize_statements
+            .push(generate_guard_block(initialize));
+        self.update_statements.push(generate_guard_block(update));
+
+        Ok(true)
+    }
+
+    // Returns whether every path through the if statement returned, leaving
+    // the following statements unreachable
+    fn process_guard_statement(
+        &mut self,
+        if_stmt: &IfStmt,
+        rest: &[&Stmt],
+    ) -> ComponentResult<bool> {
+        let (cons_initialize, cons_update, cons) = self.process_guard_path(&if_stmt.cons);
+        let alt = if_stmt.alt.as_ref().map(|alt| self.process_guard_path(alt));
+        let cons = cons?;
+
+        let (alt_initialize, alt_update, alt) = match alt {
+            Some((initialize, update, result)) => (Some(initialize), Some(update), result?),
+            None => (None, None, false),
+        };
+
+        self.initialize_statements.push(generate_if_statement(
+            if_stmt.span,
+            &if_stmt.test,
+            cons_initialize,
+            alt_initialize,
+        ));
+        self.update_statements.push(generate_if_statement(
+            if_stmt.span,
+            &if_stmt.test,
+            cons_update,
+            alt_update,
+        ));
+
+        if cons && alt {
+            return Ok(true);
+        }
+
+        self.process_statements(rest)
+    }
+
+    // Effects reached by a path are not reached by the statements following
+    // its if statement
+    fn process_guard_path(&mut self, stmt: &Stmt) -> (Vec<Stmt>, Vec<Stmt>, ComponentResult<bool>) {
+        let effects = self.branch_effects.as_ref().map_or(0, Vec::len);
+
+        self.guard_depth += 1;
+
+        let result = self.capture_statements(|processor| processor.process_statements(&[stmt]));
+
+        self.guard_depth -= 1;
+
+        if let Some(branch_effects) = self.branch_effects.as_mut() {
+            branch_effects.truncate(effects);
+        }
+
+        result
+    }
+
+    // Elements assigned in a conditional block are created by a branch element
+    // the first time the block runs, and only updated by its next runs. The
+    // branch is not rendered, the elements being rendered where they are used.
+    fn process_jsx_if_statement(&mut self, if_stmt: &IfStmt) -> ComponentResult<()> {
+        self.elements
+            .push(ElementDescriptor::Branch(BranchElementDescriptor {
+                span: if_stmt.span,
+                branches: vec![],
+            }));
+
+        let assignment_branch = self.assignment_branch.replace(self.elements.len() - 1);
+
+        let (cons_initialize, cons_update, cons) =
+            self.capture_statements(|processor| processor.process_statements(&[&if_stmt.cons]));
+        let alt = if_stmt
+            .alt
+            .as_ref()
+            .map(|alt| self.capture_statements(|processor| processor.process_statements(&[alt])));
+
+        self.assignment_branch = assignment_branch;
+        cons?;
+
+        let (alt_initialize, alt_update) = match alt {
+            Some((initialize, update, result)) => {
+                result?;
+                (Some(initialize), Some(update))
+            }
+            None => (None, None),
+        };
+
+        self.initialize_statements.push(generate_if_statement(
+            if_stmt.span,
+            &if_stmt.test,
+            cons_initialize,
+            alt_initialize,
+        ));
+        self.update_statements.push(generate_if_statement(
+            if_stmt.span,
+            &if_stmt.test,
+            cons_update,
+            alt_update,
+        ));
+
+        Ok(())
+    }
+
+    fn capture_statements<R>(
+        &mut self,
+        f: impl FnOnce(&mut Self) -> R,
+    ) -> (Vec<Stmt>, Vec<Stmt>, R) {
+        let initialize_statements = std::mem::take(&mut self.initialize_statements);
+        let update_statements = std::mem::take(&mut self.update_statements);
+
+        let result = f(self);
+
+        (
+            std::mem::replace(&mut self.initialize_statements, initialize_statements),
+            std::mem::replace(&mut self.update_statements, update_statements),
+            result,
+        )
+    }
+
+    fn add_update_branch_statement(
+        &mut self,
+        children: Vec<ElementChildren>,
+        initialize: Vec<Stmt>,
+        update: Vec<Stmt>,
+        first_element: usize,
+    ) {
+        let Some(position) = self.branch_element else {
+            return;
+        };
+
+        // The branch cleans up the effects it does not run when activated
+        let effects = self.branch_effects.clone().unwrap_or_default();
+
+        let statement = self.add_branch(
+            position,
+            children,
+            initialize,
+            update,
+            first_element,
+            &effects,
+        );
+
+        self.initialize_statements.push(statement.clone());
+        self.update_statements.push(statement);
+
+        for index in effects {
+            self.update_statements
+                .push(generate_run_effect_call(index, &self.effects[index]));
+        }
+
+        if self.guard_depth > 0 {
+            self.initialize_statements.push(generate_guard_break());
+            self.update_statements.push(generate_guard_break());
+        }
+    }
+
+    // Adds a branch to the branch element at `position` and returns the
+    // statement activating it. The elements created since `first_element` are
+    // only created when the branch is activated for the first time.
+    fn add_branch(
+        &mut self,
+        position: usize,
+        children: Vec<ElementChildren>,
+        initialize: Vec<Stmt>,
+        update: Vec<Stmt>,
+        first_element: usize,
+        effects: &[usize],
+    ) -> Stmt {
+        let mut branch_initialize = self.take_branch_elements(first_element);
+
+        branch_initialize.extend(initialize);
+
+        let ElementDescriptor::Branch(ref mut branch) = self.elements[position] else {
+            unreachable!("Branch position should point to a branch element");
+        };
+
+        branch.branches.push(children);
+
+        generate_element_update_branch_call(
+            position,
+            branch.branches.len() - 1,
+            &branch_initialize,
+            &update,
+            effects,
+        )
+    }
+
+    // Elements already owned by a nested branch are left to it, and the
+    // children elements are always needed by the component definition.
+    fn take_branch_elements(&mut self, first_element: usize) -> Vec<Stmt> {
+        let mut statements = vec![];
+
+        for index in (first_element..self.elements.len()).rev() {
+            if self.lazy_elements.contains(&index)
+                || matches!(self.elements[index], ElementDescriptor::Children(_))
+            {
+                continue;
+            }
+
+            self.lazy_elements.insert(index);
+            statements.push(generate_element_assignment(index, &self.elements[index]));
+        }
+
+        statements
+    }
+
+    fn process_return_statement(&mut self, stmt: &ReturnStmt) -> ComponentResult<()> {
+        if self.branch_element.is_some() {
+            let first_element = self.elements.len();
+            let (initialize, update, children) =
+                self.capture_statements(|processor| match &stmt.arg {
+                    Some(arg) => processor.process_return_argument(arg),
+                    None => Ok(vec![]),
+                });
+
+            self.add_update_branch_statement(children?, initialize, update, first_element);
+            return Ok(());
+        }
+
+        if let Some(arg) = &stmt.arg {
+            if let [ElementChildren::Element(index)] = self.process_return_argument(arg)?[..] {
+                self.root_element = Some(index);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn process_return_argument(&mut self, arg: &Expr) -> ComponentResult<Vec<ElementChildren>> {
+        match arg {
+            Expr::JSXElement(element) => self.process_jsx_element(element.deref()),
+            Expr::JSXFragment(fragment) => self.process_root_fragment(fragment),
+            Expr::Paren(expr) => match expr.expr.deref() {
+                Expr::JSXFragment(fragment) => self.process_root_fragment(fragment),
+                _ => self.process_parenthesis_expression(expr),
             },
-            None => {}
+            Expr::Lit(Lit::Null(_)) if self.branch_element.is_some() => Ok(vec![]),
+            Expr::Ident(ident) => self.process_returned_variable(ident),
+            arg => Err(ComponentError::ReturnArgumentNotJsx(arg.span())),
         }
     }
 
-    fn process_parenthesis_expression(&mut self, expr: &ParenExpr) -> Vec<ElementChildren> {
+    fn process_parenthesis_expression(
+        &mut self,
+        expr: &ParenExpr,
+    ) -> ComponentResult<Vec<ElementChildren>> {
         match expr.expr.deref() {
             Expr::JSXElement(element) => self.process_jsx_element(element.deref()),
             Expr::JSXFragment(fragment) => self.process_jsx_fragment(fragment),
-            _ => {
-                panic!("Parenthesis expression is not JSX Element");
-            }
+            expr => Err(ComponentError::ParenthesisNotJsx(expr.span())),
         }
     }
 
-    fn process_expression(&mut self, expr: &Expr) -> Vec<ElementChildren> {
+    fn process_expression(&mut self, expr: &Expr) -> ComponentResult<Vec<ElementChildren>> {
         match expr {
             Expr::Paren(paren_expr) => self.process_parenthesis_expression(paren_expr),
             Expr::JSXElement(element) => self.process_jsx_element(element),
             Expr::JSXFragment(fragment) => self.process_jsx_fragment(fragment),
             Expr::Bin(bin_expr) => self.process_expression(bin_expr.right.deref()),
             Expr::Cond(cond_expr) => {
-                let mut children = self.process_expression(cond_expr.cons.deref());
-                children.append(&mut self.process_expression(cond_expr.alt.deref()));
-                children
-            }
-            _ => {
-                vec![]
+                let mut children = self.process_expression(cond_expr.cons.deref())?;
+                children.append(&mut self.process_expression(cond_expr.alt.deref())?);
+                Ok(children)
             }
+            _ => Ok(vec![]),
         }
     }
 
-    fn processs_declaration(&mut self, decl: &Decl) {
+    fn processs_declaration(&mut self, decl: &Decl) -> ComponentResult<()> {
         match decl {
-            Decl::Var(var_decl) => self.process_var_declaration(var_decl),
+            Decl::Var(var_decl) => self.process_var_declaration(var_decl)?,
             decl => {
                 self.update_statements.push(Stmt::Decl(decl.clone()));
                 self.initialize_statements.push(Stmt::Decl(decl.clone()));
             }
         }
+
+        Ok(())
+    }
+
+    fn process_expression_statement(&mut self, stmt: &ExprStmt) -> ComponentResult<()> {
+        let mut stmt = stmt.clone();
+
+        if let Expr::Assign(assign_expr) = stmt.expr.deref_mut() {
+            *assign_expr.right = self.process_assigned_expression(&assign_expr.right)?;
+        }
+
+        self.update_statements.push(Stmt::Expr(stmt.clone()));
+        self.initialize_statements.push(Stmt::Expr(stmt));
+
+        Ok(())
     }
 
-    fn process_var_declaration(&mut self, var_decl: &VarDecl) {
+    // Replaces the elements of an expression by their variables. Inside an if
+    // statement, they are created by a branch of its branch element.
+    fn process_assigned_expression(&mut self, expr: &Expr) -> ComponentResult<Expr> {
+        let first_element = self.elements.len();
+        let (initialize, update, children) =
+            self.capture_statements(|processor| processor.process_expression(expr));
+        let children = children?;
+        let assigned =
+            replace_jsx_elements_in_expression(expr, &mut VecDeque::from(children.clone()));
+
+        match self.assignment_branch {
+            Some(position) if self.elements.len() > first_element => {
+                let statement =
+                    self.add_branch(position, children, initialize, update, first_element, &[]);
+
+                self.initialize_statements.push(statement.clone());
+                self.update_statements.push(statement);
+            }
+            _ => {
+                self.initialize_statements.extend(initialize);
+                self.update_statements.extend(update);
+            }
+        }
+
+        Ok(assigned)
+    }
+
+    fn process_var_declaration(&mut self, var_decl: &VarDecl) -> ComponentResult<()> {
         let mut new_var_decl = var_decl.clone();
 
         new_var_decl.decls = vec![];
 
         for decl in &var_decl.decls {
             match &decl.init {
-                Some(init) => {
-                    if let Expr::Call(call_expression) = init.deref() {
-                        if is_palta_state_call(&call_expression.clone()) {
-                            self.process_palta_state_declaration(&decl.name, call_expression);
-                        }
-                    } else {
-                        let expr_elements = self.process_expression(init);
-
+                Some(init) => match init.deref() {
+                    Expr::Call(call_expression) if is_palta_state_call(call_expression) => {
+                        self.process_palta_state_declaration(&decl.name, call_expression)?;
+                    }
+                    _ => {
                         new_var_decl.decls.push(VarDeclarator {
                             span: decl.span,
                             name: decl.name.clone(),
-                            init: Some(Box::new(replace_jsx_elements_in_expression(
-                                init,
-                                &mut VecDeque::from(expr_elements),
-                            ))),
+                            init: Some(Box::new(self.process_assigned_expression(init)?)),
                             definite: decl.definite,
                         });
                     }
-                }
+                },
                 None => {
                     new_var_decl.decls.push(decl.clone());
                 }
@@ -497,114 +985,127 @@ impl Processor {
             self.initialize_statements
                 .push(Stmt::Decl(Decl::Var(Box::new(new_var_decl.clone()))));
         }
+
+        Ok(())
     }
 
-    fn process_palta_state_declaration(&mut self, name: &Pat, call_expression: &CallExpr) {
-        if let Pat::Array(array) = name {
-            if (array.elems.len() != 1 && array.elems.len() != 2) || array.elems[0].is_none() {
-                panic!("Palta state declaration should have one or two elements");
+    fn process_palta_state_declaration(
+        &mut self,
+        name: &Pat,
+        call_expression: &CallExpr,
+    ) -> ComponentResult<()> {
+        let Pat::Array(array) = name else {
+            return Err(ComponentError::StateDeclarationNotArrayPattern(name.span()));
+        };
+
+        let variable = match array.elems.as_slice() {
+            [Some(variable)] | [Some(variable), _] => variable,
+            _ => return Err(ComponentError::StateDeclarationLength(array.span)),
+        };
+
+        let Pat::Ident(variable) = variable else {
+            return Err(ComponentError::StateVariableNotIdentifier(variable.span()));
+        };
+
+        let updater_name = match array.elems.get(1) {
+            Some(Some(Pat::Ident(ident))) => Some(ident.id.clone()),
+            Some(Some(pat)) => {
+                return Err(ComponentError::StateUpdaterNotIdentifier(pat.span()));
             }
+            _ => None,
+        };
 
-            self.states.push(StateDescriptor {
-                variable_name: match array.elems[0].clone().unwrap() {
-                    Pat::Ident(ident) => ident.id,
-                    _ => panic!("First element of Palta state declaration should be an identifier"),
-                },
-                updater_name: array.elems[1].clone().map(|pat| match pat {
-                    Pat::Ident(ident) => ident.id,
-                    _ => {
-                        panic!("Second element of Palta state declaration should be an identifier")
-                    }
-                }),
-                initial_value: if call_expression.args.is_empty() {
-                    None
-                } else {
-                    Some(call_expression.args[0].expr.deref().clone())
-                },
-                type_ann: if let Pat::Ident(ident) = array.elems[0].clone().unwrap() {
-                    ident.type_ann.clone()
-                } else {
-                    None
-                },
-            });
-        } else {
-            panic!("Palta state declaration should be an array pattern");
-        }
+        self.states.push(StateDescriptor {
+            span: call_expression.span,
+            variable_name: variable.id.clone(),
+            updater_name,
+            initial_value: if call_expression.args.is_empty() {
+                None
+            } else {
+                Some(call_expression.args[0].expr.deref().clone())
+            },
+            type_ann: variable.type_ann.clone(),
+        });
+
+        Ok(())
     }
 
-    fn process_palta_effect_call(&mut self, call_expression: &CallExpr) {
+    fn process_palta_effect_call(&mut self, call_expression: &CallExpr) -> ComponentResult<()> {
         if call_expression.args.len() != 2 {
-            panic!("Palta effect should have two arguments");
+            return Err(ComponentError::EffectArgumentsCount(call_expression.span));
         }
 
+        // Returned cleanups are kept by the callback and captured when the
+        // effect runs, so they can close over the values of that run
         let callback = match call_expression.args[0].expr.deref() {
             Expr::Arrow(arrow) => match arrow.body.deref() {
                 BlockStmtOrExpr::BlockStmt(block) => block.stmts.clone(),
-                BlockStmtOrExpr::Expr(expr) => vec![Stmt::Expr(ExprStmt {
+                BlockStmtOrExpr::Expr(expr) => vec![Stmt::Return(ReturnStmt {
                     span: DUMMY_SP,
-                    expr: expr.clone(),
+                    arg: Some(expr.clone()),
                 })],
             },
-            _ => panic!("First argument of Palta effect should be an arrow function"),
+            expr => {
+                return Err(ComponentError::EffectCallbackNotArrowFunction(expr.span()));
+            }
         };
 
         let deps = match call_expression.args[1].expr.deref() {
             Expr::Array(array) => array.elems.clone().into_iter().flatten().collect(),
-            _ => panic!("Second argument of Palta effect should be an array"),
+            expr => return Err(ComponentError::EffectDepsNotArray(expr.span())),
         };
 
-        let cleanup = match call_expression.args[1].expr.deref() {
-            Expr::Arrow(arrow) => match arrow.body.deref() {
-                BlockStmtOrExpr::BlockStmt(block) => {
-                    let return_statement = block
-                        .stmts
-                        .iter()
-                        .find(|stmt| matches!(stmt, Stmt::Return(_)));
-
-                    return_statement.map(|stmt| match stmt {
-                        Stmt::Return(stmt) => match &stmt.arg {
-                            Some(arg) => match arg.deref() {
-                                Expr::Arrow(arrow) => match arrow.body.deref() {
-                                    BlockStmtOrExpr::BlockStmt(block) => block.stmts.clone(),
-                                    BlockStmtOrExpr::Expr(expr) => vec![Stmt::Expr(ExprStmt {
-                                        span: DUMMY_SP,
-                                        expr: expr.clone(),
-                                    })],
-                                },
-                                _ => panic!(
-                                    "Return value from an effect should be an arrow function"
-                                ),
-                            },
-                            None => vec![],
-                        },
-                        _ => vec![],
-                    })
+        for stmt in callback.iter() {
+            if let Stmt::Return(ReturnStmt { arg: Some(arg), .. }) = stmt {
+                if matches!(
+                    arg.deref(),
+                    Expr::Lit(_) | Expr::Tpl(_) | Expr::Array(_) | Expr::Object(_)
+                ) {
+                    return Err(ComponentError::EffectCleanupNotFunction(arg.span()));
                 }
-                _ => None,
-            },
-            _ => None,
-        };
+            }
+        }
 
-        self.effects.push(EffectDescriptor {
+        let effect = EffectDescriptor {
+            span: call_expression.span,
             deps,
             callback,
-            cleanup,
-        });
+            in_branch: self.branch_effects.is_some(),
+        };
+
+        // Mounted effects are deferred, so they are queued in declaration order
+        self.initialize_statements
+            .push(generate_mount_effect_call(self.effects.len(), &effect));
+        self.effects.push(effect);
+
+        if let Some(branch_effects) = self.branch_effects.as_mut() {
+            branch_effects.push(self.effects.len() - 1);
+        }
+
+        Ok(())
     }
 
-    fn process_jsx_element(&mut self, element: &JSXElement) -> Vec<ElementChildren> {
-        let element_descriptor = self.get_element_descriptor(&element.opening);
+    fn process_jsx_element(
+        &mut self,
+        element: &JSXElement,
+    ) -> ComponentResult<Vec<ElementChildren>> {
+        let element_descriptor = self.get_element_descriptor(&element.opening)?;
 
-        if let ElementDescriptor::Children = element_descriptor {
-            if self.children_element.is_some() {
-                return vec![ElementChildren::Element(self.children_element.unwrap())];
+        if let ElementDescriptor::Children(children) = &element_descriptor {
+            if let Some((_, index)) = self.slots.iter().find(|(slot, _)| *slot == children.name) {
+                return Ok(vec![ElementChildren::Element(*index)]);
             }
         }
 
         self.elements.push(element_descriptor);
 
         let position = self.elements.len() - 1;
-        let children = self.process_jsx_children(&element.children, Some(position));
+
+        if let ElementDescriptor::Children(_) = self.elements[position] {
+            return self.process_children_element(position, element);
+        }
+
+        let children = self.process_jsx_children(&element.children, Some(position))?;
         let props = match self.elements[position] {
             ElementDescriptor::Tag(ref mut tag) => {
                 tag.children = children;
@@ -614,26 +1115,132 @@ impl Processor {
                 component.children = children;
                 component.props.clone()
             }
-            ElementDescriptor::Children => {
-                self.children_element = Some(position);
+            ElementDescriptor::Slot(ref mut slot) => {
+                slot.children = children;
                 None
             }
+            ElementDescriptor::Fragment(_)
+            | ElementDescriptor::Branch(_)
+            | ElementDescriptor::Children(_) => None,
         };
 
-        if matches!(self.elements[position], ElementDescriptor::Tag(_))
-            || matches!(self.elements[position], ElementDescriptor::Component(_))
-        {
-            self.add_update_props_statement(position, &props);
-            self.add_initialize_statement(position, &props);
+        if !matches!(self.elements[position], ElementDescriptor::Component(_)) {
+            self.check_slot_children(position)?;
+        }
+
+        match self.elements[position] {
+            ElementDescriptor::Tag(_) => {
+                self.add_update_props_statement(position, &props, false);
+                self.add_initialize_statement(position, &props);
+            }
+            ElementDescriptor::Component(_) => {
+                self.add_update_props_statement(position, &props, true);
+                self.add_initialize_statement(position, &props);
+            }
+            _ => {}
         }
 
-        vec![ElementChildren::Element(position)]
+        Ok(vec![ElementChildren::Element(position)])
     }
 
-    fn process_jsx_fragment(&mut self, element: &JSXFragment) -> Vec<ElementChildren> {
+    // Content nested in `<Children>` is kept in a fragment of its own, so that
+    // it can be mounted and unmounted as a whole by the children element.
+    fn process_children_element(
+        &mut self,
+        position: usize,
+        element: &JSXElement,
+    ) -> ComponentResult<Vec<ElementChildren>> {
+        self.elements
+            .push(ElementDescriptor::Fragment(FragmentElementDescriptor {
+                span: element.span,
+                children: vec![],
+            }));
+
+        let fallback = self.elements.len() - 1;
+        let children = self.process_jsx_children(&element.children, Some(fallback))?;
+
+        let fallback = if children.is_empty() {
+            self.elements.pop();
+            None
+        } else {
+            if let ElementDescriptor::Fragment(ref mut fragment) = self.elements[fallback] {
+                fragment.children = children;
+            }
+
+            self.check_slot_children(fallback)?;
+            Some(fallback)
+        };
+
+        if let ElementDescriptor::Children(ref mut descriptor) = self.elements[position] {
+            descriptor.fallback = fallback;
+            self.slots.push((descriptor.name.clone(), position));
+        }
+
+        Ok(vec![ElementChildren::Element(position)])
+    }
+
+    fn process_jsx_fragment(
+        &mut self,
+        element: &JSXFragment,
+    ) -> ComponentResult<Vec<ElementChildren>> {
         self.process_jsx_children(&element.children, None)
     }
 
+    // A returned variable holds elements created in the body, which may change
+    // between updates, so it is rendered as the only child of a fragment.
+    fn process_returned_variable(
+        &mut self,
+        ident: &Ident,
+    ) -> ComponentResult<Vec<ElementChildren>> {
+        self.process_value_fragment(ident.span, &Expr::Ident(ident.clone()))
+    }
+
+    fn process_value_fragment(
+        &mut self,
+        span: Span,
+        value: &Expr,
+    ) -> ComponentResult<Vec<ElementChildren>> {
+        self.elements
+            .push(ElementDescriptor::Fragment(FragmentElementDescriptor {
+                span,
+                children: vec![ElementChildren::Text("".to_string())],
+            }));
+
+        let position = self.elements.len() - 1;
+        let expression = generate_expression_function(value);
+
+        self.update_statements
+            .push(generate_element_update_child_call(position, 0, &expression));
+        self.initialize_statements
+            .push(generate_element_update_child_call(position, 0, &expression));
+
+        Ok(vec![ElementChildren::Element(position)])
+    }
+
+    // A returned fragment is kept as an element, so that the component always
+    // has a single root holding all of its nodes.
+    fn process_root_fragment(
+        &mut self,
+        fragment: &JSXFragment,
+    ) -> ComponentResult<Vec<ElementChildren>> {
+        self.elements
+            .push(ElementDescriptor::Fragment(FragmentElementDescriptor {
+                span: fragment.span,
+                children: vec![],
+            }));
+
+        let position = self.elements.len() - 1;
+        let children = self.process_jsx_children(&fragment.children, Some(position))?;
+
+        if let ElementDescriptor::Fragment(ref mut fragment) = self.elements[position] {
+            fragment.children = children;
+        }
+
+        self.check_slot_children(position)?;
+
+        Ok(vec![ElementChildren::Element(position)])
+    }
+
     fn process_jsx_text(&mut self, element: &JSXText) -> Vec<ElementChildren> {
         let value = element.value.as_str().to_string();
 
@@ -649,22 +1256,64 @@ impl Processor {
         expression: &JSXExprContainer,
         parent: Option<usize>,
         children_position: usize,
-    ) -> Vec<ElementChildren> {
+    ) -> ComponentResult<Vec<ElementChildren>> {
+        let mut expression = expression.clone();
+
+        if let JSXExpr::Expr(expr) = &mut expression.expr {
+            lower_lists(expr, self);
+        }
+
+        if let JSXExpr::Expr(expr) = &expression.expr {
+            if let Some(path) = get_branch_path(expr) {
+                return self.process_branch_expression(expr.span(), &path);
+            }
+        }
+
+        if let JSXExpr::Expr(expr) = &mut expression.expr {
+            let is_component_child = parent.is_some_and(|parent| {
+                matches!(self.elements[parent], ElementDescriptor::Component(_))
+            });
+
+            if is_component_child {
+                if let Some(render_prop) = self.process_render_prop(expr) {
+                    **expr = render_prop;
+                }
+            }
+
+            let mut replacer = RenderChildrenReplacer {
+                processor: self,
+                children: None,
+            };
+
+            expr.visit_mut_with(&mut replacer);
+
+            if let Some(children) = replacer.children {
+                **expr = generate_render_children_collect_call(
+                    children,
+                    self.render_sites,
+                    std::mem::take(expr),
+                );
+                self.render_sites += 1;
+            }
+        }
+
         if let Some(parent) = parent {
             let update_expression = match &expression.expr {
                 JSXExpr::Expr(expr) => {
-                    let expr_elements = self.process_expression(expr);
+                    let expr_elements = self.process_expression(expr)?;
                     replace_jsx_elements_in_expression(expr, &mut VecDeque::from(expr_elements))
                 }
                 expr => jsx_expr_to_expr(expr),
             };
 
-            self.update_statements
-                .push(generate_element_update_child_call(
-                    parent,
-                    children_position,
-                    &generate_expression_function(&update_expression),
-                ));
+            if !is_static_expression(&update_expression) {
+                self.update_statements
+                    .push(generate_element_update_child_call(
+                        parent,
+                        children_position,
+                        &generate_expression_function(&update_expression),
+                    ));
+            }
             self.initialize_statements
                 .push(generate_element_update_child_call(
                     parent,
@@ -673,39 +1322,193 @@ impl Processor {
                 ));
         }
 
-        vec![ElementChildren::Text("".to_string())]
+        Ok(vec![ElementChildren::Text("".to_string())])
+    }
+
+    // Spread children are collected in an array rendered as a single dynamic
+    // child, so any iterable of nodes can be spliced into the parent.
+    fn process_jsx_spread_child(
+        &mut self,
+        spread_child: &JSXSpreadChild,
+        parent: Option<usize>,
+        children_position: usize,
+    ) -> ComponentResult<Vec<ElementChildren>> {
+        let expression = JSXExprContainer {
+            span: spread_child.span,
+            expr: JSXExpr::Expr(Box::new(spread_child_to_array(spread_child))),
+        };
+
+        self.process_jsx_expression_container(&expression, parent, children_position)
+    }
+
+    fn process_branch_expression(
+        &mut self,
+        span: Span,
+        path: &BranchPath,
+    ) -> ComponentResult<Vec<ElementChildren>> {
+        self.elements
+            .push(ElementDescriptor::Branch(BranchElementDescriptor {
+                span,
+                branches: vec![],
+            }));
+
+        let position = self.elements.len() - 1;
+        let statement = self.process_branch_path(position, path)?;
+
+        self.initialize_statements.push(statement.clone());
+        self.update_statements.push(statement);
+
+        Ok(vec![ElementChildren::Element(position)])
+    }
+
+    fn process_branch_path(&mut self, position: usize, path: &BranchPath) -> ComponentResult<Stmt> {
+        match path {
+            BranchPath::Condition {
+                span,
+                test,
+                cons,
+                alt,
+            } => {
+                let cons = self.process_branch_path(position, cons)?;
+                let alt = self.process_branch_path(position, alt)?;
+
+                Ok(generate_if_statement(
+                    *span,
+                    test,
+                    vec![cons],
+                    Some(vec![alt]),
+                ))
+            }
+            BranchPath::And {
+                span,
+                test,
+                cons,
+                renders_test,
+            } => {
+                let cons = self.process_branch_path(position, cons)?;
+                let first_element = self.elements.len();
+                let (initialize, update, children) = if *renders_test {
+                    self.capture_statements(|processor| {
+                        processor.process_value_fragment(test.span(), test)
+                    })
+                } else {
+                    (vec![], vec![], Ok(vec![]))
+                };
+                let alt =
+                    self.add_branch(position, children?, initialize, update, first_element, &[]);
+
+                Ok(generate_if_statement(
+                    *span,
+                    test,
+                    vec![cons],
+                    Some(vec![alt]),
+                ))
+            }
+            BranchPath::Leaf(expr) => {
+                let first_element = self.elements.len();
+                let (initialize, update, children) =
+                    self.capture_statements(|processor| processor.process_expression(expr));
+
+                Ok(self.add_branch(position, children?, initialize, update, first_element, &[]))
+            }
+        }
+    }
+
+    fn process_render_prop(&mut self, expr: &Expr) -> Option<Expr> {
+        let (params, body) = get_jsx_callback(expr)?;
+        let mut props = params
+            .iter()
+            .flat_map(get_pat_identifiers)
+            .collect::<Vec<_>>();
+
+        props.extend(get_free_identifiers(&params, &body));
+
+        self.lists.push(ListDescriptor {
+            span: expr.span(),
+            props: props.clone(),
+            body,
+            render_prop: true,
+        });
+
+        Some(generate_render_prop_call(
+            self.lists.len() - 1,
+            params,
+            &props,
+        ))
+    }
+
+    // Render props given as children are created by the default children
+    // element, which is declared even when the component does not render it.
+    fn get_children_slot(&mut self) -> usize {
+        if let Some((_, index)) = self.slots.iter().find(|(name, _)| name == "children") {
+            return *index;
+        }
+
+        self.elements
+            .push(ElementDescriptor::Children(ChildrenElementDescriptor {
+                name: "children".to_string(),
+                fallback: None,
+            }));
+
+        let position = self.elements.len() - 1;
+
+        self.slots.push(("children".to_string(), position));
+        position
     }
 
     fn process_jsx_children(
         &mut self,
         children: &Vec<JSXElementChild>,
         parent: Option<usize>,
-    ) -> Vec<ElementChildren> {
+    ) -> ComponentResult<Vec<ElementChildren>> {
         let mut result = vec![];
 
         for child in children {
             let mut children_elements = match child {
-                JSXElementChild::JSXElement(element) => self.process_jsx_element(element),
-                JSXElementChild::JSXFragment(fragment) => self.process_jsx_fragment(fragment),
+                JSXElementChild::JSXElement(element) => self.process_jsx_element(element)?,
+                JSXElementChild::JSXFragment(fragment) => self.process_jsx_fragment(fragment)?,
                 JSXElementChild::JSXText(text) => self.process_jsx_text(text),
                 JSXElementChild::JSXExprContainer(expression) => {
-                    self.process_jsx_expression_container(expression, parent, result.len())
+                    self.process_jsx_expression_container(expression, parent, result.len())?
                 }
-                JSXElementChild::JSXSpreadChild(_) => {
-                    panic!("JSX Spread Child is not supported");
+                JSXElementChild::JSXSpreadChild(spread_child) => {
+                    self.process_jsx_spread_child(spread_child, parent, result.len())?
                 }
             };
             result.append(&mut children_elements);
         }
 
-        result
+        Ok(result)
     }
 
-    fn add_update_props_statement(&mut self, position: usize, props: &Option<ObjectLit>) {
-        if let Some(props) = props {
-            self.update_statements
-                .push(generate_element_update_props_call(position, props));
-        }
+    // Static props are only applied in initialize. Components receive their
+    // props as a whole, so they are only skipped when none of them can change.
+    fn add_update_props_statement(
+        &mut self,
+        position: usize,
+        props: &Option<ObjectLit>,
+        is_component: bool,
+    ) {
+        let props = match props {
+            Some(props) if !props.props.iter().all(is_static_prop) => props,
+            _ => return,
+        };
+
+        let props = match is_component {
+            true => props.clone(),
+            false => ObjectLit {
+                props: props
+                    .props
+                    .iter()
+                    .filter(|prop| !is_static_prop(prop))
+                    .cloned()
+                    .collect(),
+                ..props.clone()
+            },
+        };
+
+        self.update_statements
+            .push(generate_element_update_props_call(position, &props));
     }
 
     fn add_initialize_statement(&mut self, position: usize, props: &Option<ObjectLit>) {
@@ -713,84 +1516,232 @@ impl Processor {
             .push(generate_element_initialize_call(position, props));
     }
 
-    fn get_element_descriptor(&mut self, element: &JSXOpeningElement) -> ElementDescriptor {
-        match element.name.clone() {
-            JSXElementName::Ident(ident) if ident.sym == "Children" => ElementDescriptor::Children,
-            JSXElementName::Ident(ident) if HTML_ELEMENT_TAGS.contains(&ident.sym.as_str()) => {
+    // Slots are only filled by the direct children of a component
+    fn check_slot_children(&self, position: usize) -> ComponentResult<()> {
+        let children = match &self.elements[position] {
+            ElementDescriptor::Tag(tag) => &tag.children,
+            ElementDescriptor::Fragment(fragment) => &fragment.children,
+            ElementDescriptor::Slot(slot) => &slot.children,
+            _ => return Ok(()),
+        };
+
+        for child in children {
+            if let ElementChildren::Element(index) = child {
+                if let ElementDescriptor::Slot(slot) = &self.elements[*index] {
+                    return Err(ComponentError::SlotOutsideComponent(slot.span));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn get_element_descriptor(
+        &mut self,
+        element: &JSXOpeningElement,
+    ) -> ComponentResult<ElementDescriptor> {
+        if is_runtime_element(&element.name, RuntimeHelper::Children) {
+            return Ok(ElementDescriptor::Children(ChildrenElementDescriptor {
+                name: get_slot_name(element)?.unwrap_or_else(|| "children".to_string()),
+                fallback: None,
+            }));
+        }
+
+        if is_runtime_element(&element.name, RuntimeHelper::Slot) {
+            return match get_slot_name(element)? {
+                Some(name) => Ok(ElementDescriptor::Slot(SlotElementDescriptor {
+                    span: element.span,
+                    name,
+                    children: vec![],
+                })),
+                None => Err(ComponentError::SlotNameNotString(element.span)),
+            };
+        }
+
+        Ok(match element.name.clone() {
+            JSXElementName::Ident(ident) if is_html_tag(&ident.sym) => {
                 ElementDescriptor::Tag(TagElementDescriptor {
+                    span: element.span,
                     tag: ident.sym.as_str().to_string(),
                     children: vec![],
-                    props: self.get_element_props(element),
+                    props: self.get_element_props(element, false)?,
                 })
             }
             JSXElementName::Ident(ident) => {
                 ElementDescriptor::Component(ComponentElementDescriptor {
+                    span: element.span,
                     component: ComponentName::Identifier(ident.clone()),
                     children: vec![],
-                    props: self.get_element_props(element),
+                    props: self.get_element_props(element, true)?,
                 })
             }
             JSXElementName::JSXMemberExpr(member_expression) => {
                 ElementDescriptor::Component(ComponentElementDescriptor {
+                    span: element.span,
                     component: ComponentName::MemberExpression(jsx_member_expr_to_member_expr(
                         &member_expression,
                     )),
                     children: vec![],
-                    props: self.get_element_props(element),
+                    props: self.get_element_props(element, true)?,
                 })
             }
-            JSXElementName::JSXNamespacedName(_) => {
-                panic!("JSX Namespaced Name is not supported");
+            JSXElementName::JSXNamespacedName(name) => {
+                return Err(ComponentError::NamespacedName(name.span()));
             }
-        }
+        })
     }
 
-    fn get_element_props(&mut self, element: &JSXOpeningElement) -> Option<ObjectLit> {
+    fn get_element_props(
+        &mut self,
+        element: &JSXOpeningElement,
+        is_component: bool,
+    ) -> ComponentResult<Option<ObjectLit>> {
         if element.attrs.is_empty() {
-            return None;
+            return Ok(None);
         }
 
-        Some(ObjectLit {
+        Ok(Some(ObjectLit {
             span: element.span,
             props: element
                 .attrs
                 .iter()
                 .map(|attr| match attr {
                     JSXAttrOrSpread::JSXAttr(attr) => {
-                        PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                        Ok(PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
                             key: PropName::Ident(match &attr.name {
                                 JSXAttrName::Ident(ident) => ident.clone(),
-                                JSXAttrName::JSXNamespacedName(_) => {
-                                    panic!("JSX Namespaced Name is not supported")
+                                JSXAttrName::JSXNamespacedName(name) => {
+                                    return Err(ComponentError::NamespacedName(name.span()));
                                 }
                             }),
-                            value: match attr.value.clone() {
-                                Some(value) => Box::new(match value {
-                                    JSXAttrValue::Lit(lit) => Expr::Lit(lit.clone()),
-                                    JSXAttrValue::JSXExprContainer(container) => {
-                                        match &container.expr {
-                                            JSXExpr::Expr(expr) => {
-                                                let expr_elements = self.process_expression(expr);
-                                                replace_jsx_elements_in_expression(
-                                                    expr,
-                                                    &mut VecDeque::from(expr_elements),
-                                                )
-                                            }
-                                            _ => jsx_expr_to_expr(&container.expr),
-                                        }
-                                    }
-                                    _ => panic!("JSX Attribute Value is not supported"), // TODO: SUpport jsxElement as prop
-                                }),
+                            value: match &attr.value {
+                                Some(value) => {
+                                    Box::new(self.process_attribute_value(value, is_component)?)
+                                }
                                 None => Box::new(Expr::Lit(Lit::Bool(Bool {
                                     span: DUMMY_SP,
                                     value: true,
                                 }))),
                             },
-                        })))
+                        }))))
+                    }
+                    JSXAttrOrSpread::SpreadElement(spread) => {
+                        Ok(PropOrSpread::Spread(spread.clone()))
                     }
-                    JSXAttrOrSpread::SpreadElement(spread) => PropOrSpread::Spread(spread.clone()),
                 })
-                .collect(),
+                .collect::<ComponentResult<Vec<_>>>()?,
+        }))
+    }
+
+    // Elements given as attribute values are created with the component, and
+    // JSX returning callbacks given to components are compiled as render props
+    fn process_attribute_value(
+        &mut self,
+        value: &JSXAttrValue,
+        is_component: bool,
+    ) -> ComponentResult<Expr> {
+        let expr = match value {
+            JSXAttrValue::Lit(lit) => return Ok(Expr::Lit(lit.clone())),
+            JSXAttrValue::JSXExprContainer(container) => match &container.expr {
+                JSXExpr::Expr(expr) => expr.deref().clone(),
+                expr => return Ok(jsx_expr_to_expr(expr)),
+            },
+            JSXAttrValue::JSXElement(element) => Expr::JSXElement(element.clone()),
+            // A fragment is kept as a single element, as with returned fragments
+            JSXAttrValue::JSXFragment(fragment) => {
+                let position = self.elements.len();
+
+                self.process_root_fragment(fragment)?;
+
+                return Ok(Expr::Ident(element_ident(position)));
+            }
+        };
+
+        if is_component {
+            if let Some(render_prop) = self.process_render_prop(&expr) {
+                return Ok(render_prop);
+            }
+        }
+
+        let expr_elements = self.process_expression(&expr)?;
+
+        Ok(replace_jsx_elements_in_expression(
+            &expr,
+            &mut VecDeque::from(expr_elements),
+        ))
+    }
+}
+
+impl ListLowering for Processor {
+    fn lower_list(&mut self, list: ListCallback) -> Expr {
+        let mut props = list
+            .params
+            .iter()
+            .flat_map(get_pat_identifiers)
+            .collect::<Vec<_>>();
+
+        props.extend(get_free_identifiers(&list.params, &list.body));
+
+        self.lists.push(ListDescriptor {
+            span: list.span,
+            props: props.clone(),
+            body: list.body,
+            render_prop: false,
+        });
+
+        Expr::Arrow(ArrowExpr {
+            params: list.params,
+            body: Box::new(BlockStmtOrExpr::Expr(Box::new(generate_list_render_call(
+                self.lists.len() - 1,
+                &props,
+                list.key,
+            )))),
+            ..ArrowExpr::default()
         })
     }
+
+    // Lists render their elements in the call, which collects them
+    fn lower_list_call(&mut self, call: Expr, lists: usize) -> Expr {
+        (self.lists.len() - lists..self.lists.len())
+            .fold(call, |call, index| generate_list_collect_call(index, call))
+    }
+}
+
+// Replaces the `$children(...)` calls rendering the render prop given as
+// children, the elements being created by the default children element.
+struct RenderChildrenReplacer<'a> {
+    processor: &'a mut Processor,
+    children: Option<usize>,
+}
+
+impl VisitMut for RenderChildrenReplacer<'_> {
+    fn visit_mut_expr(&mut self, expr: &mut Expr) {
+        expr.visit_mut_children_with(self);
+
+        let Expr::Call(call_expression) = expr else {
+            return;
+        };
+
+        let Callee::Expr(callee) = &call_expression.callee else {
+            return;
+        };
+
+        if !is_runtime_helper(callee, RuntimeHelper::RenderChildren) {
+            return;
+        }
+
+        let children = *self
+            .children
+            .get_or_insert_with(|| self.processor.get_children_slot());
+
+        *expr = generate_render_children_call(
+            children,
+            self.processor.render_sites,
+            std::mem::take(&mut call_expression.args),
+        );
+    }
+
+    fn visit_mut_jsx_element(&mut self, _: &mut JSXElement) {}
+
+    fn visit_mut_jsx_fragment(&mut self, _: &mut JSXFragment) {}
 }
@@ -0,0 +1,666 @@
+/**
+ * Copyright 2024 Aubin REBILLAT
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+use std::ops::Deref;
+
+use swc_core::common::{Span, DUMMY_SP};
+use swc_core::ecma::ast::{
+    ArrowExpr, BinExpr, BinaryOp, BindingIdent, BlockStmt, BlockStmtOrExpr, Bool, CallExpr, Callee,
+    CondExpr, Expr, ExprOrSpread, ExprStmt, IdentName, JSXAttr, JSXAttrName, JSXAttrOrSpread,
+    JSXAttrValue, JSXElement, JSXElementChild, JSXElementName, JSXExpr, JSXExprContainer,
+    KeyValueProp, Lit, MemberExpr, MemberProp, ObjectLit, ObjectPat, Param, Pat, Prop, PropName,
+    PropOrSpread, Stmt, TaggedTpl, Tpl, TplElement, VarDeclarator,
+};
+use swc_core::ecma::visit::{VisitMut, VisitMutWith};
+
+use crate::error::ComponentResult;
+use crate::generators::ComponentDeclaration;
+use crate::lowering::{get_branch_path, lower_lists, BranchPath, ListCallback, ListLowering};
+use crate::names::{
+    generated_ident, is_runtime_element, is_runtime_helper, runtime_ident, RuntimeHelper,
+};
+use crate::processor::{
+    get_slot_name, is_html_tag, is_palta_effect_call, is_palta_state_call, Processor,
+};
+use crate::utils::{
+    get_jsx_callback, jsx_member_expr_to_member_expr, slot_member_prop, slot_prop_name,
+    spread_child_to_array,
+};
+
+pub const HYDRATION_START: &str = "<!--[-->";
+pub const HYDRATION_END: &str = "<!--]-->";
+
+// Elements rendered without a closing tag, whose children are ignored
+pub const VOID_ELEMENT_TAGS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "keygen", "link", "meta", "param",
+    "source", "track", "wbr",
+];
+
+pub fn escape_html(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+// Event handlers are only bound in the browser
+fn is_event_handler(name: &str) -> bool {
+    name.strip_prefix("on")
+        .is_some_and(|event| event.starts_with(|c: char| c.is_ascii_uppercase()))
+}
+
+fn generate_runtime_call(method: &str, args: Vec<Expr>) -> Expr {
+    Expr::Call(CallExpr {
+        callee: Callee::Expr(Box::new(Expr::Member(MemberExpr {
+            obj: Box::new(Expr::Ident(runtime_ident())),
+            prop: MemberProp::Ident(IdentName {
+                sym: method.into(),
+                ..IdentName::default()
+            }),
+            ..MemberExpr::default()
+        }))),
+        args: args
+            .into_iter()
+            .map(|arg| ExprOrSpread {
+                spread: None,
+                expr: Box::new(arg),
+            })
+            .collect(),
+        ..CallExpr::default()
+    })
+}
+
+// Markup known at compile time is escaped in the template strings, and the
+// interpolated values are escaped by the `Palta.html` tag when rendered
+struct HtmlTemplate {
+    quasis: Vec<String>,
+    exprs: Vec<Expr>,
+}
+
+impl HtmlTemplate {
+    fn new() -> Self {
+        HtmlTemplate {
+            quasis: vec![String::new()],
+            exprs: vec![],
+        }
+    }
+
+    fn push_str(&mut self, value: &str) {
+        if let Some(quasi) = self.quasis.last_mut() {
+            quasi.push_str(value);
+        }
+    }
+
+    fn push_expr(&mut self, expr: Expr) {
+        self.exprs.push(expr);
+        self.quasis.push(String::new());
+    }
+
+    // Children changing between renders are delimited by markers, so that the
+    // hydrating client can locate them among the server-rendered nodes
+    fn push_dynamic(&mut self, expr: Option<Expr>) {
+        self.push_str(HYDRATION_START);
+
+        if let Some(expr) = expr {
+            self.push_expr(expr);
+        }
+
+        self.push_str(HYDRATION_END);
+    }
+
+    fn is_empty(&self) -> bool {
+        self.exprs.is_empty() && self.quasis[0].is_empty()
+    }
+
+    fn into_expr(self, span: Span) -> Expr {
+        let last = self.quasis.len() - 1;
+
+        Expr::TaggedTpl(TaggedTpl {
+            span,
+            tag: Box::new(Expr::Member(MemberExpr {
+                obj: Box::new(Expr::Ident(runtime_ident())),
+                prop: MemberProp::Ident(IdentName {
+                    sym: "html".into(),
+                    ..IdentName::default()
+                }),
+                ..MemberExpr::default()
+            })),
+            tpl: Box::new(Tpl {
+                span,
+                exprs: self.exprs.into_iter().map(Box::new).collect(),
+                quasis: self
+                    .quasis
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, quasi)| TplElement {
+                        span: DUMMY_SP,
+                        tail: index == last,
+                        raw: quasi
+                            .replace('\\', "\\\\")
+                            .replace('`', "\\`")
+                            .replace("${", "\\${")
+                            .into(),
+                        cooked: Some(quasi.into()),
+                    })
+                    .collect(),
+            }),
+            ..TaggedTpl::default()
+        })
+    }
+}
+
+// Rewrites a component body so that it returns its markup: `$state` values
+// are kept as plain variables, `$effect` calls are removed, and JSX becomes
+// `Palta.html` templates.
+#[derive(Default)]
+struct SsrCompiler {
+    uses_children: bool,
+    uses_slots: bool,
+}
+
+impl SsrCompiler {
+    fn compile_expr(&mut self, expr: &Expr) -> Expr {
+        let mut expr = expr.clone();
+
+        expr.visit_mut_with(self);
+        expr
+    }
+
+    // Children are lowered like the DOM compiler does, so that the server
+    // renders the branch paths and list items the client creates
+    fn compile_child(&mut self, expr: &Expr) -> Expr {
+        let mut expr = expr.clone();
+
+        lower_lists(&mut expr, self);
+
+        match get_branch_path(&expr) {
+            Some(path) => self.compile_branch_path(&path),
+            None => self.compile_expr(&expr),
+        }
+    }
+
+    fn compile_branch_path(&mut self, path: &BranchPath) -> Expr {
+        match path {
+            BranchPath::Condition {
+                span,
+                test,
+                cons,
+                alt,
+            } => Expr::Cond(CondExpr {
+                span: *span,
+                test: Box::new(self.compile_expr(test)),
+                cons: Box::new(self.compile_branch_path(cons)),
+                alt: Box::new(self.compile_branch_path(alt)),
+            }),
+            // Falsy tests are rendered by the runtime, which renders nothing
+            // for booleans
+            BranchPath::And {
+                span, test, cons, ..
+            } => Expr::Bin(BinExpr {
+                span: *span,
+                op: BinaryOp::LogicalAnd,
+                left: Box::new(self.compile_expr(test)),
+                right: Box::new(self.compile_branch_path(cons)),
+            }),
+            BranchPath::Leaf(expr) => self.compile_expr(expr),
+        }
+    }
+
+    // Components render their own markup, which is returned as is
+    fn compile_element(&mut self, element: &JSXElement) -> Expr {
+        if let Some(call) = self.component_call(element) {
+            return call;
+        }
+
+        let mut template = HtmlTemplate::new();
+
+        self.push_element(&mut template, element);
+        template.into_expr(element.span)
+    }
+
+    fn component_call(&mut self, element: &JSXElement) -> Option<Expr> {
+        if is_runtime_element(&element.opening.name, RuntimeHelper::Children)
+            || is_runtime_element(&element.opening.name, RuntimeHelper::Slot)
+        {
+            return None;
+        }
+
+        let component = match &element.opening.name {
+            JSXElementName::Ident(ident) if !is_html_tag(&ident.sym) => Expr::Ident(ident.clone()),
+            JSXElementName::JSXMemberExpr(member) => {
+                Expr::Member(jsx_member_expr_to_member_expr(member))
+            }
+            _ => return None,
+        };
+
+        let mut args = vec![component];
+        let mut children = HtmlTemplate::new();
+        let mut slots = vec![];
+
+        for child in element.children.iter() {
+            match child {
+                JSXElementChild::JSXElement(slot)
+                    if is_runtime_element(&slot.opening.name, RuntimeHelper::Slot) =>
+                {
+                    slots.push(self.slot_prop(slot));
+                }
+                // Render props are called by the component through `$children`
+                JSXElementChild::JSXExprContainer(JSXExprContainer {
+                    expr: JSXExpr::Expr(expr),
+                    ..
+                }) if get_jsx_callback(expr).is_some() => {
+                    slots.push(PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                        key: slot_prop_name("$children"),
+                        value: Box::new(self.compile_expr(expr)),
+                    }))));
+                }
+                child => self.push_children(&mut children, std::slice::from_ref(child)),
+            }
+        }
+
+        let has_children = !children.is_empty();
+
+        if !element.opening.attrs.is_empty() || has_children || !slots.is_empty() {
+            args.push(Expr::Object(self.component_props(&element.opening.attrs)));
+        }
+
+        if has_children || !slots.is_empty() {
+            args.push(children.into_expr(element.span));
+        }
+
+        if !slots.is_empty() {
+            args.push(Expr::Object(ObjectLit {
+                props: slots,
+                ..ObjectLit::default()
+            }));
+        }
+
+        Some(generate_runtime_call("renderComponent", args))
+    }
+
+    // Slot names are checked by the processor
+    fn slot_prop(&mut self, slot: &JSXElement) -> PropOrSpread {
+        let name = get_slot_name(&slot.opening)
+            .ok()
+            .flatten()
+            .unwrap_or_default();
+        let mut template = HtmlTemplate::new();
+
+        self.push_children(&mut template, &slot.children);
+
+        PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+            key: slot_prop_name(&name),
+            value: Box::new(template.into_expr(slot.span)),
+        })))
+    }
+
+    fn push_element(&mut self, template: &mut HtmlTemplate, element: &JSXElement) {
+        if is_runtime_element(&element.opening.name, RuntimeHelper::Children) {
+            let slot = match get_slot_name(&element.opening).ok().flatten() {
+                Some(name) if name != "children" => {
+                    self.uses_slots = true;
+                    Expr::Member(MemberExpr {
+                        obj: Box::new(Expr::Ident(generated_ident("__$slots"))),
+                        prop: slot_member_prop(&name),
+                        ..MemberExpr::default()
+                    })
+                }
+                _ => {
+                    self.uses_children = true;
+                    Expr::Ident(generated_ident("__$children"))
+                }
+            };
+
+            let mut fallback = HtmlTemplate::new();
+
+            self.push_children(&mut fallback, &element.children);
+
+            if fallback.is_empty() {
+                template.push_dynamic(Some(slot));
+            } else {
+                template.push_dynamic(Some(generate_runtime_call(
+                    "fallback",
+                    vec![slot, fallback.into_expr(element.span)],
+                )));
+            }
+            return;
+        }
+
+        if let Some(call) = self.component_call(element) {
+            template.push_dynamic(Some(call));
+            return;
+        }
+
+        // Namespaced names are rejected by the processor
+        let JSXElementName::Ident(tag) = &element.opening.name else {
+            return;
+        };
+
+        template.push_str(&format!("<{}", tag.sym));
+
+        for attr in element.opening.attrs.iter() {
+            self.push_attribute(template, attr);
+        }
+
+        template.push_str(">");
+
+        if VOID_ELEMENT_TAGS.contains(&tag.sym.as_str()) {
+            return;
+        }
+
+        self.push_children(template, &element.children);
+        template.push_str(&format!("</{}>", tag.sym));
+    }
+
+    fn push_children(&mut self, template: &mut HtmlTemplate, children: &[JSXElementChild]) {
+        for child in children {
+            match child {
+                JSXElementChild::JSXText(text) => {
+                    if !text.value.trim().is_empty() {
+                        template.push_str(&escape_html(&text.value));
+                    }
+                }
+                JSXElementChild::JSXExprContainer(container) => match &container.expr {
+                    JSXExpr::Expr(expr) => template.push_dynamic(Some(self.compile_child(expr))),
+                    JSXExpr::JSXEmptyExpr(_) => template.push_dynamic(None),
+                },
+                JSXElementChild::JSXSpreadChild(spread) => {
+                    template.push_dynamic(Some(self.compile_child(&spread_child_to_array(spread))));
+                }
+                JSXElementChild::JSXElement(element) => self.push_element(template, element),
+                JSXElementChild::JSXFragment(fragment) => {
+                    self.push_children(template, &fragment.children)
+                }
+            }
+        }
+    }
+
+    // Literal attributes are rendered at compile time, the others by the
+    // runtime, which skips event handlers and empty values
+    fn push_attribute(&mut self, template: &mut HtmlTemplate, attr: &JSXAttrOrSpread) {
+        let attr = match attr {
+            JSXAttrOrSpread::JSXAttr(attr) => attr,
+            JSXAttrOrSpread::SpreadElement(spread) => {
+                let props = self.compile_expr(&spread.expr);
+
+                template.push_expr(generate_runtime_call("attributes", vec![props]));
+                return;
+            }
+        };
+
+        let JSXAttrName::Ident(name) = &attr.name else {
+            return;
+        };
+
+        if name.sym == "key" || is_event_handler(&name.sym) {
+            return;
+        }
+
+        let attribute_name = match name.sym.as_str() {
+            "className" => "class",
+            name => name,
+        };
+
+        match &attr.value {
+            None | Some(JSXAttrValue::Lit(Lit::Bool(Bool { value: true, .. }))) => {
+                template.push_str(&format!(" {}", attribute_name))
+            }
+            Some(JSXAttrValue::Lit(Lit::Bool(_))) => {}
+            Some(JSXAttrValue::Lit(Lit::Str(value))) => template.push_str(&format!(
+                " {}=\"{}\"",
+                attribute_name,
+                escape_html(&value.value)
+            )),
+            Some(JSXAttrValue::Lit(Lit::Num(value))) => {
+                template.push_str(&format!(" {}=\"{}\"", attribute_name, value.value))
+            }
+            _ => {
+                if let Some(value) = self.attr_value(attr) {
+                    template.push_expr(generate_runtime_call(
+                        "attribute",
+                        vec![Expr::Lit(Lit::Str(name.sym.clone().into())), value],
+                    ));
+                }
+            }
+        }
+    }
+
+    fn attr_value(&mut self, attr: &JSXAttr) -> Option<Expr> {
+        match attr.value.as_ref()? {
+            JSXAttrValue::Lit(Lit::Bool(value)) if value.value => None,
+            JSXAttrValue::Lit(lit) => Some(Expr::Lit(lit.clone())),
+            JSXAttrValue::JSXExprContainer(container) => match &container.expr {
+                JSXExpr::Expr(expr) => Some(self.compile_expr(expr)),
+                JSXExpr::JSXEmptyExpr(_) => None,
+            },
+            JSXAttrValue::JSXElement(element) => {
+                Some(self.compile_expr(&Expr::JSXElement(element.clone())))
+            }
+            JSXAttrValue::JSXFragment(fragment) => {
+                Some(self.compile_expr(&Expr::JSXFragment(fragment.clone())))
+            }
+        }
+    }
+
+    fn component_props(&mut self, attrs: &[JSXAttrOrSpread]) -> ObjectLit {
+        ObjectLit {
+            props: attrs
+                .iter()
+                .filter_map(|attr| match attr {
+                    JSXAttrOrSpread::JSXAttr(attr) => {
+                        let JSXAttrName::Ident(name) = &attr.name else {
+                            return None;
+                        };
+
+                        Some(PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                            key: PropName::Ident(name.clone()),
+                            value: Box::new(
+                                self.attr_value(attr)
+                                    .unwrap_or(Expr::Lit(Lit::Bool(true.into()))),
+                            ),
+                        }))))
+                    }
+                    JSXAttrOrSpread::SpreadElement(spread) => {
+                        let mut spread = spread.clone();
+
+                        spread.expr.visit_mut_with(self);
+                        Some(PropOrSpread::Spread(spread))
+                    }
+                })
+                .collect(),
+            ..ObjectLit::default()
+        }
+    }
+
+    fn state_declarators(&self, decl: &VarDeclarator) -> Option<Vec<VarDeclarator>> {
+        let call = decl
+            .init
+            .as_deref()?
+            .as_call()
+            .filter(|call| is_palta_state_call(call))?;
+        let Pat::Array(array) = &decl.name else {
+            return None;
+        };
+
+        let mut decls = vec![];
+
+        if let Some(Some(variable)) = array.elems.first() {
+            decls.push(VarDeclarator {
+                span: decl.span,
+                name: variable.clone(),
+                init: Some(match call.args.first() {
+                    Some(arg) => arg.expr.clone(),
+                    None => Expr::undefined(DUMMY_SP),
+                }),
+                definite: false,
+            });
+        }
+
+        // Nothing is rendered again on the server, so updates are ignored
+        if let Some(Some(updater)) = array.elems.get(1) {
+            decls.push(VarDeclarator {
+                span: decl.span,
+                name: updater.clone(),
+                init: Some(Box::new(Expr::Arrow(ArrowExpr {
+                    body: Box::new(BlockStmtOrExpr::BlockStmt(BlockStmt::default())),
+                    ..ArrowExpr::default()
+                }))),
+                definite: false,
+            });
+        }
+
+        Some(decls)
+    }
+}
+
+// List items are rendered by their callback, without their key
+impl ListLowering for SsrCompiler {
+    fn lower_list(&mut self, list: ListCallback) -> Expr {
+        Expr::Arrow(ArrowExpr {
+            span: list.span,
+            params: list.params,
+            body: Box::new(list.body),
+            ..ArrowExpr::default()
+        })
+    }
+}
+
+impl VisitMut for SsrCompiler {
+    fn visit_mut_expr(&mut self, expr: &mut Expr) {
+        match expr {
+            Expr::JSXElement(element) => *expr = self.compile_element(element),
+            Expr::JSXFragment(fragment) => {
+                let mut template = HtmlTemplate::new();
+
+                self.push_children(&mut template, &fragment.children);
+                *expr = template.into_expr(fragment.span);
+            }
+            Expr::Call(CallExpr {
+                callee: Callee::Expr(callee),
+                args,
+                ..
+            }) if is_runtime_helper(callee, RuntimeHelper::RenderChildren) => {
+                args.visit_mut_with(self);
+                self.uses_slots = true;
+
+                let args = std::iter::once(Expr::Ident(generated_ident("__$slots")))
+                    .chain(args.iter().map(|arg| *arg.expr.clone()))
+                    .collect();
+
+                *expr = generate_runtime_call("renderChildren", args);
+            }
+            expr => expr.visit_mut_children_with(self),
+        }
+    }
+
+    fn visit_mut_stmts(&mut self, stmts: &mut Vec<Stmt>) {
+        stmts.retain(|stmt| {
+            !matches!(stmt, Stmt::Expr(ExprStmt { expr, .. })
+                if matches!(expr.deref(), Expr::Call(call) if is_palta_effect_call(call)))
+        });
+
+        stmts.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_var_declarators(&mut self, decls: &mut Vec<VarDeclarator>) {
+        decls.visit_mut_children_with(self);
+
+        *decls = std::mem::take(decls)
+            .into_iter()
+            .flat_map(|decl| self.state_declarators(&decl).unwrap_or_else(|| vec![decl]))
+            .collect();
+    }
+}
+
+fn children_params(params: &[Pat], uses_slots: bool) -> Vec<Pat> {
+    let mut params = params.to_vec();
+
+    if params.is_empty() {
+        params.push(Pat::Object(ObjectPat {
+            span: DUMMY_SP,
+            props: vec![],
+            optional: false,
+            type_ann: None,
+        }));
+    }
+
+    params.truncate(1);
+    params.push(Pat::Ident(BindingIdent {
+        id: generated_ident("__$children"),
+        type_ann: None,
+    }));
+
+    if uses_slots {
+        params.push(Pat::Ident(BindingIdent {
+            id: generated_ident("__$slots"),
+            type_ann: None,
+        }));
+    }
+
+    params
+}
+
+// Server components receive their props and rendered children, and return
+// their markup instead of a component definition.
+pub fn generate_ssr_component_declaration(node: ComponentDeclaration) -> ComponentResult<()> {
+    let mut compiler = SsrCompiler::default();
+
+    match node {
+        ComponentDeclaration::Function(function) => {
+            Processor::new().process_function(function)?;
+
+            if let Some(body) = function.body.as_mut() {
+                body.visit_mut_with(&mut compiler);
+            }
+
+            if compiler.uses_children || compiler.uses_slots {
+                let params = function
+                    .params
+                    .iter()
+                    .map(|param| param.pat.clone())
+                    .collect::<Vec<_>>();
+
+                function.params = children_params(&params, compiler.uses_slots)
+                    .into_iter()
+                    .map(Param::from)
+                    .collect();
+            }
+
+            function.return_type = None;
+        }
+        ComponentDeclaration::Arrow(arrow) => {
+            Processor::new().process_arrow_expression(arrow)?;
+            arrow.body.visit_mut_with(&mut compiler);
+
+            if compiler.uses_children || compiler.uses_slots {
+                arrow.params = children_params(&arrow.params, compiler.uses_slots);
+            }
+
+            arrow.return_type = None;
+        }
+    }
+
+    Ok(())
+}
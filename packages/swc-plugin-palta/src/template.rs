@@ -0,0 +1,201 @@
+use std::collections::{HashMap, HashSet};
+/**
+ * Copyright 2024 Aubin REBILLAT
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+use swc_core::ecma::ast::{Lit, ObjectLit, Prop, PropName, PropOrSpread};
+
+use crate::processor::{ElementChildren, ElementDescriptor, HydrationClaim, Processor};
+use crate::ssr::{escape_html, HYDRATION_END, HYDRATION_START, VOID_ELEMENT_TAGS};
+
+// Trees of elements created with the component and nested in one another are
+// rendered from a template when they contain static elements. The template is
+// parsed once and cloned for each instance: static elements are only part of
+// the clone, while the others claim their node from it, and the children
+// changing between renders are rendered in place of markers.
+#[derive(Default)]
+pub struct Templates {
+    // Root element of each template, along with its markup
+    pub roots: Vec<(usize, String)>,
+    pub claims: HashMap<usize, HydrationClaim>,
+    pub static_elements: HashSet<usize>,
+}
+
+impl Templates {
+    pub fn new(processor: &Processor) -> Self {
+        let mut templates = Templates::default();
+        let elements = processor.get_elements();
+        let is_tag = |index: usize| {
+            matches!(elements[index], ElementDescriptor::Tag(_))
+                && !processor.is_lazy_element(index)
+        };
+        let nested: HashSet<usize> = (0..elements.len())
+            .filter(|index| is_tag(*index))
+            .flat_map(|index| element_children(&elements[index]))
+            .collect();
+
+        for root in (0..elements.len()).filter(|index| is_tag(*index) && !nested.contains(index)) {
+            let mut tree = Templates::default();
+            let mut html = String::new();
+
+            if !tree.push_element(processor, root, &mut html) {
+                continue;
+            }
+
+            // The root is referenced by its parent even when static, so a
+            // template only saves work when it contains other static elements
+            tree.static_elements.remove(&root);
+
+            if tree.static_elements.is_empty() {
+                continue;
+            }
+
+            templates.roots.push((root, html));
+            templates.claims.extend(tree.claims);
+            templates.static_elements.extend(tree.static_elements);
+        }
+
+        templates
+    }
+
+    // Returns false when the markup cannot be parsed back into the children,
+    // as adjacent texts are merged into a single node
+    fn push_element(&mut self, processor: &Processor, index: usize, html: &mut String) -> bool {
+        let ElementDescriptor::Tag(tag) = &processor.get_elements()[index] else {
+            return false;
+        };
+        let attributes = tag
+            .props
+            .as_ref()
+            .map_or(Some(String::new()), static_attributes);
+        let mut is_static = attributes.is_some();
+
+        html.push_str(&format!(
+            "<{}{}",
+            tag.tag,
+            attributes.unwrap_or_else(|| literal_attributes(tag.props.as_ref()))
+        ));
+        html.push('>');
+
+        if VOID_ELEMENT_TAGS.contains(&tag.tag.as_str()) {
+            return self.mark_static(index, is_static);
+        }
+
+        let mut previous_text = false;
+
+        for (position, child) in tag.children.iter().enumerate() {
+            match child {
+                ElementChildren::Text(text) if !text.is_empty() => {
+                    if previous_text {
+                        return false;
+                    }
+
+                    html.push_str(&escape_html(text));
+                    previous_text = true;
+                    continue;
+                }
+                ElementChildren::Element(child)
+                    if matches!(processor.get_elements()[*child], ElementDescriptor::Tag(_))
+                        && !processor.is_lazy_element(*child) =>
+                {
+                    if !self.push_element(processor, *child, html) {
+                        return false;
+                    }
+
+                    if !self.static_elements.contains(child) {
+                        is_static = false;
+                        self.claims.insert(
+                            *child,
+                            HydrationClaim {
+                                parent: Some(index),
+                                position,
+                            },
+                        );
+                    }
+                }
+                _ => {
+                    html.push_str(HYDRATION_START);
+                    html.push_str(HYDRATION_END);
+                    is_static = false;
+                }
+            }
+
+            previous_text = false;
+        }
+
+        html.push_str(&format!("</{}>", tag.tag));
+        self.mark_static(index, is_static)
+    }
+
+    fn mark_static(&mut self, index: usize, is_static: bool) -> bool {
+        if is_static {
+            self.static_elements.insert(index);
+        }
+
+        true
+    }
+}
+
+fn element_children(element: &ElementDescriptor) -> Vec<usize> {
+    let ElementDescriptor::Tag(tag) = element else {
+        return vec![];
+    };
+
+    tag.children
+        .iter()
+        .filter_map(|child| match child {
+            ElementChildren::Element(index) => Some(*index),
+            ElementChildren::Text(_) => None,
+        })
+        .collect()
+}
+
+// Only strings and numbers are rendered as the element would set them, the
+// other values being set when the element is initialized
+fn literal_attribute(prop: &PropOrSpread) -> Option<String> {
+    let PropOrSpread::Prop(prop) = prop else {
+        return None;
+    };
+    let Prop::KeyValue(key_value) = prop.as_ref() else {
+        return None;
+    };
+    let name = match &key_value.key {
+        PropName::Ident(ident) => ident.sym.as_str(),
+        PropName::Str(name) => name.value.as_str(),
+        _ => return None,
+    };
+    let name = match name {
+        "className" => "class",
+        "style" => return None,
+        name => name,
+    };
+    let value = match key_value.value.as_lit()? {
+        Lit::Str(value) => value.value.to_string(),
+        Lit::Num(value) => value.value.to_string(),
+        _ => return None,
+    };
+
+    Some(format!(" {}=\"{}\"", name, escape_html(&value)))
+}
+
+fn literal_attributes(props: Option<&ObjectLit>) -> String {
+    props.map_or(String::new(), |props| {
+        props.props.iter().filter_map(literal_attribute).collect()
+    })
+}
+
+// Attributes of an element needing no initialization
+fn static_attributes(props: &ObjectLit) -> Option<String> {
+    props.props.iter().map(literal_attribute).collect()
+}
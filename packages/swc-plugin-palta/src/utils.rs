@@ -13,18 +13,26 @@
  * See the License for the specific language governing permissions and
  * limitations under the License.
  */
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 use std::ops::Deref;
 
 use swc_core::atoms::Atom;
+use swc_core::common::comments::{Comment, CommentKind};
 use swc_core::common::DUMMY_SP;
 use swc_core::ecma::ast::{
-    ArrayLit, AssignExpr, AssignOp, AssignTarget, AssignTargetPat, BinExpr, CondExpr, Expr,
-    ExprOrSpread, Ident, IdentName, Invalid, JSXElementChild, JSXExpr, JSXMemberExpr, JSXObject,
-    KeyValueProp, Lit, MemberExpr, MemberProp, ObjectLit, ObjectPatProp, Pat, Prop, PropName,
-    PropOrSpread, SimpleAssignTarget, SpreadElement, Str,
+    ArrayLit, ArrowExpr, AssignExpr, AssignOp, AssignTarget, AssignTargetPat, BinExpr, BlockStmt,
+    BlockStmtOrExpr, CallExpr, Callee, Class, ComputedPropName, CondExpr, Decl, DefaultDecl,
+    ExportDefaultDecl, ExportNamedSpecifier, ExportSpecifier, Expr, ExprOrSpread, FnExpr, Function,
+    Id, Ident, IdentName, Invalid, JSXAttr, JSXAttrName, JSXAttrOrSpread, JSXAttrValue, JSXElement,
+    JSXElementChild, JSXExpr, JSXFragment, JSXMemberExpr, JSXObject, JSXSpreadChild, KeyValueProp,
+    Lit, MemberExpr, MemberProp, ModuleDecl, ModuleExportName, ModuleItem, ObjectLit,
+    ObjectPatProp, Pat, Prop, PropName, PropOrSpread, ReturnStmt, SimpleAssignTarget,
+    SpreadElement, Stmt, Str, VarDecl,
 };
+use swc_core::ecma::visit::{Visit, VisitMut, VisitMutWith, VisitWith};
 
+use crate::error::{ComponentError, ComponentResult};
+use crate::names::{element_ident, is_runtime_component};
 use crate::processor::ElementChildren;
 
 pub fn jsx_expr_to_expr(expression: &JSXExpr) -> Expr {
@@ -51,6 +59,30 @@ pub fn jsx_member_expr_to_member_expr(member_expression: &JSXMemberExpr) -> Memb
     }
 }
 
+// Slot names are kept as identifiers when they are valid ones
+pub fn slot_prop_name(name: &str) -> PropName {
+    match Ident::verify_symbol(name) {
+        Ok(_) => PropName::Ident(IdentName {
+            sym: name.into(),
+            ..IdentName::default()
+        }),
+        Err(_) => PropName::Str(name.into()),
+    }
+}
+
+pub fn slot_member_prop(name: &str) -> MemberProp {
+    match Ident::verify_symbol(name) {
+        Ok(_) => MemberProp::Ident(IdentName {
+            sym: name.into(),
+            ..IdentName::default()
+        }),
+        Err(_) => MemberProp::Computed(ComputedPropName {
+            span: DUMMY_SP,
+            expr: Box::new(Expr::Lit(Lit::Str(name.into()))),
+        }),
+    }
+}
+
 pub fn pat_to_expr(pat: &Pat) -> Expr {
     match pat {
         Pat::Ident(binding_ident) => Expr::Ident(binding_ident.id.clone()),
@@ -127,10 +159,7 @@ fn get_next_element_variable(elements: &mut VecDeque<ElementChildren>) -> Expr {
                 value: text.into(),
                 raw: None,
             })),
-            ElementChildren::Element(position) => Expr::Ident(Ident {
-                sym: format!("__$element${}", position).into(),
-                ..Ident::default()
-            }),
+            ElementChildren::Element(position) => Expr::Ident(element_ident(position)),
         },
         None => Expr::Invalid(Invalid { span: DUMMY_SP }),
     }
@@ -157,7 +186,7 @@ fn replace_jsx_elements_in_jsx_element_child(
             JSXExpr::JSXEmptyExpr(_) => None,
         },
         JSXElementChild::JSXSpreadChild(spread_child) => Some(ExprOrSpread {
-            spread: None,
+            spread: Some(spread_child.span),
             expr: spread_child.expr.clone(),
         }),
         JSXElementChild::JSXElement(_) => Some(ExprOrSpread {
@@ -178,6 +207,16 @@ fn replace_jsx_elements_in_jsx_element_child(
     }
 }
 
+pub fn spread_child_to_array(spread_child: &JSXSpreadChild) -> Expr {
+    Expr::Array(ArrayLit {
+        span: spread_child.span,
+        elems: vec![Some(ExprOrSpread {
+            spread: Some(spread_child.span),
+            expr: spread_child.expr.clone(),
+        })],
+    })
+}
+
 pub fn replace_jsx_elements_in_expression(
     expr: &Expr,
     elements: &mut VecDeque<ElementChildren>,
@@ -214,3 +253,363 @@ pub fn replace_jsx_elements_in_expression(
         _ => expr.clone(),
     }
 }
+
+struct ReturnFinder {
+    found: bool,
+}
+
+impl Visit for ReturnFinder {
+    fn visit_return_stmt(&mut self, _: &ReturnStmt) {
+        self.found = true;
+    }
+
+    // Returns in nested functions and classes belong to them, not to the component
+    fn visit_function(&mut self, _: &Function) {}
+
+    fn visit_arrow_expr(&mut self, _: &ArrowExpr) {}
+
+    fn visit_class(&mut self, _: &Class) {}
+}
+
+pub fn stmt_contains_return(stmt: &Stmt) -> bool {
+    let mut finder = ReturnFinder { found: false };
+
+    stmt.visit_with(&mut finder);
+
+    finder.found
+}
+
+// An expression is static when it is only made of literals, so its value
+// cannot change between two updates of the component.
+pub fn is_static_expression(expr: &Expr) -> bool {
+    match expr {
+        Expr::Lit(_) => true,
+        Expr::Tpl(tpl) => tpl.exprs.iter().all(|expr| is_static_expression(expr)),
+        Expr::Paren(paren) => is_static_expression(&paren.expr),
+        Expr::Unary(unary) => is_static_expression(&unary.arg),
+        Expr::Bin(bin) => is_static_expression(&bin.left) && is_static_expression(&bin.right),
+        Expr::Cond(cond) => {
+            is_static_expression(&cond.test)
+                && is_static_expression(&cond.cons)
+                && is_static_expression(&cond.alt)
+        }
+        Expr::Array(array) => array.elems.iter().all(|elem| match elem {
+            Some(ExprOrSpread { spread: None, expr }) => is_static_expression(expr),
+            Some(_) => false,
+            None => true,
+        }),
+        Expr::Object(object) => object.props.iter().all(|prop| match prop {
+            PropOrSpread::Prop(prop) => match prop.deref() {
+                Prop::KeyValue(KeyValueProp { key, value }) => {
+                    !matches!(key, PropName::Computed(_)) && is_static_expression(value)
+                }
+                _ => false,
+            },
+            PropOrSpread::Spread(_) => false,
+        }),
+        _ => false,
+    }
+}
+
+pub fn is_static_prop(prop: &PropOrSpread) -> bool {
+    match prop {
+        PropOrSpread::Prop(prop) => match prop.deref() {
+            Prop::KeyValue(key_value) => is_static_expression(&key_value.value),
+            _ => false,
+        },
+        PropOrSpread::Spread(_) => false,
+    }
+}
+
+fn is_jsx_expression(expr: &Expr) -> bool {
+    match expr {
+        Expr::Paren(paren_expr) => is_jsx_expression(&paren_expr.expr),
+        Expr::JSXElement(_) | Expr::JSXFragment(_) => true,
+        _ => false,
+    }
+}
+
+struct JsxReturnFinder {
+    found: bool,
+}
+
+impl Visit for JsxReturnFinder {
+    fn visit_return_stmt(&mut self, stmt: &ReturnStmt) {
+        if stmt.arg.as_deref().is_some_and(is_jsx_expression) {
+            self.found = true;
+        }
+    }
+
+    fn visit_function(&mut self, _: &Function) {}
+
+    fn visit_arrow_expr(&mut self, _: &ArrowExpr) {}
+
+    fn visit_class(&mut self, _: &Class) {}
+}
+
+fn block_returns_jsx(block: &BlockStmt) -> bool {
+    let mut finder = JsxReturnFinder { found: false };
+
+    block.visit_with(&mut finder);
+
+    finder.found
+}
+
+pub fn returns_jsx(body: &BlockStmtOrExpr) -> bool {
+    match body {
+        BlockStmtOrExpr::Expr(expr) => is_jsx_expression(expr),
+        BlockStmtOrExpr::BlockStmt(block) => block_returns_jsx(block),
+    }
+}
+
+// Parameters and body of a function expression returning JSX
+pub fn get_jsx_callback(expr: &Expr) -> Option<(Vec<Pat>, BlockStmtOrExpr)> {
+    let (params, body) = match expr {
+        Expr::Paren(paren) => return get_jsx_callback(&paren.expr),
+        Expr::Arrow(arrow_expr) => (arrow_expr.params.clone(), *arrow_expr.body.clone()),
+        Expr::Fn(fn_expr) => (
+            fn_expr
+                .function
+                .params
+                .iter()
+                .map(|param| param.pat.clone())
+                .collect(),
+            BlockStmtOrExpr::BlockStmt(fn_expr.function.body.clone()?),
+        ),
+        _ => return None,
+    };
+
+    returns_jsx(&body).then_some((params, body))
+}
+
+pub fn function_returns_jsx(function: &Function) -> bool {
+    function.body.as_ref().is_some_and(block_returns_jsx)
+}
+
+// Components are named like the elements using them, which JSX only resolves
+// to variables when they are capitalized
+pub fn is_component_name(name: &Atom) -> bool {
+    name.starts_with(|c: char| c.is_ascii_uppercase())
+}
+
+// The key attribute only identifies the element in its list, so it is
+// removed from the element and returned to the caller.
+fn take_jsx_element_key(expr: &mut Expr) -> Option<Expr> {
+    match expr {
+        Expr::Paren(paren_expr) => take_jsx_element_key(&mut paren_expr.expr),
+        Expr::JSXElement(element) => {
+            let position = element.opening.attrs.iter().position(|attr| {
+                matches!(attr, JSXAttrOrSpread::JSXAttr(JSXAttr {
+                    name: JSXAttrName::Ident(name),
+                    ..
+                }) if name.sym == "key")
+            })?;
+
+            match element.opening.attrs.remove(position) {
+                JSXAttrOrSpread::JSXAttr(JSXAttr {
+                    value: Some(JSXAttrValue::Lit(lit)),
+                    ..
+                }) => Some(Expr::Lit(lit)),
+                JSXAttrOrSpread::JSXAttr(JSXAttr {
+                    value: Some(JSXAttrValue::JSXExprContainer(container)),
+                    ..
+                }) => Some(jsx_expr_to_expr(&container.expr)),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+struct JsxKeyRemover {
+    keys: Vec<Expr>,
+}
+
+impl VisitMut for JsxKeyRemover {
+    fn visit_mut_return_stmt(&mut self, stmt: &mut ReturnStmt) {
+        if let Some(key) = stmt.arg.as_deref_mut().and_then(take_jsx_element_key) {
+            self.keys.push(key);
+        }
+    }
+
+    fn visit_mut_function(&mut self, _: &mut Function) {}
+
+    fn visit_mut_arrow_expr(&mut self, _: &mut ArrowExpr) {}
+
+    fn visit_mut_class(&mut self, _: &mut Class) {}
+}
+
+pub fn take_jsx_keys(body: &mut BlockStmtOrExpr) -> Vec<Expr> {
+    match body {
+        BlockStmtOrExpr::Expr(expr) => take_jsx_element_key(expr).into_iter().collect(),
+        BlockStmtOrExpr::BlockStmt(block) => {
+            let mut remover = JsxKeyRemover { keys: vec![] };
+
+            block.visit_mut_with(&mut remover);
+
+            remover.keys
+        }
+    }
+}
+
+struct JsxFinder {
+    found: bool,
+}
+
+impl Visit for JsxFinder {
+    fn visit_jsx_element(&mut self, _: &JSXElement) {
+        self.found = true;
+    }
+
+    fn visit_jsx_fragment(&mut self, _: &JSXFragment) {
+        self.found = true;
+    }
+
+    fn visit_function(&mut self, _: &Function) {}
+
+    fn visit_arrow_expr(&mut self, _: &ArrowExpr) {}
+
+    fn visit_class(&mut self, _: &Class) {}
+}
+
+pub fn stmt_contains_jsx(stmt: &Stmt) -> bool {
+    let mut finder = JsxFinder { found: false };
+
+    stmt.visit_with(&mut finder);
+
+    finder.found
+}
+
+pub const JSDOC_COMPONENT_TAG: &str = "@component";
+
+// Block comments are matched line by line, without the leading stars of
+// JSDoc comments, which can also use the tag instead of the marker
+pub fn comment_has_marker(comment: &Comment, marker: &str, jsdoc_tag: Option<&str>) -> bool {
+    let is_jsdoc = comment.kind == CommentKind::Block && comment.text.starts_with('*');
+
+    comment
+        .text
+        .lines()
+        .map(|line| line.trim().trim_start_matches('*').trim())
+        .any(|line| {
+            line == marker
+                || (is_jsdoc
+                    && jsdoc_tag.is_some_and(|tag| line.split_whitespace().next() == Some(tag)))
+        })
+}
+
+pub fn is_palta_component_call(call: &CallExpr) -> bool {
+    match &call.callee {
+        Callee::Expr(callee) => is_runtime_component(callee),
+        _ => false,
+    }
+}
+
+// Replaces `Palta.component(() => ...)` by the wrapped arrow function, which
+// is then compiled as any other component declaration
+pub fn unwrap_component_call(expr: &mut Box<Expr>) -> Option<ComponentResult<()>> {
+    let call = expr
+        .as_call()
+        .filter(|call| is_palta_component_call(call))?;
+
+    match call.args.as_slice() {
+        [ExprOrSpread {
+            spread: None,
+            expr: arrow,
+        }] if arrow.is_arrow() => {
+            *expr = arrow.clone();
+
+            Some(Ok(()))
+        }
+        _ => Some(Err(ComponentError::ComponentNotArrowFunction(call.span))),
+    }
+}
+
+pub fn unwrap_component_declaration(var_decl: &mut VarDecl) -> Option<ComponentResult<()>> {
+    unwrap_component_call(var_decl.decls.first_mut()?.init.as_mut()?)
+}
+
+// Names under which the top-level bindings of a module are exported, the
+// first one being kept for bindings exported several times
+pub fn exported_names(items: &[ModuleItem]) -> HashMap<Id, Atom> {
+    let mut names = HashMap::new();
+
+    for item in items {
+        let ModuleItem::ModuleDecl(decl) = item else {
+            continue;
+        };
+
+        match decl {
+            ModuleDecl::ExportDecl(export) => {
+                for ident in decl_idents(&export.decl) {
+                    names.entry(ident.to_id()).or_insert(ident.sym);
+                }
+            }
+            ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+                decl:
+                    DefaultDecl::Fn(FnExpr {
+                        ident: Some(ident), ..
+                    }),
+                ..
+            }) => {
+                names.entry(ident.to_id()).or_insert("default".into());
+            }
+            ModuleDecl::ExportDefaultExpr(export) => {
+                if let Expr::Ident(ident) = export.expr.deref() {
+                    names.entry(ident.to_id()).or_insert("default".into());
+                }
+            }
+            ModuleDecl::ExportNamed(export) if export.src.is_none() => {
+                for specifier in export.specifiers.iter() {
+                    let ExportSpecifier::Named(ExportNamedSpecifier {
+                        orig: ModuleExportName::Ident(orig),
+                        exported,
+                        ..
+                    }) = specifier
+                    else {
+                        continue;
+                    };
+
+                    let exported = match exported {
+                        Some(ModuleExportName::Ident(ident)) => ident.sym.clone(),
+                        Some(ModuleExportName::Str(str)) => str.value.clone(),
+                        None => orig.sym.clone(),
+                    };
+
+                    names.entry(orig.to_id()).or_insert(exported);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    names
+}
+
+fn decl_idents(decl: &Decl) -> Vec<Ident> {
+    match decl {
+        Decl::Fn(fn_decl) => vec![fn_decl.ident.clone()],
+        Decl::Var(var_decl) => var_decl
+            .decls
+            .iter()
+            .filter_map(|decl| decl.name.as_ident())
+            .map(|binding| binding.id.clone())
+            .collect(),
+        _ => vec![],
+    }
+}
+
+// Bindings declared by a top-level item of a module
+pub fn module_item_idents(item: &ModuleItem) -> Vec<Ident> {
+    match item {
+        ModuleItem::Stmt(Stmt::Decl(decl)) => decl_idents(decl),
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export)) => decl_idents(&export.decl),
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+            decl: DefaultDecl::Fn(FnExpr {
+                ident: Some(ident), ..
+            }),
+            ..
+        })) => vec![ident.clone()],
+        _ => vec![],
+    }
+}
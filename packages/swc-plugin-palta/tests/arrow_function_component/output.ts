@@ -15,7 +15,7 @@ const Component = (): Palta.ComponentDefinition<{
   let __$root = __$element$0;
 
   return {
-    childrenElement: null,
+    slots: {},
     initialize: ({ name }: { name: string }) => {
       __$props = { name };
       __$element$1.updateChild(1, () => {
@@ -0,0 +1,18 @@
+import Palta from "palta";
+
+// @Palta.component
+const Card = ({ icon, footer }: { icon: Palta.Node; footer: Palta.Node }) => {
+  return (
+    <div>
+      {icon}
+      {footer}
+    </div>
+  );
+};
+
+// @Palta.component
+const Component = ({ name }: { name: string }) => {
+  return <Card icon=<span>*</span> footer=<>Hello, {name}!</> />;
+};
+
+export default Component;
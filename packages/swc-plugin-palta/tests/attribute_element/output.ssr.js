@@ -0,0 +1,15 @@
+import Palta from "palta";
+
+const Card = ({ icon, footer }) => {
+  return Palta
+    .html`<div><!--[-->${icon}<!--]--><!--[-->${footer}<!--]--></div>`;
+};
+
+const Component = ({ name }) => {
+  return Palta.renderComponent(Card, {
+    icon: Palta.html`<span>*</span>`,
+    footer: Palta.html`Hello, <!--[-->${name}<!--]-->!`,
+  });
+};
+
+export default Component;
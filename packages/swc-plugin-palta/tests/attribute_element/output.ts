@@ -0,0 +1,94 @@
+import Palta from "palta";
+
+const Card = (): Palta.ComponentDefinition<{
+  icon: Palta.Node;
+  footer: Palta.Node;
+}> => {
+  const __$element$0 = Palta.createElement("div", ["", ""]);
+  let __$props: any = {};
+  const __$update = ({ icon, footer }: {
+    icon: Palta.Node;
+    footer: Palta.Node;
+  }) => {
+    __$props = {
+      icon,
+      footer,
+    };
+    __$element$0.updateChild(0, () => {
+      return icon;
+    });
+    __$element$0.updateChild(1, () => {
+      return footer;
+    });
+  };
+  let __$root = __$element$0;
+
+  return {
+    slots: {},
+    initialize: ({ icon, footer }: {
+      icon: Palta.Node;
+      footer: Palta.Node;
+    }) => {
+      __$props = {
+        icon,
+        footer,
+      };
+      __$element$0.updateChild(0, () => {
+        return icon;
+      });
+      __$element$0.updateChild(1, () => {
+        return footer;
+      });
+      __$element$0.initialize({});
+    },
+    update: __$update,
+    getRoot: () => __$root,
+  };
+};
+
+const Component = (): Palta.ComponentDefinition<{
+  name: string;
+}> => {
+  const __$element$2 = Palta.createComponent(Card, []);
+  const __$element$1 = Palta.createFragment(["Hello, ", "", "!"]);
+  const __$element$0 = Palta.createElement("span", ["*"]);
+  let __$props: any = {};
+  const __$update = ({ name }: {
+    name: string;
+  }) => {
+    __$props = {
+      name,
+    };
+    __$element$1.updateChild(1, () => {
+      return name;
+    });
+    __$element$2.updateProps({
+      icon: __$element$0,
+      footer: __$element$1,
+    });
+  };
+  let __$root = __$element$2;
+
+  return {
+    slots: {},
+    initialize: ({ name }: {
+      name: string;
+    }) => {
+      __$props = {
+        name,
+      };
+      __$element$0.initialize({});
+      __$element$1.updateChild(1, () => {
+        return name;
+      });
+      __$element$2.initialize({
+        icon: __$element$0,
+        footer: __$element$1,
+      });
+    },
+    update: __$update,
+    getRoot: () => __$root,
+  };
+};
+
+export default Component;
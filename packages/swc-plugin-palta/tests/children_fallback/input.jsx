@@ -0,0 +1,30 @@
+import Palta, { Children } from "palta";
+
+// @Palta.component
+const List = ({ label }) => {
+  return (
+    <ul title={label}>
+      <Children>
+        <li>No items in {label}</li>
+      </Children>
+    </ul>
+  );
+};
+
+// @Palta.component
+const Panel = ({ open }) => {
+  return (
+    <section>
+      <Children name="title">
+        <h2>Untitled</h2>
+      </Children>
+      {open && (
+        <div>
+          <Children>Nothing to show</Children>
+        </div>
+      )}
+    </section>
+  );
+};
+
+export default Panel;
@@ -0,0 +1,30 @@
+import Palta, { Children } from "palta";
+
+// @Palta.component
+const List = ({ label }: { label: string }) => {
+  return (
+    <ul title={label}>
+      <Children>
+        <li>No items in {label}</li>
+      </Children>
+    </ul>
+  );
+};
+
+// @Palta.component
+const Panel = ({ open }: { open: boolean }) => {
+  return (
+    <section>
+      <Children name="title">
+        <h2>Untitled</h2>
+      </Children>
+      {open && (
+        <div>
+          <Children>Nothing to show</Children>
+        </div>
+      )}
+    </section>
+  );
+};
+
+export default Panel;
@@ -0,0 +1,120 @@
+import Palta from "palta";
+
+const List = () => {
+  const __$element$3 = Palta.createElement("li", ["No items in ", ""]);
+  const __$element$2 = Palta.createFragment([__$element$3]);
+  const __$element$1 = Palta.createChildren(() => __$element$2);
+  const __$element$0 = Palta.createElement("ul", [__$element$1]);
+  let __$props = {};
+  const __$update = ({ label }) => {
+    __$props = {
+      label,
+    };
+    __$element$3.updateChild(1, () => {
+      return label;
+    });
+    __$element$0.updateProps({
+      title: label,
+    });
+  };
+  let __$root = __$element$0;
+
+  return {
+    slots: {
+      children: __$element$1,
+    },
+    initialize: ({ label }) => {
+      __$props = {
+        label,
+      };
+      __$element$3.updateChild(1, () => {
+        return label;
+      });
+      __$element$3.initialize({});
+      __$element$0.initialize({
+        title: label,
+      });
+    },
+    update: __$update,
+    getRoot: () => __$root,
+  };
+};
+
+const Panel = () => {
+  let __$element$8;
+  let __$element$7;
+  const __$element$6 = Palta.createChildren(() => __$element$7);
+  let __$element$5;
+  const __$element$4 = Palta.createBranch([
+    () => __$element$5,
+    () => __$element$8,
+  ]);
+  const __$element$3 = Palta.createElement("h2", ["Untitled"]);
+  const __$element$2 = Palta.createFragment([__$element$3]);
+  const __$element$1 = Palta.createChildren(() => __$element$2);
+  const __$element$0 = Palta.createElement("section", [
+    __$element$1,
+    __$element$4,
+  ]);
+  let __$props = {};
+  const __$update = ({ open }) => {
+    __$props = {
+      open,
+    };
+    if (open) {
+      __$element$4.updateBranch(0, () => {
+        __$element$7 = Palta.createFragment(["Nothing to show"]);
+        __$element$5 = Palta.createElement("div", [__$element$6]);
+        __$element$5.initialize({});
+      }, () => {});
+    } else {
+      __$element$4.updateBranch(1, () => {
+        __$element$8 = Palta.createFragment([""]);
+        __$element$8.updateChild(0, () => {
+          return open;
+        });
+      }, () => {
+        __$element$8.updateChild(0, () => {
+          return open;
+        });
+      });
+    }
+  };
+  let __$root = __$element$0;
+
+  return {
+    slots: {
+      title: __$element$1,
+      children: __$element$6,
+    },
+    initialize: ({ open }) => {
+      __$props = {
+        open,
+      };
+      __$element$3.initialize({});
+      if (open) {
+        __$element$4.updateBranch(0, () => {
+          __$element$7 = Palta.createFragment(["Nothing to show"]);
+          __$element$5 = Palta.createElement("div", [__$element$6]);
+          __$element$5.initialize({});
+        }, () => {});
+      } else {
+        __$element$4.updateBranch(1, () => {
+          __$element$8 = Palta.createFragment([""]);
+          __$element$8.updateChild(0, () => {
+            return open;
+          });
+        }, () => {
+          __$element$8.updateChild(0, () => {
+            return open;
+          });
+        });
+      }
+      __$element$0.initialize({});
+    },
+    update: __$update,
+    getRoot: () => __$root,
+  };
+};
+
+export default Panel;
@@ -0,0 +1,23 @@
+import Palta from "palta";
+
+const List = ({ label }, __$children) => {
+  return Palta.html`<ul${Palta.attribute("title", label)}><!--[-->${
+    Palta.fallback(
+      __$children,
+      Palta.html`<li>No items in <!--[-->${label}<!--]--></li>`
+    )
+  }<!--]--></ul>`;
+};
+
+const Panel = ({ open }, __$children, __$slots) => {
+  return Palta.html`<section><!--[-->${
+    Palta.fallback(__$slots.title, Palta.html`<h2>Untitled</h2>`)
+  }<!--]--><!--[-->${
+    open
+    && Palta.html`<div><!--[-->${
+      Palta.fallback(__$children, Palta.html`Nothing to show`)
+    }<!--]--></div>`
+  }<!--]--></section>`;
+};
+
+export default Panel;
@@ -0,0 +1,10 @@
+import * as P from "palta";
+
+export const Title = P.component(({ text }) => <h1>{text}</h1>);
+
+export default P.component(({ name }) => (
+  <div>
+    <Title text="Hello" />
+    <p>{name}</p>
+  </div>
+));
@@ -0,0 +1,12 @@
+import * as P from "palta";
+
+export const Title = P.component(({ text }: { text: string }) => (
+  <h1>{text}</h1>
+));
+
+export default P.component(({ name }: { name: string }) => (
+  <div>
+    <Title text="Hello" />
+    <p>{name}</p>
+  </div>
+));
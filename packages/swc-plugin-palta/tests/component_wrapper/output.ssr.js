@@ -0,0 +1,12 @@
+import Palta from "palta";
+
+import * as P from "palta";
+
+export const Title = ({ text }) => Palta.html`<h1><!--[-->${text}<!--]--></h1>`;
+
+export default (({ name }) =>
+  Palta.html`<div><!--[-->${
+    Palta.renderComponent(Title, {
+      text: "Hello",
+    })
+  }<!--]--><p><!--[-->${name}<!--]--></p></div>`);
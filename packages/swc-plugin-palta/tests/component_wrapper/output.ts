@@ -0,0 +1,79 @@
+import Palta from "palta";
+
+import * as P from "palta";
+
+export const Title = (): Palta.ComponentDefinition<{
+  text: string;
+}> => {
+  const __$element$0 = Palta.createElement("h1", [""]);
+  let __$props: any = {};
+  const __$update = ({ text }: {
+    text: string;
+  }) => {
+    __$props = {
+      text,
+    };
+    __$element$0.updateChild(0, () => {
+      return text;
+    });
+  };
+  let __$root = __$element$0;
+
+  return {
+    slots: {},
+    initialize: ({ text }: {
+      text: string;
+    }) => {
+      __$props = {
+        text,
+      };
+      __$element$0.updateChild(0, () => {
+        return text;
+      });
+      __$element$0.initialize({});
+    },
+    update: __$update,
+    getRoot: () => __$root,
+  };
+};
+
+export default ((): Palta.ComponentDefinition<{
+  name: string;
+}> => {
+  const __$element$2 = Palta.createElement("p", [""]);
+  const __$element$1 = Palta.createComponent(Title, []);
+  const __$element$0 = Palta.createElement("div", [__$element$1, __$element$2]);
+  let __$props: any = {};
+  const __$update = ({ name }: {
+    name: string;
+  }) => {
+    __$props = {
+      name,
+    };
+    __$element$2.updateChild(0, () => {
+      return name;
+    });
+  };
+  let __$root = __$element$0;
+
+  return {
+    slots: {},
+    initialize: ({ name }: {
+      name: string;
+    }) => {
+      __$props = {
+        name,
+      };
+      __$element$1.initialize({
+        text: "Hello",
+      });
+      __$element$2.updateChild(0, () => {
+        return name;
+      });
+      __$element$2.initialize({});
+      __$element$0.initialize({});
+    },
+    update: __$update,
+    getRoot: () => __$root,
+  };
+});
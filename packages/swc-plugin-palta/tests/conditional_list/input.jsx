@@ -0,0 +1,20 @@
+// @Palta.component
+const TodoList = ({ todos, filter }) => {
+  return (
+    <ul>
+      {todos.filter(filter).map(function (todo) {
+        const label = todo.title.trim();
+
+        return (
+          <li key={todo.id}>
+            {todo.done ? <s>{label}</s> : <span>{label}</span>}
+            {todo.tags.length && <em>{todo.tags.length} tags</em>}
+          </li>
+        );
+      })}
+      {todos.length === 0 && <li>Nothing to do</li>}
+    </ul>
+  );
+};
+
+export default TodoList;
@@ -0,0 +1,24 @@
+type Todo = { id: number; title: string; done: boolean; tags: string[] };
+
+type Props = { todos: Todo[]; filter: (todo: Todo) => boolean };
+
+// @Palta.component
+const TodoList = ({ todos, filter }: Props) => {
+  return (
+    <ul>
+      {todos.filter(filter).map(function (todo) {
+        const label = todo.title.trim();
+
+        return (
+          <li key={todo.id}>
+            {todo.done ? <s>{label}</s> : <span>{label}</span>}
+            {todo.tags.length && <em>{todo.tags.length} tags</em>}
+          </li>
+        );
+      })}
+      {todos.length === 0 && <li>Nothing to do</li>}
+    </ul>
+  );
+};
+
+export default TodoList;
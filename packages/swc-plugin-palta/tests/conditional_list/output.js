@@ -0,0 +1,188 @@
+import Palta from "palta";
+
+const TodoList = () => {
+  const __$list$0 = Palta.createList(() => {
+    let __$element$6;
+    let __$element$5;
+    const __$element$4 = Palta.createBranch([
+      () => __$element$5,
+      () => __$element$6,
+    ]);
+    let __$element$3;
+    let __$element$2;
+    const __$element$1 = Palta.createBranch([
+      () => __$element$2,
+      () => __$element$3,
+    ]);
+    const __$element$0 = Palta.createElement("li", [
+      __$element$1,
+      __$element$4,
+    ]);
+    let __$props = {};
+    const __$update = ([todo]) => {
+      __$props = [todo];
+      const label = todo.title.trim();
+      if (todo.done) {
+        __$element$1.updateBranch(0, () => {
+          __$element$2 = Palta.createElement("s", [""]);
+          __$element$2.updateChild(0, () => {
+            return label;
+          });
+          __$element$2.initialize({});
+        }, () => {
+          __$element$2.updateChild(0, () => {
+            return label;
+          });
+        });
+      } else {
+        __$element$1.updateBranch(1, () => {
+          __$element$3 = Palta.createElement("span", [""]);
+          __$element$3.updateChild(0, () => {
+            return label;
+          });
+          __$element$3.initialize({});
+        }, () => {
+          __$element$3.updateChild(0, () => {
+            return label;
+          });
+        });
+      }
+      if (todo.tags.length) {
+        __$element$4.updateBranch(0, () => {
+          __$element$5 = Palta.createElement("em", ["", " tags"]);
+          __$element$5.updateChild(0, () => {
+            return todo.tags.length;
+          });
+          __$element$5.initialize({});
+        }, () => {
+          __$element$5.updateChild(0, () => {
+            return todo.tags.length;
+          });
+        });
+      } else {
+        __$element$4.updateBranch(1, () => {
+          __$element$6 = Palta.createFragment([""]);
+          __$element$6.updateChild(0, () => {
+            return todo.tags.length;
+          });
+        }, () => {
+          __$element$6.updateChild(0, () => {
+            return todo.tags.length;
+          });
+        });
+      }
+    };
+    let __$root = __$element$0;
+    return {
+      slots: {},
+      initialize: ([todo]) => {
+        __$props = [todo];
+        const label = todo.title.trim();
+        if (todo.done) {
+          __$element$1.updateBranch(0, () => {
+            __$element$2 = Palta.createElement("s", [""]);
+            __$element$2.updateChild(0, () => {
+              return label;
+            });
+            __$element$2.initialize({});
+          }, () => {
+            __$element$2.updateChild(0, () => {
+              return label;
+            });
+          });
+        } else {
+          __$element$1.updateBranch(1, () => {
+            __$element$3 = Palta.createElement("span", [""]);
+            __$element$3.updateChild(0, () => {
+              return label;
+            });
+            __$element$3.initialize({});
+          }, () => {
+            __$element$3.updateChild(0, () => {
+              return label;
+            });
+          });
+        }
+        if (todo.tags.length) {
+          __$element$4.updateBranch(0, () => {
+            __$element$5 = Palta.createElement("em", ["", " tags"]);
+            __$element$5.updateChild(0, () => {
+              return todo.tags.length;
+            });
+            __$element$5.initialize({});
+          }, () => {
+            __$element$5.updateChild(0, () => {
+              return todo.tags.length;
+            });
+          });
+        } else {
+          __$element$4.updateBranch(1, () => {
+            __$element$6 = Palta.createFragment([""]);
+            __$element$6.updateChild(0, () => {
+              return todo.tags.length;
+            });
+          }, () => {
+            __$element$6.updateChild(0, () => {
+              return todo.tags.length;
+            });
+          });
+        }
+        __$element$0.initialize({});
+      },
+      update: __$update,
+      getRoot: () => __$root,
+    };
+  });
+  let __$element$2;
+  const __$element$1 = Palta.createBranch([() => __$element$2, () => null]);
+  const __$element$0 = Palta.createElement("ul", ["", __$element$1]);
+  let __$props = {};
+  const __$update = ({ todos, filter }) => {
+    __$props = {
+      todos,
+      filter,
+    };
+    __$element$0.updateChild(0, () => {
+      return __$list$0.collect(() =>
+        todos.filter(filter).map((todo) => __$list$0.render([todo], todo.id))
+      );
+    });
+    if (todos.length === 0) {
+      __$element$1.updateBranch(0, () => {
+        __$element$2 = Palta.createElement("li", ["Nothing to do"]);
+        __$element$2.initialize({});
+      }, () => {});
+    } else {
+      __$element$1.updateBranch(1);
+    }
+  };
+  let __$root = __$element$0;
+
+  return {
+    slots: {},
+    initialize: ({ todos, filter }) => {
+      __$props = {
+        todos,
+        filter,
+      };
+      __$element$0.updateChild(0, () => {
+        return __$list$0.collect(() =>
+          todos.filter(filter).map((todo) => __$list$0.render([todo], todo.id))
+        );
+      });
+      if (todos.length === 0) {
+        __$element$1.updateBranch(0, () => {
+          __$element$2 = Palta.createElement("li", ["Nothing to do"]);
+          __$element$2.initialize({});
+        }, () => {});
+      } else {
+        __$element$1.updateBranch(1);
+      }
+      __$element$0.initialize({});
+    },
+    update: __$update,
+    getRoot: () => __$root,
+  };
+};
+
+export default TodoList;
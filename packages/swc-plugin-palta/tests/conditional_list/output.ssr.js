@@ -0,0 +1,21 @@
+import Palta from "palta";
+
+const TodoList = ({ todos, filter }) => {
+  return Palta.html`<ul><!--[-->${
+    todos.filter(filter).map((todo) => {
+      const label = todo.title.trim();
+      return Palta.html`<li><!--[-->${
+        todo.done
+          ? Palta.html`<s><!--[-->${label}<!--]--></s>`
+          : Palta.html`<span><!--[-->${label}<!--]--></span>`
+      }<!--]--><!--[-->${
+        todo.tags.length
+        && Palta.html`<em><!--[-->${todo.tags.length}<!--]--> tags</em>`
+      }<!--]--></li>`;
+    })
+  }<!--]--><!--[-->${
+    todos.length === 0 && Palta.html`<li>Nothing to do</li>`
+  }<!--]--></ul>`;
+};
+
+export default TodoList;
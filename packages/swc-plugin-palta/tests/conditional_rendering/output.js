@@ -1,27 +1,45 @@
 import Palta from "palta";
 
 const Component = () => {
-  const __$element$2 = Palta.createElement("span", ["closed"]);
-  const __$element$1 = Palta.createElement("span", ["opened"]);
-  const __$element$0 = Palta.createElement("div", [""]);
+  let __$element$3;
+  let __$element$2;
+  const __$element$1 = Palta.createBranch([
+    () => __$element$2,
+    () => __$element$3,
+  ]);
+  const __$element$0 = Palta.createElement("div", [__$element$1]);
   let __$props = {};
   const __$update = ({ open }) => {
     __$props = { open };
-    __$element$0.updateChild(0, () => {
-      return open ? __$element$1 : __$element$2;
-    });
+    if (open) {
+      __$element$1.updateBranch(0, () => {
+        __$element$2 = Palta.createElement("span", ["opened"]);
+        __$element$2.initialize({});
+      }, () => {});
+    } else {
+      __$element$1.updateBranch(1, () => {
+        __$element$3 = Palta.createElement("span", ["closed"]);
+        __$element$3.initialize({});
+      }, () => {});
+    }
   };
   let __$root = __$element$0;
 
   return {
-    childrenElement: null,
+    slots: {},
     initialize: ({ open }) => {
       __$props = { open };
-      __$element$1.initialize({});
-      __$element$2.initialize({});
-      __$element$0.updateChild(0, () => {
-        return open ? __$element$1 : __$element$2;
-      });
+      if (open) {
+        __$element$1.updateBranch(0, () => {
+          __$element$2 = Palta.createElement("span", ["opened"]);
+          __$element$2.initialize({});
+        }, () => {});
+      } else {
+        __$element$1.updateBranch(1, () => {
+          __$element$3 = Palta.createElement("span", ["closed"]);
+          __$element$3.initialize({});
+        }, () => {});
+      }
       __$element$0.initialize({});
     },
     update: __$update,
@@ -0,0 +1,9 @@
+import Palta from "palta";
+
+const Component = ({ open }) => {
+  return Palta.html`<div><!--[-->${
+    open ? Palta.html`<span>opened</span>` : Palta.html`<span>closed</span>`
+  }<!--]--></div>`;
+};
+
+export default Component;
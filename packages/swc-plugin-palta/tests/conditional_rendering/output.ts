@@ -3,27 +3,45 @@ import Palta from "palta";
 const Component = (): Palta.ComponentDefinition<{
   open: boolean;
 }> => {
-  const __$element$2 = Palta.createElement("span", ["closed"]);
-  const __$element$1 = Palta.createElement("span", ["opened"]);
-  const __$element$0 = Palta.createElement("div", [""]);
+  let __$element$3: any;
+  let __$element$2: any;
+  const __$element$1 = Palta.createBranch([
+    () => __$element$2,
+    () => __$element$3,
+  ]);
+  const __$element$0 = Palta.createElement("div", [__$element$1]);
   let __$props: any = {};
   const __$update = ({ open }: { open: boolean }) => {
     __$props = { open };
-    __$element$0.updateChild(0, () => {
-      return open ? __$element$1 : __$element$2;
-    });
+    if (open) {
+      __$element$1.updateBranch(0, () => {
+        __$element$2 = Palta.createElement("span", ["opened"]);
+        __$element$2.initialize({});
+      }, () => {});
+    } else {
+      __$element$1.updateBranch(1, () => {
+        __$element$3 = Palta.createElement("span", ["closed"]);
+        __$element$3.initialize({});
+      }, () => {});
+    }
   };
   let __$root = __$element$0;
 
   return {
-    childrenElement: null,
+    slots: {},
     initialize: ({ open }: { open: boolean }) => {
       __$props = { open };
-      __$element$1.initialize({});
-      __$element$2.initialize({});
-      __$element$0.updateChild(0, () => {
-        return open ? __$element$1 : __$element$2;
-      });
+      if (open) {
+        __$element$1.updateBranch(0, () => {
+          __$element$2 = Palta.createElement("span", ["opened"]);
+          __$element$2.initialize({});
+        }, () => {});
+      } else {
+        __$element$1.updateBranch(1, () => {
+          __$element$3 = Palta.createElement("span", ["closed"]);
+          __$element$3.initialize({});
+        }, () => {});
+      }
       __$element$0.initialize({});
     },
     update: __$update,
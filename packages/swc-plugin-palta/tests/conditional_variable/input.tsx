@@ -0,0 +1,17 @@
+import { $state } from "palta";
+
+// @Palta.component
+const Toggle = ({ label }: { label: string }) => {
+  const [open, setOpen] = $state(false);
+  let content;
+
+  if (open) {
+    content = <section>{label}</section>;
+  } else {
+    content = <button onClick={() => setOpen(true)}>Open</button>;
+  }
+
+  return content;
+};
+
+export default Toggle;
@@ -0,0 +1,14 @@
+import Palta from "palta";
+
+const Toggle = ({ label }) => {
+  const open = false, setOpen = () => {};
+  let content;
+  if (open) {
+    content = Palta.html`<section><!--[-->${label}<!--]--></section>`;
+  } else {
+    content = Palta.html`<button>Open</button>`;
+  }
+  return content;
+};
+
+export default Toggle;
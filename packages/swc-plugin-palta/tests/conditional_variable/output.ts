@@ -0,0 +1,136 @@
+import Palta from "palta";
+
+const Toggle = (): Palta.ComponentDefinition<{
+  label: string;
+}> => {
+  const __$element$3 = Palta.createFragment([""]);
+  let __$element$2: any;
+  let __$element$1: any;
+  const __$element$0 = Palta.createBranch([
+    () => __$element$1,
+    () => __$element$2,
+  ]);
+  let __$props: any = {};
+  let open = false;
+  const setOpen: Palta.StateUpdater<any> = (value) => {
+    open = typeof value === "function" ? value(open) : value;
+    Palta.componentUpdate(() => __$update$0(__$props));
+  };
+  const __$update = ({ label }: {
+    label: string;
+  }) => {
+    __$props = {
+      label,
+    };
+    let content;
+    if (open) {
+      __$element$0.updateBranch(0, () => {
+        __$element$1 = Palta.createElement("section", [""]);
+        __$element$1.updateChild(0, () => {
+          return label;
+        });
+        __$element$1.initialize({});
+      }, () => {
+        __$element$1.updateChild(0, () => {
+          return label;
+        });
+      });
+      content = __$element$1;
+    } else {
+      __$element$0.updateBranch(1, () => {
+        __$element$2 = Palta.createElement("button", ["Open"]);
+        __$element$2.initialize({
+          onClick: () => setOpen(true),
+        });
+      }, () => {
+        __$element$2.updateProps({
+          onClick: () => setOpen(true),
+        });
+      });
+      content = __$element$2;
+    }
+    __$element$3.updateChild(0, () => {
+      return content;
+    });
+  };
+  const __$update$0 = ({ label }: {
+    label: string;
+  }) => {
+    let content;
+    if (open) {
+      __$element$0.updateBranch(0, () => {
+        __$element$1 = Palta.createElement("section", [""]);
+        __$element$1.updateChild(0, () => {
+          return label;
+        });
+        __$element$1.initialize({});
+      }, () => {
+        __$element$1.updateChild(0, () => {
+          return label;
+        });
+      });
+      content = __$element$1;
+    } else {
+      __$element$0.updateBranch(1, () => {
+        __$element$2 = Palta.createElement("button", ["Open"]);
+        __$element$2.initialize({
+          onClick: () => setOpen(true),
+        });
+      }, () => {
+        __$element$2.updateProps({
+          onClick: () => setOpen(true),
+        });
+      });
+      content = __$element$2;
+    }
+    __$element$3.updateChild(0, () => {
+      return content;
+    });
+  };
+  let __$root = __$element$3;
+
+  return {
+    slots: {},
+    initialize: ({ label }: {
+      label: string;
+    }) => {
+      __$props = {
+        label,
+      };
+      let content;
+      if (open) {
+        __$element$0.updateBranch(0, () => {
+          __$element$1 = Palta.createElement("section", [""]);
+          __$element$1.updateChild(0, () => {
+            return label;
+          });
+          __$element$1.initialize({});
+        }, () => {
+          __$element$1.updateChild(0, () => {
+            return label;
+          });
+        });
+        content = __$element$1;
+      } else {
+        __$element$0.updateBranch(1, () => {
+          __$element$2 = Palta.createElement("button", ["Open"]);
+          __$element$2.initialize({
+            onClick: () => setOpen(true),
+          });
+        }, () => {
+          __$element$2.updateProps({
+            onClick: () => setOpen(true),
+          });
+        });
+        content = __$element$2;
+      }
+      __$element$3.updateChild(0, () => {
+        return content;
+      });
+    },
+    update: __$update,
+    getRoot: () => __$root,
+  };
+};
+
+export default Toggle;
@@ -0,0 +1,13 @@
+import { $state } from "@acme/palta";
+
+// @component
+const Counter = () => {
+  const [count, setCount] = $state(0);
+
+  return <button onClick={() => setCount(count + 1)}>{count}</button>;
+};
+
+// @Palta.component
+const NotAComponent = () => <p />;
+
+export default Counter;
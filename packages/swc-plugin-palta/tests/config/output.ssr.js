@@ -0,0 +1,12 @@
+import Palta from "@acme/palta";
+
+const Counter = () => {
+  const count = 0, setCount = () => {};
+  return Palta.html`<button><!--[-->${count}<!--]--></button>`;
+};
+
+// @Palta.component
+
+const NotAComponent = () => <p />;
+
+export default Counter;
@@ -0,0 +1,50 @@
+import Palta from "@acme/palta";
+
+const Counter = () => {
+  const __$element$0 = Palta.createElement("button", [""]);
+  let __$props = {};
+  let count = 0;
+  const setCount = (value) => {
+    count = typeof value === "function" ? value(count) : value;
+    Palta.componentUpdate(() => __$update$0(__$props));
+  };
+  const __$update = ({}) => {
+    __$props = {};
+    __$element$0.updateChild(0, () => {
+      return count;
+    });
+    __$element$0.updateProps({
+      onClick: () => setCount(count + 1),
+    });
+  };
+  const __$update$0 = ({}) => {
+    __$element$0.updateChild(0, () => {
+      return count;
+    });
+    __$element$0.updateProps({
+      onClick: () => setCount(count + 1),
+    });
+  };
+  let __$root = __$element$0;
+
+  return {
+    slots: {},
+    initialize: ({}) => {
+      __$props = {};
+      __$element$0.updateChild(0, () => {
+        return count;
+      });
+      __$element$0.initialize({
+        onClick: () => setCount(count + 1),
+      });
+    },
+    update: __$update,
+    getRoot: () => __$root,
+  };
+};
+
+// @Palta.component
+
+const NotAComponent = () => <p />;
+
+export default Counter;
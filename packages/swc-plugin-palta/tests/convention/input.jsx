@@ -0,0 +1,31 @@
+import { $state } from "palta";
+
+const Title = ({ text }) => <h1>{text}</h1>;
+
+export function Counter() {
+  const [count, setCount] = $state(0);
+
+  return <button onClick={() => setCount(count + 1)}>{count}</button>;
+}
+
+// @Palta.ignore
+const Legacy = () => <p>Legacy</p>;
+
+const formatLabel = (label) => <span>{label}</span>;
+
+const Settings = { theme: "dark" };
+
+const Layout = () => {
+  const render = () => <footer />;
+
+  return null;
+};
+
+export default function App() {
+  return (
+    <div>
+      <Title text="Hello" />
+      <Counter />
+    </div>
+  );
+}
@@ -0,0 +1,29 @@
+import Palta from "palta";
+
+const Title = ({ text }) => Palta.html`<h1><!--[-->${text}<!--]--></h1>`;
+
+export function Counter() {
+  const count = 0, setCount = () => {};
+  return Palta.html`<button><!--[-->${count}<!--]--></button>`;
+}
+
+const Legacy = () => <p>Legacy</p>;
+
+const formatLabel = (label) => <span>{label}</span>;
+
+const Settings = {
+  theme: "dark",
+};
+
+const Layout = () => {
+  const render = () => <footer />;
+  return null;
+};
+
+export default function App() {
+  return Palta.html`<div><!--[-->${
+    Palta.renderComponent(Title, {
+      text: "Hello",
+    })
+  }<!--]--><!--[-->${Palta.renderComponent(Counter)}<!--]--></div>`;
+}
@@ -0,0 +1,117 @@
+import Palta from "palta";
+
+const Title = (): Palta.ComponentDefinition<{
+  text: string;
+}> => {
+  const __$element$0 = Palta.createElement("h1", [""]);
+  let __$props: any = {};
+  const __$update = ({ text }: {
+    text: string;
+  }) => {
+    __$props = {
+      text,
+    };
+    __$element$0.updateChild(0, () => {
+      return text;
+    });
+  };
+  let __$root = __$element$0;
+
+  return {
+    slots: {},
+    initialize: ({ text }: {
+      text: string;
+    }) => {
+      __$props = {
+        text,
+      };
+      __$element$0.updateChild(0, () => {
+        return text;
+      });
+      __$element$0.initialize({});
+    },
+    update: __$update,
+    getRoot: () => __$root,
+  };
+};
+
+export function Counter() {
+  const __$element$0 = Palta.createElement("button", [""]);
+  let __$props = {};
+  let count = 0;
+  const setCount = (value) => {
+    count = typeof value === "function" ? value(count) : value;
+    Palta.componentUpdate(() => __$update$0(__$props));
+  };
+  const __$update = ({}) => {
+    __$props = {};
+    __$element$0.updateChild(0, () => {
+      return count;
+    });
+    __$element$0.updateProps({
+      onClick: () => setCount(count + 1),
+    });
+  };
+  const __$update$0 = ({}) => {
+    __$element$0.updateChild(0, () => {
+      return count;
+    });
+    __$element$0.updateProps({
+      onClick: () => setCount(count + 1),
+    });
+  };
+  let __$root = __$element$0;
+
+  return {
+    slots: {},
+    initialize: ({}) => {
+      __$props = {};
+      __$element$0.updateChild(0, () => {
+        return count;
+      });
+      __$element$0.initialize({
+        onClick: () => setCount(count + 1),
+      });
+    },
+    update: __$update,
+    getRoot: () => __$root,
+  };
+}
+
+const Legacy = () => <p>Legacy</p>;
+
+const formatLabel = (label: string) => <span>{label}</span>;
+
+const Settings = {
+  theme: "dark",
+};
+
+const Layout = () => {
+  const render = () => <footer />;
+  return null;
+};
+
+export default function App() {
+  const __$element$2 = Palta.createComponent(Counter, []);
+  const __$element$1 = Palta.createComponent(Title, []);
+  const __$element$0 = Palta.createElement("div", [__$element$1, __$element$2]);
+  let __$props = {};
+  const __$update = ({}) => {
+    __$props = {};
+  };
+  let __$root = __$element$0;
+
+  return {
+    slots: {},
+    initialize: ({}) => {
+      __$props = {};
+      __$element$1.initialize({
+        text: "Hello",
+      });
+      __$element$2.initialize({});
+      __$element$0.initialize({});
+    },
+    update: __$update,
+    getRoot: () => __$root,
+  };
+}
@@ -0,0 +1,23 @@
+import { $state } from "palta";
+
+const Counter = () => {
+  const [count, setCount] = $state(0);
+
+  return <button onClick={() => setCount(count + 1)}>{count}</button>;
+};
+
+const Badge = function ({ label }) {
+  return <span class="badge">{label}</span>;
+};
+
+const renderIcon = function (name) {
+  return <i>{name}</i>;
+};
+
+export const App = () => (
+  <div>
+    <Counter />
+    <Badge label="New" />
+    {renderIcon("star")}
+  </div>
+);
@@ -0,0 +1,114 @@
+import Palta from "palta";
+
+const Counter = () => {
+  const __$element$0 = Palta.createElement("button", [""]);
+  let __$props = {};
+  let count = 0;
+  const setCount = (value) => {
+    count = typeof value === "function" ? value(count) : value;
+    Palta.componentUpdate(() => __$update$0(__$props));
+  };
+  const __$update = ({}) => {
+    __$props = {};
+    __$element$0.updateChild(0, () => {
+      return count;
+    });
+    __$element$0.updateProps({
+      onClick: () => setCount(count + 1),
+    });
+  };
+  const __$update$0 = ({}) => {
+    __$element$0.updateChild(0, () => {
+      return count;
+    });
+    __$element$0.updateProps({
+      onClick: () => setCount(count + 1),
+    });
+  };
+  let __$root = __$element$0;
+
+  return {
+    slots: {},
+    initialize: ({}) => {
+      __$props = {};
+      __$element$0.updateChild(0, () => {
+        return count;
+      });
+      __$element$0.initialize({
+        onClick: () => setCount(count + 1),
+      });
+    },
+    update: __$update,
+    getRoot: () => __$root,
+  };
+};
+
+const Badge = function() {
+  const __$element$0 = Palta.createElement("span", [""]);
+  let __$props = {};
+  const __$update = ({ label }) => {
+    __$props = {
+      label,
+    };
+    __$element$0.updateChild(0, () => {
+      return label;
+    });
+  };
+  let __$root = __$element$0;
+
+  return {
+    slots: {},
+    initialize: ({ label }) => {
+      __$props = {
+        label,
+      };
+      __$element$0.updateChild(0, () => {
+        return label;
+      });
+      __$element$0.initialize({
+        class: "badge",
+      });
+    },
+    update: __$update,
+    getRoot: () => __$root,
+  };
+};
+
+const renderIcon = function(name) {
+  return <i>{name}</i>;
+};
+
+export const App = () => {
+  const __$element$2 = Palta.createComponent(Badge, []);
+  const __$element$1 = Palta.createComponent(Counter, []);
+  const __$element$0 = Palta.createElement("div", [
+    __$element$1,
+    __$element$2,
+    "",
+  ]);
+  let __$props = {};
+  const __$update = ({}) => {
+    __$props = {};
+    __$element$0.updateChild(2, () => {
+      return renderIcon("star");
+    });
+  };
+  let __$root = __$element$0;
+
+  return {
+    slots: {},
+    initialize: ({}) => {
+      __$props = {};
+      __$element$1.initialize({});
+      __$element$2.initialize({
+        label: "New",
+      });
+      __$element$0.updateChild(2, () => {
+        return renderIcon("star");
+      });
+      __$element$0.initialize({});
+    },
+    update: __$update,
+    getRoot: () => __$root,
+  };
+};
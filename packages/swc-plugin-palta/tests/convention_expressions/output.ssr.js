@@ -0,0 +1,21 @@
+import Palta from "palta";
+
+const Counter = () => {
+  const count = 0, setCount = () => {};
+  return Palta.html`<button><!--[-->${count}<!--]--></button>`;
+};
+
+const Badge = function({ label }) {
+  return Palta.html`<span class="badge"><!--[-->${label}<!--]--></span>`;
+};
+
+const renderIcon = function(name) {
+  return <i>{name}</i>;
+};
+
+export const App = () =>
+  Palta.html`<div><!--[-->${Palta.renderComponent(Counter)}<!--]--><!--[-->${
+    Palta.renderComponent(Badge, {
+      label: "New",
+    })
+  }<!--]--><!--[-->${renderIcon("star")}<!--]--></div>`;
@@ -0,0 +1,18 @@
+import { $state } from "palta";
+
+// @Palta.component
+const Counter = ({ title }: { title: string }) => {
+  const [count, setCount] = $state(0);
+
+  const info = { value: 0 };
+  info.value = count * 2;
+
+  return (
+    <div>
+      <h1>{title}</h1>
+      <span onClick={() => setCount(count + 1)}>{info.value}</span>
+    </div>
+  );
+};
+
+export default Counter;
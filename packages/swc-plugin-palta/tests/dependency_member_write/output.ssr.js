@@ -0,0 +1,13 @@
+import Palta from "palta";
+
+const Counter = ({ title }) => {
+  const count = 0, setCount = () => {};
+  const info = {
+    value: 0,
+  };
+  info.value = count * 2;
+  return Palta
+    .html`<div><h1><!--[-->${title}<!--]--></h1><span><!--[-->${info.value}<!--]--></span></div>`;
+};
+
+export default Counter;
@@ -0,0 +1,80 @@
+import Palta from "palta";
+
+const Counter = (): Palta.ComponentDefinition<{
+  title: string;
+}> => {
+  const __$element$2 = Palta.createElement("span", [""]);
+  const __$element$1 = Palta.createElement("h1", [""]);
+  const __$element$0 = Palta.createElement("div", [__$element$1, __$element$2]);
+  let __$props: any = {};
+  let count = 0;
+  const setCount: Palta.StateUpdater<any> = (value) => {
+    count = typeof value === "function" ? value(count) : value;
+    Palta.componentUpdate(() => __$update$0(__$props));
+  };
+  const __$update = ({ title }: {
+    title: string;
+  }) => {
+    __$props = {
+      title,
+    };
+    const info = {
+      value: 0,
+    };
+    info.value = count * 2;
+    __$element$1.updateChild(0, () => {
+      return title;
+    });
+    __$element$2.updateChild(0, () => {
+      return info.value;
+    });
+    __$element$2.updateProps({
+      onClick: () => setCount(count + 1),
+    });
+  };
+  const __$update$0 = ({ title }: {
+    title: string;
+  }) => {
+    const info = {
+      value: 0,
+    };
+    info.value = count * 2;
+    __$element$2.updateChild(0, () => {
+      return info.value;
+    });
+    __$element$2.updateProps({
+      onClick: () => setCount(count + 1),
+    });
+  };
+  let __$root = __$element$0;
+
+  return {
+    slots: {},
+    initialize: ({ title }: {
+      title: string;
+    }) => {
+      __$props = {
+        title,
+      };
+      const info = {
+        value: 0,
+      };
+      info.value = count * 2;
+      __$element$1.updateChild(0, () => {
+        return title;
+      });
+      __$element$1.initialize({});
+      __$element$2.updateChild(0, () => {
+        return info.value;
+      });
+      __$element$2.initialize({
+        onClick: () => setCount(count + 1),
+      });
+      __$element$0.initialize({});
+    },
+    update: __$update,
+    getRoot: () => __$root,
+  };
+};
+
+export default Counter;
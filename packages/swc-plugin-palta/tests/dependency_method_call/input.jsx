@@ -0,0 +1,18 @@
+import { $state } from "palta";
+
+// @Palta.component
+const Counter = ({ title }) => {
+  const [count, setCount] = $state(0);
+
+  const parts= [];
+  parts.push(count);
+
+  return (
+    <div>
+      <h1>{title}</h1>
+      <span onClick={() => setCount(count + 1)}>{parts.join(",")}</span>
+    </div>
+  );
+};
+
+export default Counter;
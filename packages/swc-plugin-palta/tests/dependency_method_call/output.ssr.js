@@ -0,0 +1,12 @@
+import Palta from "palta";
+
+const Counter = ({ title }) => {
+  const count = 0, setCount = () => {};
+  const parts = [];
+  parts.push(count);
+  return Palta.html`<div><h1><!--[-->${title}<!--]--></h1><span><!--[-->${
+    parts.join(",")
+  }<!--]--></span></div>`;
+};
+
+export default Counter;
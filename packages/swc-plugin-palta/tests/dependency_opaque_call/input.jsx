@@ -0,0 +1,22 @@
+import { $state } from "palta";
+
+const fill = (parts, value) => {
+  parts.push(value);
+};
+
+// @Palta.component
+const Counter = ({ title }) => {
+  const [count, setCount] = $state(0);
+
+  const parts= [];
+  fill(parts, count);
+
+  return (
+    <div>
+      <h1>{title}</h1>
+      <span onClick={() => setCount(count + 1)}>{parts.join(",")}</span>
+    </div>
+  );
+};
+
+export default Counter;
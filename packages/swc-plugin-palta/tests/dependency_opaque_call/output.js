@@ -0,0 +1,73 @@
+import Palta from "palta";
+
+const fill = (parts, value) => {
+  parts.push(value);
+};
+
+const Counter = () => {
+  const __$element$2 = Palta.createElement("span", [""]);
+  const __$element$1 = Palta.createElement("h1", [""]);
+  const __$element$0 = Palta.createElement("div", [__$element$1, __$element$2]);
+  let __$props = {};
+  let count = 0;
+  const setCount = (value1) => {
+    count = typeof value1 === "function" ? value1(count) : value1;
+    Palta.componentUpdate(() => __$update$0(__$props));
+  };
+  const __$update = ({ title }) => {
+    __$props = {
+      title,
+    };
+    const parts = [];
+    fill(parts, count);
+    __$element$1.updateChild(0, () => {
+      return title;
+    });
+    __$element$2.updateChild(0, () => {
+      return parts.join(",");
+    });
+    __$element$2.updateProps({
+      onClick: () => setCount(count + 1),
+    });
+  };
+  const __$update$0 = ({ title }) => {
+    const parts = [];
+    fill(parts, count);
+    __$element$1.updateChild(0, () => {
+      return title;
+    });
+    __$element$2.updateChild(0, () => {
+      return parts.join(",");
+    });
+    __$element$2.updateProps({
+      onClick: () => setCount(count + 1),
+    });
+  };
+  let __$root = __$element$0;
+
+  return {
+    slots: {},
+    initialize: ({ title }) => {
+      __$props = {
+        title,
+      };
+      const parts = [];
+      fill(parts, count);
+      __$element$1.updateChild(0, () => {
+        return title;
+      });
+      __$element$1.initialize({});
+      __$element$2.updateChild(0, () => {
+        return parts.join(",");
+      });
+      __$element$2.initialize({
+        onClick: () => setCount(count + 1),
+      });
+      __$element$0.initialize({});
+    },
+    update: __$update,
+    getRoot: () => __$root,
+  };
+};
+
+export default Counter;
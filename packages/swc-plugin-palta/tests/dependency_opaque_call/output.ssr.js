@@ -0,0 +1,16 @@
+import Palta from "palta";
+
+const fill = (parts, value) => {
+  parts.push(value);
+};
+
+const Counter = ({ title }) => {
+  const count = 0, setCount = () => {};
+  const parts = [];
+  fill(parts, count);
+  return Palta.html`<div><h1><!--[-->${title}<!--]--></h1><span><!--[-->${
+    parts.join(",")
+  }<!--]--></span></div>`;
+};
+
+export default Counter;
@@ -0,0 +1,20 @@
+import { $state } from "palta";
+
+// @Palta.component
+const Counter = ({ title }) => {
+  const [count, setCount] = $state(0);
+  const [label, setLabel] = $state("Clicks");
+
+  const double = count * 2;
+
+  return (
+    <div>
+      <h1>{title}</h1>
+      <span>
+        {label}: {double}
+      </span>
+    </div>
+  );
+};
+
+export default Counter;
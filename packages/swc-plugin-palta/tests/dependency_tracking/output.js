@@ -0,0 +1,71 @@
+import Palta from "palta";
+
+const Counter = () => {
+  const __$element$2 = Palta.createElement("span", ["", ": ", ""]);
+  const __$element$1 = Palta.createElement("h1", [""]);
+  const __$element$0 = Palta.createElement("div", [__$element$1, __$element$2]);
+  let __$props = {};
+  let count = 0;
+  const setCount = (value) => {
+    count = typeof value === "function" ? value(count) : value;
+    Palta.componentUpdate(() => __$update$0(__$props));
+  };
+  let label = "Clicks";
+  const setLabel = (value) => {
+    label = typeof value === "function" ? value(label) : value;
+    Palta.componentUpdate(() => __$update$1(__$props));
+  };
+  const __$update = ({ title }) => {
+    __$props = {
+      title,
+    };
+    const double = count * 2;
+    __$element$1.updateChild(0, () => {
+      return title;
+    });
+    __$element$2.updateChild(0, () => {
+      return label;
+    });
+    __$element$2.updateChild(2, () => {
+      return double;
+    });
+  };
+  const __$update$0 = ({ title }) => {
+    const double = count * 2;
+    __$element$2.updateChild(2, () => {
+      return double;
+    });
+  };
+  const __$update$1 = ({ title }) => {
+    __$element$2.updateChild(0, () => {
+      return label;
+    });
+  };
+  let __$root = __$element$0;
+
+  return {
+    slots: {},
+    initialize: ({ title }) => {
+      __$props = {
+        title,
+      };
+      const double = count * 2;
+      __$element$1.updateChild(0, () => {
+        return title;
+      });
+      __$element$1.initialize({});
+      __$element$2.updateChild(0, () => {
+        return label;
+      });
+      __$element$2.updateChild(2, () => {
+        return double;
+      });
+      __$element$2.initialize({});
+      __$element$0.initialize({});
+    },
+    update: __$update,
+    getRoot: () => __$root,
+  };
+};
+
+export default Counter;
@@ -0,0 +1,11 @@
+import Palta from "palta";
+
+const Counter = ({ title }) => {
+  const count = 0, setCount = () => {};
+  const label = "Clicks", setLabel = () => {};
+  const double = count * 2;
+  return Palta
+    .html`<div><h1><!--[-->${title}<!--]--></h1><span><!--[-->${label}<!--]-->: <!--[-->${double}<!--]--></span></div>`;
+};
+
+export default Counter;
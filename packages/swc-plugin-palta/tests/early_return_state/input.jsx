@@ -0,0 +1,20 @@
+import { $effect, $state } from "palta";
+
+// @Palta.component
+const Component = ({ a, b }) => {
+  if (a) {
+    if (b) {
+      return <b>b</b>;
+    }
+  }
+
+  const [count, setCount] = $state(0);
+
+  $effect(() => {
+    document.title = `${count}`;
+  }, [count]);
+
+  return <div onClick={() => setCount(count + 1)}>{count}</div>;
+};
+
+export default Component;
@@ -0,0 +1,13 @@
+import Palta from "palta";
+
+const Component = ({ a, b }) => {
+  if (a) {
+    if (b) {
+      return Palta.html`<b>b</b>`;
+    }
+  }
+  const count = 0, setCount = () => {};
+  return Palta.html`<div><!--[-->${count}<!--]--></div>`;
+};
+
+export default Component;
@@ -0,0 +1,148 @@
+import Palta from "palta";
+
+const Component = (): Palta.ComponentDefinition<{
+  a: boolean;
+  b: boolean;
+}> => {
+  let __$element$2: any;
+  let __$element$1: any;
+  const __$element$0 = Palta.createBranch([
+    () => __$element$1,
+    () => __$element$2,
+  ]);
+  let __$props: any = {};
+  let count = 0;
+  const setCount: Palta.StateUpdater<any> = (value) => {
+    count = typeof value === "function" ? value(count) : value;
+    Palta.componentUpdate(() => __$update$0(__$props));
+  };
+  const __$effect$0 = {
+    deps: null,
+    cleanup: null,
+  };
+  const __$update = ({ a, b }: {
+    a: boolean;
+    b: boolean;
+  }) => {
+    __$props = {
+      a,
+      b,
+    };
+    __$guard: {
+      if (a) {
+        if (b) {
+          __$element$0.updateBranch(0, () => {
+            __$element$1 = Palta.createElement("b", ["b"]);
+            __$element$1.initialize({});
+          }, () => {});
+          break __$guard;
+        }
+      }
+      __$element$0.updateBranch(1, () => {
+        __$element$2 = Palta.createElement("div", [""]);
+        __$element$2.updateChild(0, () => {
+          return count;
+        });
+        __$element$2.initialize({
+          onClick: () => setCount(count + 1),
+        });
+      }, () => {
+        __$element$2.updateChild(0, () => {
+          return count;
+        });
+        __$element$2.updateProps({
+          onClick: () => setCount(count + 1),
+        });
+      }, [__$effect$0]);
+      Palta.runEffect(__$effect$0, () => {
+        document.title = `${count}`;
+      }, [count]);
+    }
+  };
+  const __$update$0 = ({ a, b }: {
+    a: boolean;
+    b: boolean;
+  }) => {
+    __$guard: {
+      if (a) {
+        if (b) {
+          __$element$0.updateBranch(0, () => {
+            __$element$1 = Palta.createElement("b", ["b"]);
+            __$element$1.initialize({});
+          }, () => {});
+          break __$guard;
+        }
+      }
+      __$element$0.updateBranch(1, () => {
+        __$element$2 = Palta.createElement("div", [""]);
+        __$element$2.updateChild(0, () => {
+          return count;
+        });
+        __$element$2.initialize({
+          onClick: () => setCount(count + 1),
+        });
+      }, () => {
+        __$element$2.updateChild(0, () => {
+          return count;
+        });
+        __$element$2.updateProps({
+          onClick: () => setCount(count + 1),
+        });
+      }, [__$effect$0]);
+      Palta.runEffect(__$effect$0, () => {
+        document.title = `${count}`;
+      }, [count]);
+    }
+  };
+  let __$root = __$element$0;
+
+  return {
+    slots: {},
+    initialize: ({ a, b }: {
+      a: boolean;
+      b: boolean;
+    }) => {
+      __$props = {
+        a,
+        b,
+      };
+      __$guard: {
+        if (a) {
+          if (b) {
+            __$element$0.updateBranch(0, () => {
+              __$element$1 = Palta.createElement("b", ["b"]);
+              __$element$1.initialize({});
+            }, () => {});
+            break __$guard;
+          }
+        }
+        Palta.mountEffect(__$effect$0, () => {
+          document.title = `${count}`;
+        }, [count]);
+        __$element$0.updateBranch(1, () => {
+          __$element$2 = Palta.createElement("div", [""]);
+          __$element$2.updateChild(0, () => {
+            return count;
+          });
+          __$element$2.initialize({
+            onClick: () => setCount(count + 1),
+          });
+        }, () => {
+          __$element$2.updateChild(0, () => {
+            return count;
+          });
+          __$element$2.updateProps({
+            onClick: () => setCount(count + 1),
+          });
+        }, [__$effect$0]);
+      }
+    },
+    update: __$update,
+    getRoot: () => __$root,
+    unmount: () => {
+      Palta.cleanupEffect(__$effect$0);
+    },
+  };
+};
+
+export default Component;
@@ -6,6 +6,7 @@ const Component = () => {
   let __$props = {};
   const __$effect$0 = {
     deps: null,
+    cleanup: null,
   };
   const __$update = ({ name }) => {
     __$props = { name };
@@ -17,16 +18,22 @@ const Component = () => {
       () => {
         console.log("Name has changed");
       },
-      null,
       [name]
     );
   };
   let __$root = __$element$0;
 
   return {
-    childrenElement: null,
+    slots: {},
     initialize: ({ name }) => {
       __$props = { name };
+      Palta.mountEffect(
+        __$effect$0,
+        () => {
+          console.log("Name has changed");
+        },
+        [name]
+      );
       __$element$1.updateChild(1, () => {
         return name;
       });
@@ -35,6 +42,9 @@ const Component = () => {
     },
     update: __$update,
     getRoot: () => __$root,
+    unmount: () => {
+      Palta.cleanupEffect(__$effect$0);
+    },
   };
 };
 
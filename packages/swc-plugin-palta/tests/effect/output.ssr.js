@@ -0,0 +1,7 @@
+import Palta from "palta";
+
+const Component = ({ name }) => {
+  return Palta.html`<div><h1>Hello, <!--[-->${name}<!--]-->!</h1></div>`;
+};
+
+export default Component;
@@ -8,6 +8,7 @@ const Component = (): Palta.ComponentDefinition<{
   let __$props: any = {};
   const __$effect$0 = {
     deps: null,
+    cleanup: null,
   };
   const __$update = ({ name }: { name: string }) => {
     __$props = { name };
@@ -19,16 +20,22 @@ const Component = (): Palta.ComponentDefinition<{
       () => {
         console.log("Name has changed");
       },
-      null,
       [name]
     );
   };
   let __$root = __$element$0;
 
   return {
-    childrenElement: null,
+    slots: {},
     initialize: ({ name }: { name: string }) => {
       __$props = { name };
+      Palta.mountEffect(
+        __$effect$0,
+        () => {
+          console.log("Name has changed");
+        },
+        [name]
+      );
       __$element$1.updateChild(1, () => {
         return name;
       });
@@ -37,6 +44,9 @@ const Component = (): Palta.ComponentDefinition<{
     },
     update: __$update,
     getRoot: () => __$root,
+    unmount: () => {
+      Palta.cleanupEffect(__$effect$0);
+    },
   };
 };
 
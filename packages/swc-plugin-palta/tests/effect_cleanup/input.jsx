@@ -0,0 +1,18 @@
+import { $effect, $state } from "palta";
+
+// @Palta.component
+const Component = ({ delay }) => {
+  const [ticks, setTicks] = $state(0);
+
+  $effect(() => {
+    const interval = setInterval(() => setTicks((t) => t + 1), delay);
+
+    return () => clearInterval(interval);
+  }, [delay]);
+
+  $effect(() => subscribe(ticks), [ticks]);
+
+  return <p>{ticks}</p>;
+};
+
+export default Component;
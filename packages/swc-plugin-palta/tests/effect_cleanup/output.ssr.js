@@ -0,0 +1,8 @@
+import Palta from "palta";
+
+const Component = ({ delay }) => {
+  const ticks = 0, setTicks = () => {};
+  return Palta.html`<p><!--[-->${ticks}<!--]--></p>`;
+};
+
+export default Component;
@@ -0,0 +1,79 @@
+import Palta from "palta";
+
+const Component = (): Palta.ComponentDefinition<{
+  delay: number;
+}> => {
+  const __$element$0 = Palta.createElement("p", [""]);
+  let __$props: any = {};
+  let ticks = 0;
+  const setTicks: Palta.StateUpdater<any> = (value) => {
+    ticks = typeof value === "function" ? value(ticks) : value;
+    Palta.componentUpdate(() => __$update$0(__$props));
+  };
+  const __$effect$0 = {
+    deps: null,
+    cleanup: null,
+  };
+  const __$effect$1 = {
+    deps: null,
+    cleanup: null,
+  };
+  const __$update = ({ delay }: {
+    delay: number;
+  }) => {
+    __$props = {
+      delay,
+    };
+    __$element$0.updateChild(0, () => {
+      return ticks;
+    });
+    Palta.runEffect(__$effect$0, () => {
+      const interval = setInterval(() => setTicks((t) => t + 1), delay);
+      return () => clearInterval(interval);
+    }, [delay]);
+    Palta.runEffect(__$effect$1, () => {
+      return subscribe(ticks);
+    }, [ticks]);
+  };
+  const __$update$0 = ({ delay }: {
+    delay: number;
+  }) => {
+    __$element$0.updateChild(0, () => {
+      return ticks;
+    });
+    Palta.runEffect(__$effect$1, () => {
+      return subscribe(ticks);
+    }, [ticks]);
+  };
+  let __$root = __$element$0;
+
+  return {
+    slots: {},
+    initialize: ({ delay }: {
+      delay: number;
+    }) => {
+      __$props = {
+        delay,
+      };
+      Palta.mountEffect(__$effect$0, () => {
+        const interval = setInterval(() => setTicks((t) => t + 1), delay);
+        return () => clearInterval(interval);
+      }, [delay]);
+      Palta.mountEffect(__$effect$1, () => {
+        return subscribe(ticks);
+      }, [ticks]);
+      __$element$0.updateChild(0, () => {
+        return ticks;
+      });
+      __$element$0.initialize({});
+    },
+    update: __$update,
+    getRoot: () => __$root,
+    unmount: () => {
+      Palta.cleanupEffect(__$effect$0);
+      Palta.cleanupEffect(__$effect$1);
+    },
+  };
+};
+
+export default Component;
@@ -0,0 +1,24 @@
+import { $effect, $state } from "palta";
+
+// @Palta.component
+const Component = ({ url }) => {
+  const [data, setData] = $state(null);
+
+  $effect(() => {
+    fetch(url)
+      .then((response) => response.json())
+      .then(setData);
+  }, []);
+
+  if (data === null) {
+    $effect(() => {
+      console.log("Loading");
+    }, [url]);
+
+    return <p>Loading...</p>;
+  }
+
+  return <p>{data.title}</p>;
+};
+
+export default Component;
@@ -0,0 +1,11 @@
+import Palta from "palta";
+
+const Component = ({ url }) => {
+  const data = null, setData = () => {};
+  if (data === null) {
+    return Palta.html`<p>Loading...</p>`;
+  }
+  return Palta.html`<p><!--[-->${data.title}<!--]--></p>`;
+};
+
+export default Component;
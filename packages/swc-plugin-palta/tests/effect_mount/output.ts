@@ -0,0 +1,147 @@
+import Palta from "palta";
+
+const Component = (): Palta.ComponentDefinition<{
+  url: string;
+}> => {
+  let __$element$2: any;
+  let __$element$1: any;
+  const __$element$0 = Palta.createBranch([
+    () => __$element$1,
+    () => __$element$2,
+  ]);
+  let __$props: any = {};
+  let data = null;
+  const setData: Palta.StateUpdater<any> = (value) => {
+    data = typeof value === "function" ? value(data) : value;
+    Palta.componentUpdate(() => __$update$0(__$props));
+  };
+  const __$effect$0 = {
+    deps: null,
+    cleanup: null,
+  };
+  const __$effect$1 = {
+    deps: null,
+    cleanup: null,
+  };
+  const __$update = ({ url }: {
+    url: string;
+  }) => {
+    __$props = {
+      url,
+    };
+    __$guard: {
+      if (data === null) {
+        __$element$0.updateBranch(
+          0,
+          () => {
+            __$element$1 = Palta.createElement("p", ["Loading..."]);
+            __$element$1.initialize({});
+          },
+          () => {},
+          [__$effect$1]
+        );
+        Palta.runEffect(__$effect$1, () => {
+          console.log("Loading");
+        }, [url]);
+        break __$guard;
+      }
+      __$element$0.updateBranch(1, () => {
+        __$element$2 = Palta.createElement("p", [""]);
+        __$element$2.updateChild(0, () => {
+          return data.title;
+        });
+        __$element$2.initialize({});
+      }, () => {
+        __$element$2.updateChild(0, () => {
+          return data.title;
+        });
+      });
+    }
+    Palta.runEffect(__$effect$0, () => {
+      fetch(url).then((response) => response.json()).then(setData);
+    }, []);
+  };
+  const __$update$0 = ({ url }: {
+    url: string;
+  }) => {
+    __$guard: {
+      if (data === null) {
+        __$element$0.updateBranch(
+          0,
+          () => {
+            __$element$1 = Palta.createElement("p", ["Loading..."]);
+            __$element$1.initialize({});
+          },
+          () => {},
+          [__$effect$1]
+        );
+        Palta.runEffect(__$effect$1, () => {
+          console.log("Loading");
+        }, [url]);
+        break __$guard;
+      }
+      __$element$0.updateBranch(1, () => {
+        __$element$2 = Palta.createElement("p", [""]);
+        __$element$2.updateChild(0, () => {
+          return data.title;
+        });
+        __$element$2.initialize({});
+      }, () => {
+        __$element$2.updateChild(0, () => {
+          return data.title;
+        });
+      });
+    }
+  };
+  let __$root = __$element$0;
+
+  return {
+    slots: {},
+    initialize: ({ url }: {
+      url: string;
+    }) => {
+      __$props = {
+        url,
+      };
+      Palta.mountEffect(__$effect$0, () => {
+        fetch(url).then((response) => response.json()).then(setData);
+      }, []);
+      __$guard: {
+        if (data === null) {
+          Palta.mountEffect(__$effect$1, () => {
+            console.log("Loading");
+          }, [url]);
+          __$element$0.updateBranch(
+            0,
+            () => {
+              __$element$1 = Palta.createElement("p", ["Loading..."]);
+              __$element$1.initialize({});
+            },
+            () => {},
+            [__$effect$1]
+          );
+          break __$guard;
+        }
+        __$element$0.updateBranch(1, () => {
+          __$element$2 = Palta.createElement("p", [""]);
+          __$element$2.updateChild(0, () => {
+            return data.title;
+          });
+          __$element$2.initialize({});
+        }, () => {
+          __$element$2.updateChild(0, () => {
+            return data.title;
+          });
+        });
+      }
+    },
+    update: __$update,
+    getRoot: () => __$root,
+    unmount: () => {
+      Palta.cleanupEffect(__$effect$0);
+      Palta.cleanupEffect(__$effect$1);
+    },
+  };
+};
+
+export default Component;
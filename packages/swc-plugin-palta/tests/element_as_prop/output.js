@@ -14,7 +14,7 @@ const SubComponent = () => {
   let __$root = __$element$0;
 
   return {
-    childrenElement: null,
+    slots: {},
     initialize: ({ element }) => {
       __$props = {
         element,
@@ -47,7 +47,7 @@ const Component = () => {
   let __$root = __$element$1;
 
   return {
-    childrenElement: null,
+    slots: {},
     initialize: ({ name }) => {
       __$props = {
         name,
@@ -0,0 +1,13 @@
+import Palta from "palta";
+
+const SubComponent = ({ element }) => {
+  return Palta.html`<div><!--[-->${element}<!--]--></div>`;
+};
+
+const Component = ({ name }) => {
+  return Palta.renderComponent(SubComponent, {
+    element: Palta.html`<h1>Hello, <!--[-->${name}<!--]-->!</h1>`,
+  });
+};
+
+export default Component;
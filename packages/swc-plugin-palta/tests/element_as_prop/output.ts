@@ -16,7 +16,7 @@ const SubComponent = (): Palta.ComponentDefinition<{
   let __$root = __$element$0;
 
   return {
-    childrenElement: null,
+    slots: {},
     initialize: ({ element }: { element: Palta.Node }) => {
       __$props = {
         element,
@@ -51,7 +51,7 @@ const Component = (): Palta.ComponentDefinition<{
   let __$root = __$element$1;
   
   return {
-    childrenElement: null,
+    slots: {},
     initialize: ({ name }: { name: string }) => {
       __$props = {
         name,
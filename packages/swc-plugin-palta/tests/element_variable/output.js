@@ -17,7 +17,7 @@ const Component = () => {
   let __$root = __$element$1;
 
   return {
-    childrenElement: null,
+    slots: {},
     initialize: ({ name }) => {
       __$props = { name };
       __$element$0.updateChild(1, () => {
@@ -0,0 +1,8 @@
+import Palta from "palta";
+
+const Component = ({ name }) => {
+  const content = Palta.html`<h1>Hello, <!--[-->${name}<!--]-->!</h1>`;
+  return Palta.html`<div><!--[-->${content}<!--]--></div>`;
+};
+
+export default Component;
@@ -19,7 +19,7 @@ const Component = (): Palta.ComponentDefinition<{
   let __$root = __$element$1;
 
   return {
-    childrenElement: null,
+    slots: {},
     initialize: ({ name }: { name: string }) => {
       __$props = { name };
       __$element$0.updateChild(1, () => {
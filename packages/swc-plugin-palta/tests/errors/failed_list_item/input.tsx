@@ -0,0 +1,32 @@
+import { $effect } from "palta";
+
+// @Palta.component
+const Broken = ({ items }: { items: string[] }) => {
+  return (
+    <section>
+      <header>
+        <h1>Items</h1>
+      </header>
+      <ul>
+        {items.map((item) => {
+          $effect(() => {
+            console.log(item);
+          });
+
+          return <li>{item}</li>;
+        })}
+      </ul>
+    </section>
+  );
+};
+
+// @Palta.component
+const Component = () => {
+  return (
+    <header>
+      <h1>Hello</h1>
+    </header>
+  );
+};
+
+export default Component;
@@ -0,0 +1,42 @@
+import Palta from "palta";
+
+const __$template$0 = Palta.createTemplate("<header><h1>Hello</h1></header>");
+
+const Broken = ({ items }: {
+  items: string[];
+}) => {
+  return <section>
+      <header>
+        <h1>Items</h1>
+      </header>
+      <ul>
+        {items.map((item) => {
+    $effect(() => {
+      console.log(item);
+    });
+    return <li>{item}</li>;
+  })}
+      </ul>
+    </section>;
+};
+
+const Component = () => {
+  const __$element$0 = Palta.cloneTemplate(__$template$0, "header", [null]);
+  let __$props = {};
+  const __$update = ({}) => {
+    __$props = {};
+  };
+  let __$root = __$element$0;
+
+  return {
+    slots: {},
+    initialize: ({}) => {
+      __$props = {};
+      __$element$0.initialize({});
+    },
+    update: __$update,
+    getRoot: () => __$root,
+  };
+};
+
+export default Component;
@@ -0,0 +1,8 @@
+import Palta from "palta";
+
+// @Palta.component
+const Component = () => {
+  return <p>Hello</p>;
+};
+
+export default Component;
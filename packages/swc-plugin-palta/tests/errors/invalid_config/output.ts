@@ -0,0 +1,22 @@
+import Palta from "palta";
+
+const Component = () => {
+  const __$element$0 = Palta.createElement("p", ["Hello"]);
+  let __$props = {};
+  const __$update = ({}) => {
+    __$props = {};
+  };
+  let __$root = __$element$0;
+
+  return {
+    slots: {},
+    initialize: ({}) => {
+      __$props = {};
+      __$element$0.initialize({});
+    },
+    update: __$update,
+    getRoot: () => __$root,
+  };
+};
+
+export default Component;
@@ -0,0 +1,17 @@
+import { $effect } from "palta";
+
+// @Palta.component
+const Broken = ({ name }: { name: string }) => {
+  $effect(() => {
+    console.log(name);
+  });
+
+  return <div>{name}</div>;
+};
+
+// @Palta.component
+const Component = ({ name }: { name: string }) => {
+  return <h1>Hello, {name}!</h1>;
+};
+
+export default Component;
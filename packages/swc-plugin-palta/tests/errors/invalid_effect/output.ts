@@ -0,0 +1,47 @@
+import Palta from "palta";
+
+const Broken = ({ name }: {
+  name: string;
+}) => {
+  $effect(() => {
+    console.log(name);
+  });
+  return <div>{name}</div>;
+};
+
+const Component = (): Palta.ComponentDefinition<{
+  name: string;
+}> => {
+  const __$element$0 = Palta.createElement("h1", ["Hello, ", "", "!"]);
+  let __$props: any = {};
+  const __$update = ({ name }: {
+    name: string;
+  }) => {
+    __$props = {
+      name,
+    };
+    __$element$0.updateChild(1, () => {
+      return name;
+    });
+  };
+  let __$root = __$element$0;
+
+  return {
+    slots: {},
+    initialize: ({ name }: {
+      name: string;
+    }) => {
+      __$props = {
+        name,
+      };
+      __$element$0.updateChild(1, () => {
+        return name;
+      });
+      __$element$0.initialize({});
+    },
+    update: __$update,
+    getRoot: () => __$root,
+  };
+};
+
+export default Component;
@@ -0,0 +1,6 @@
+import Palta from "palta";
+
+// @Palta.component
+class Widget {}
+
+export const widgets = [Palta.component(() => <p>Widget</p>)];
@@ -0,0 +1,6 @@
+import Palta from "palta";
+
+class Widget {
+}
+
+export const widgets = [Palta.component(() => <p>Widget</p>)];
@@ -0,0 +1,14 @@
+import Palta, { Slot } from "palta";
+
+// @Palta.component
+const Layout = () => {
+  return (
+    <div>
+      <Slot name="header">
+        <h1>Title</h1>
+      </Slot>
+    </div>
+  );
+};
+
+export default Layout;
@@ -0,0 +1,11 @@
+import Palta from "palta";
+
+const Layout = () => {
+  return <div>
+      <Slot name="header">
+        <h1>Title</h1>
+      </Slot>
+    </div>;
+};
+
+export default Layout;
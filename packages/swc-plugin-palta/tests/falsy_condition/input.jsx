@@ -0,0 +1,11 @@
+// @Palta.component
+const Cart = ({ count }) => {
+  return (
+    <div>
+      {count && <p>{count} items</p>}
+      {count > 10 && <strong>Free shipping</strong>}
+    </div>
+  );
+};
+
+export default Cart;
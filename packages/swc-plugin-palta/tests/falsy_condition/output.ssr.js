@@ -0,0 +1,11 @@
+import Palta from "palta";
+
+const Cart = ({ count }) => {
+  return Palta.html`<div><!--[-->${
+    count && Palta.html`<p><!--[-->${count}<!--]--> items</p>`
+  }<!--]--><!--[-->${
+    count > 10 && Palta.html`<strong>Free shipping</strong>`
+  }<!--]--></div>`;
+};
+
+export default Cart;
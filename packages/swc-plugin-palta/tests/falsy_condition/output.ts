@@ -0,0 +1,104 @@
+import Palta from "palta";
+
+const Cart = (): Palta.ComponentDefinition<{
+  count: number;
+}> => {
+  let __$element$5: any;
+  const __$element$4 = Palta.createBranch([() => __$element$5, () => null]);
+  let __$element$3: any;
+  let __$element$2: any;
+  const __$element$1 = Palta.createBranch([
+    () => __$element$2,
+    () => __$element$3,
+  ]);
+  const __$element$0 = Palta.createElement("div", [__$element$1, __$element$4]);
+  let __$props: any = {};
+  const __$update = ({ count }: {
+    count: number;
+  }) => {
+    __$props = {
+      count,
+    };
+    if (count) {
+      __$element$1.updateBranch(0, () => {
+        __$element$2 = Palta.createElement("p", ["", " items"]);
+        __$element$2.updateChild(0, () => {
+          return count;
+        });
+        __$element$2.initialize({});
+      }, () => {
+        __$element$2.updateChild(0, () => {
+          return count;
+        });
+      });
+    } else {
+      __$element$1.updateBranch(1, () => {
+        __$element$3 = Palta.createFragment([""]);
+        __$element$3.updateChild(0, () => {
+          return count;
+        });
+      }, () => {
+        __$element$3.updateChild(0, () => {
+          return count;
+        });
+      });
+    }
+    if (count > 10) {
+      __$element$4.updateBranch(0, () => {
+        __$element$5 = Palta.createElement("strong", ["Free shipping"]);
+        __$element$5.initialize({});
+      }, () => {});
+    } else {
+      __$element$4.updateBranch(1);
+    }
+  };
+  let __$root = __$element$0;
+
+  return {
+    slots: {},
+    initialize: ({ count }: {
+      count: number;
+    }) => {
+      __$props = {
+        count,
+      };
+      if (count) {
+        __$element$1.updateBranch(0, () => {
+          __$element$2 = Palta.createElement("p", ["", " items"]);
+          __$element$2.updateChild(0, () => {
+            return count;
+          });
+          __$element$2.initialize({});
+        }, () => {
+          __$element$2.updateChild(0, () => {
+            return count;
+          });
+        });
+      } else {
+        __$element$1.updateBranch(1, () => {
+          __$element$3 = Palta.createFragment([""]);
+          __$element$3.updateChild(0, () => {
+            return count;
+          });
+        }, () => {
+          __$element$3.updateChild(0, () => {
+            return count;
+          });
+        });
+      }
+      if (count > 10) {
+        __$element$4.updateBranch(0, () => {
+          __$element$5 = Palta.createElement("strong", ["Free shipping"]);
+          __$element$5.initialize({});
+        }, () => {});
+      } else {
+        __$element$4.updateBranch(1);
+      }
+      __$element$0.initialize({});
+    },
+    update: __$update,
+    getRoot: () => __$root,
+  };
+};
+
+export default Cart;
@@ -0,0 +1,12 @@
+// @Palta.component
+const Component = ({ name }) => {
+  return (
+    <>
+      <h1>Title</h1>
+      Hello, {name}!
+      <p>Content</p>
+    </>
+  );
+};
+
+export default Component;
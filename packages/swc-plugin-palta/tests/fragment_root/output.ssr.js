@@ -0,0 +1,9 @@
+import Palta from "palta";
+
+const Component = ({ name }) => {
+  return Palta.html`<h1>Title</h1>
+      Hello, <!--[-->${name}<!--]-->!
+      <p>Content</p>`;
+};
+
+export default Component;
@@ -0,0 +1,47 @@
+import Palta from "palta";
+
+const Component = (): Palta.ComponentDefinition<{
+  name: string;
+}> => {
+  const __$element$2 = Palta.createElement("p", ["Content"]);
+  const __$element$1 = Palta.createElement("h1", ["Title"]);
+  const __$element$0 = Palta.createFragment([
+    __$element$1,
+    "\n      Hello, ",
+    "",
+    "!\n      ",
+    __$element$2,
+  ]);
+  let __$props: any = {};
+  const __$update = ({ name }: {
+    name: string;
+  }) => {
+    __$props = {
+      name,
+    };
+    __$element$0.updateChild(2, () => {
+      return name;
+    });
+  };
+  let __$root = __$element$0;
+
+  return {
+    slots: {},
+    initialize: ({ name }: {
+      name: string;
+    }) => {
+      __$props = {
+        name,
+      };
+      __$element$1.initialize({});
+      __$element$0.updateChild(2, () => {
+        return name;
+      });
+      __$element$2.initialize({});
+    },
+    update: __$update,
+    getRoot: () => __$root,
+  };
+};
+
+export default Component;
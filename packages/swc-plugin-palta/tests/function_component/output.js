@@ -13,7 +13,7 @@ function Component() {
   let __$root = __$element$0;
 
   return {
-    childrenElement: null,
+    slots: {},
     initialize: ({ name }) => {
       __$props = { name };
       __$element$1.updateChild(1, () => {
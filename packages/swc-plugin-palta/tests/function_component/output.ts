@@ -13,7 +13,7 @@ function Component(): Palta.ComponentDefinition<{ name: string }> {
   let __$root = __$element$0;
 
   return {
-    childrenElement: null,
+    slots: {},
     initialize: ({ name }: { name: string }) => {
       __$props = { name };
       __$element$1.updateChild(1, () => {
@@ -0,0 +1,23 @@
+import { $state } from "palta";
+
+// @Palta.component
+const Counter = () => {
+  const [count, setCount] = $state(0);
+  const [step] = $state(1);
+
+  return <button onClick={() => setCount(count + step)}>{count}</button>;
+};
+
+// @Palta.component
+export function Title({ text }: { text: string }) {
+  return <h1>{text}</h1>;
+}
+
+// @Palta.component
+const Label = ({ text }: { text: string }) => {
+  return <span>{text}</span>;
+};
+
+export { Label as Caption };
+
+export default Counter;
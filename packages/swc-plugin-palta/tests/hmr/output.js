@@ -0,0 +1,125 @@
+import Palta from "palta";
+
+const Counter = () => {
+  const __$element$0 = Palta.createElement("button", [""]);
+  let __$props = {};
+  let count = 0;
+  const setCount = (value) => {
+    count = typeof value === "function" ? value(count) : value;
+    Palta.componentUpdate(() => __$update$0(__$props));
+  };
+  let step = 1;
+  const __$update = ({}) => {
+    __$props = {};
+    __$element$0.updateChild(0, () => {
+      return count;
+    });
+    __$element$0.updateProps({
+      onClick: () => setCount(count + step),
+    });
+  };
+  const __$update$0 = ({}) => {
+    __$element$0.updateChild(0, () => {
+      return count;
+    });
+    __$element$0.updateProps({
+      onClick: () => setCount(count + step),
+    });
+  };
+  let __$root = __$element$0;
+
+  return {
+    slots: {},
+    initialize: ({}) => {
+      __$props = {};
+      __$element$0.updateChild(0, () => {
+        return count;
+      });
+      __$element$0.initialize({
+        onClick: () => setCount(count + step),
+      });
+    },
+    update: __$update,
+    getRoot: () => __$root,
+    hot: {
+      shape: "count,step",
+      save: () => [count, step],
+      restore: (__$state) => {
+        count = __$state[0];
+        step = __$state[1];
+      },
+    },
+  };
+};
+
+Palta.registerHotComponent(Counter, "tests/hmr/input.jsx#default");
+
+export function Title() {
+  const __$element$0 = Palta.createElement("h1", [""]);
+  let __$props = {};
+  const __$update = ({ text }) => {
+    __$props = {
+      text,
+    };
+    __$element$0.updateChild(0, () => {
+      return text;
+    });
+  };
+  let __$root = __$element$0;
+
+  return {
+    slots: {},
+    initialize: ({ text }) => {
+      __$props = {
+        text,
+      };
+      __$element$0.updateChild(0, () => {
+        return text;
+      });
+      __$element$0.initialize({});
+    },
+    update: __$update,
+    getRoot: () => __$root,
+  };
+}
+
+Palta.registerHotComponent(Title, "tests/hmr/input.jsx#Title");
+
+const Label = () => {
+  const __$element$0 = Palta.createElement("span", [""]);
+  let __$props = {};
+  const __$update = ({ text }) => {
+    __$props = {
+      text,
+    };
+    __$element$0.updateChild(0, () => {
+      return text;
+    });
+  };
+  let __$root = __$element$0;
+
+  return {
+    slots: {},
+    initialize: ({ text }) => {
+      __$props = {
+        text,
+      };
+      __$element$0.updateChild(0, () => {
+        return text;
+      });
+      __$element$0.initialize({});
+    },
+    update: __$update,
+    getRoot: () => __$root,
+  };
+};
+
+Palta.registerHotComponent(Label, "tests/hmr/input.jsx#Caption");
+
+export { Label as Caption };
+
+export default Counter;
+
+if (import.meta.hot) {
+  import.meta.hot.accept(() => Palta.reloadHotComponents());
+}
@@ -0,0 +1,19 @@
+import Palta from "palta";
+
+const Counter = () => {
+  const count = 0, setCount = () => {};
+  const step = 1;
+  return Palta.html`<button><!--[-->${count}<!--]--></button>`;
+};
+
+export function Title({ text }) {
+  return Palta.html`<h1><!--[-->${text}<!--]--></h1>`;
+}
+
+const Label = ({ text }) => {
+  return Palta.html`<span><!--[-->${text}<!--]--></span>`;
+};
+
+export { Label as Caption };
+
+export default Counter;
@@ -0,0 +1,3 @@
+import Palta from "palta";
+
+export default Palta.component(({ name }) => <p>Hello, {name}!</p>);
@@ -0,0 +1,5 @@
+import Palta from "palta";
+
+export default Palta.component(({ name }: { name: string }) => (
+  <p>Hello, {name}!</p>
+));
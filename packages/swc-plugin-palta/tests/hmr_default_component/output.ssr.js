@@ -0,0 +1,4 @@
+import Palta from "palta";
+
+export default (({ name }) =>
+  Palta.html`<p>Hello, <!--[-->${name}<!--]-->!</p>`);
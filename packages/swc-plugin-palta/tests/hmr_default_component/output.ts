@@ -0,0 +1,47 @@
+import Palta from "palta";
+
+const __$component = (): Palta.ComponentDefinition<{
+  name: string;
+}> => {
+  const __$element$0 = Palta.createElement("p", ["Hello, ", "", "!"]);
+  let __$props: any = {};
+  const __$update = ({ name }: {
+    name: string;
+  }) => {
+    __$props = {
+      name,
+    };
+    __$element$0.updateChild(1, () => {
+      return name;
+    });
+  };
+  let __$root = __$element$0;
+
+  return {
+    slots: {},
+    initialize: ({ name }: {
+      name: string;
+    }) => {
+      __$props = {
+        name,
+      };
+      __$element$0.updateChild(1, () => {
+        return name;
+      });
+      __$element$0.initialize({});
+    },
+    update: __$update,
+    getRoot: () => __$root,
+  };
+};
+
+Palta.registerHotComponent(
+  __$component,
+  "tests/hmr_default_component/input.tsx#default"
+);
+
+export default __$component;
+
+if (import.meta.hot) {
+  import.meta.hot.accept(() => Palta.reloadHotComponents());
+}
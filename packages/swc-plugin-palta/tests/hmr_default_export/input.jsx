@@ -0,0 +1,4 @@
+// @Palta.component
+export default function ({ name }) {
+  return <p>Hello, {name}!</p>;
+}
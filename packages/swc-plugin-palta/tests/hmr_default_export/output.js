@@ -0,0 +1,39 @@
+import Palta from "palta";
+
+export default function __$component() {
+  const __$element$0 = Palta.createElement("p", ["Hello, ", "", "!"]);
+  let __$props = {};
+  const __$update = ({ name }) => {
+    __$props = {
+      name,
+    };
+    __$element$0.updateChild(1, () => {
+      return name;
+    });
+  };
+  let __$root = __$element$0;
+
+  return {
+    slots: {},
+    initialize: ({ name }) => {
+      __$props = {
+        name,
+      };
+      __$element$0.updateChild(1, () => {
+        return name;
+      });
+      __$element$0.initialize({});
+    },
+    update: __$update,
+    getRoot: () => __$root,
+  };
+}
+
+Palta.registerHotComponent(
+  __$component,
+  "tests/hmr_default_export/input.jsx#default"
+);
+
+if (import.meta.hot) {
+  import.meta.hot.accept(() => Palta.reloadHotComponents());
+}
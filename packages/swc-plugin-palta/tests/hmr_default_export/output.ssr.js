@@ -0,0 +1,5 @@
+import Palta from "palta";
+
+export default function({ name }) {
+  return Palta.html`<p>Hello, <!--[-->${name}<!--]-->!</p>`;
+}
@@ -0,0 +1,27 @@
+import { $state } from "palta";
+import Avatar from "./Avatar";
+
+// @Palta.component
+export const Profile = ({ name }: { name: string }) => {
+  const [open, setOpen] = $state(false);
+
+  return (
+    <section className="profile">
+      <h1>Hello, {name}!</h1>
+      <Avatar name={name} />
+      <button onClick={() => setOpen(!open)}>Toggle</button>
+      {open ? <p>Opened</p> : <p>Closed</p>}
+    </section>
+  );
+};
+
+// @Palta.component
+export const Page = ({ name }: { name: string }) => <Profile name={name} />;
+
+// @Palta.component
+export const List = () => (
+  <>
+    <Profile name="first" />
+    <Profile name="second" />
+  </>
+);
@@ -0,0 +1,178 @@
+import Palta from "palta";
+
+import Avatar from "./Avatar";
+
+export const Profile = (): Palta.ComponentDefinition<{
+  name: string;
+}> => {
+  let __$element$6: any;
+  let __$element$5: any;
+  const __$element$4 = Palta.createBranch([
+    () => __$element$5,
+    () => __$element$6,
+  ]);
+  const __$element$3 = Palta.claimElement(() => __$element$0, 2, "button", [
+    "Toggle",
+  ]);
+  const __$element$2 = Palta.claimComponent(() => __$element$0, 1, Avatar, []);
+  const __$element$1 = Palta.claimElement(() => __$element$0, 0, "h1", [
+    "Hello, ",
+    "",
+    "!",
+  ]);
+  const __$element$0 = Palta.claimElement(null, 0, "section", [
+    __$element$1,
+    __$element$2,
+    __$element$3,
+    __$element$4,
+  ]);
+  let __$props: any = {};
+  let open = false;
+  const setOpen: Palta.StateUpdater<any> = (value) => {
+    open = typeof value === "function" ? value(open) : value;
+    Palta.componentUpdate(() => __$update$0(__$props));
+  };
+  const __$update = ({ name }: {
+    name: string;
+  }) => {
+    __$props = {
+      name,
+    };
+    __$element$1.updateChild(1, () => {
+      return name;
+    });
+    __$element$2.updateProps({
+      name: name,
+    });
+    __$element$3.updateProps({
+      onClick: () => setOpen(!open),
+    });
+    if (open) {
+      __$element$4.updateBranch(0, () => {
+        __$element$5 = Palta.createElement("p", ["Opened"]);
+        __$element$5.initialize({});
+      }, () => {});
+    } else {
+      __$element$4.updateBranch(1, () => {
+        __$element$6 = Palta.createElement("p", ["Closed"]);
+        __$element$6.initialize({});
+      }, () => {});
+    }
+  };
+  const __$update$0 = ({ name }: {
+    name: string;
+  }) => {
+    __$element$3.updateProps({
+      onClick: () => setOpen(!open),
+    });
+    if (open) {
+      __$element$4.updateBranch(0, () => {
+        __$element$5 = Palta.createElement("p", ["Opened"]);
+        __$element$5.initialize({});
+      }, () => {});
+    } else {
+      __$element$4.updateBranch(1, () => {
+        __$element$6 = Palta.createElement("p", ["Closed"]);
+        __$element$6.initialize({});
+      }, () => {});
+    }
+  };
+  let __$root = __$element$0;
+
+  return {
+    slots: {},
+    initialize: ({ name }: {
+      name: string;
+    }) => {
+      __$props = {
+        name,
+      };
+      __$element$1.updateChild(1, () => {
+        return name;
+      });
+      __$element$1.initialize({});
+      __$element$2.initialize({
+        name: name,
+      });
+      __$element$3.initialize({
+        onClick: () => setOpen(!open),
+      });
+      if (open) {
+        __$element$4.updateBranch(0, () => {
+          __$element$5 = Palta.createElement("p", ["Opened"]);
+          __$element$5.initialize({});
+        }, () => {});
+      } else {
+        __$element$4.updateBranch(1, () => {
+          __$element$6 = Palta.createElement("p", ["Closed"]);
+          __$element$6.initialize({});
+        }, () => {});
+      }
+      __$element$0.initialize({
+        className: "profile",
+      });
+    },
+    update: __$update,
+    getRoot: () => __$root,
+  };
+};
+
+export const Page = (): Palta.ComponentDefinition<{
+  name: string;
+}> => {
+  const __$element$0 = Palta.claimComponent(null, 0, Profile, []);
+  let __$props: any = {};
+  const __$update = ({ name }: {
+    name: string;
+  }) => {
+    __$props = {
+      name,
+    };
+    __$element$0.updateProps({
+      name: name,
+    });
+  };
+  let __$root = __$element$0;
+
+  return {
+    slots: {},
+    initialize: ({ name }: {
+      name: string;
+    }) => {
+      __$props = {
+        name,
+      };
+      __$element$0.initialize({
+        name: name,
+      });
+    },
+    update: __$update,
+    getRoot: () => __$root,
+  };
+};
+
+export const List = () => {
+  const __$element$2 = Palta.createComponent(Profile, []);
+  const __$element$1 = Palta.createComponent(Profile, []);
+  const __$element$0 = Palta.createFragment([__$element$1, __$element$2]);
+  let __$props = {};
+  const __$update = ({}) => {
+    __$props = {};
+  };
+  let __$root = __$element$0;
+
+  return {
+    slots: {},
+    initialize: ({}) => {
+      __$props = {};
+      __$element$1.initialize({
+        name: "first",
+      });
+      __$element$2.initialize({
+        name: "second",
+      });
+    },
+    update: __$update,
+    getRoot: () => __$root,
+  };
+};
@@ -0,0 +1,18 @@
+import { $state } from "palta";
+
+const __$root = "root";
+
+// @Palta.component
+const Component = ({ __$props }: { __$props: string }) => {
+  const [value, setValue] = $state(0);
+  const __$element$0 = "element";
+  const Palta = "runtime";
+
+  return (
+    <div onClick={() => setValue(value + 1)}>
+      {__$props} {__$element$0} {__$root} {Palta} {value}
+    </div>
+  );
+};
+
+export default Component;
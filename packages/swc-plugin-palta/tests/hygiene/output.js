@@ -0,0 +1,80 @@
+import Palta1 from "palta";
+
+const __$root = "root";
+
+const Component = () => {
+  const __$element$01 = Palta1.createElement("div", ["", "", "", "", ""]);
+  let __$props1 = {};
+  let value = 0;
+  const setValue = (value1) => {
+    value = typeof value1 === "function" ? value1(value) : value1;
+    Palta1.componentUpdate(() => __$update$0(__$props1));
+  };
+  const __$update = ({ __$props }) => {
+    __$props1 = {
+      __$props,
+    };
+    const __$element$0 = "element";
+    const Palta = "runtime";
+    __$element$01.updateChild(0, () => {
+      return __$props;
+    });
+    __$element$01.updateChild(1, () => {
+      return __$element$0;
+    });
+    __$element$01.updateChild(2, () => {
+      return __$root;
+    });
+    __$element$01.updateChild(3, () => {
+      return Palta;
+    });
+    __$element$01.updateChild(4, () => {
+      return value;
+    });
+    __$element$01.updateProps({
+      onClick: () => setValue(value + 1),
+    });
+  };
+  const __$update$0 = ({ __$props }) => {
+    __$element$01.updateChild(4, () => {
+      return value;
+    });
+    __$element$01.updateProps({
+      onClick: () => setValue(value + 1),
+    });
+  };
+  let __$root1 = __$element$01;
+
+  return {
+    slots: {},
+    initialize: ({ __$props }) => {
+      __$props1 = {
+        __$props,
+      };
+      const __$element$0 = "element";
+      const Palta = "runtime";
+      __$element$01.updateChild(0, () => {
+        return __$props;
+      });
+      __$element$01.updateChild(1, () => {
+        return __$element$0;
+      });
+      __$element$01.updateChild(2, () => {
+        return __$root;
+      });
+      __$element$01.updateChild(3, () => {
+        return Palta;
+      });
+      __$element$01.updateChild(4, () => {
+        return value;
+      });
+      __$element$01.initialize({
+        onClick: () => setValue(value + 1),
+      });
+    },
+    update: __$update,
+    getRoot: () => __$root1,
+  };
+};
+
+export default Component;
@@ -0,0 +1,13 @@
+import Palta1 from "palta";
+
+const __$root = "root";
+
+const Component = ({ __$props }) => {
+  const value = 0, setValue = () => {};
+  const __$element$0 = "element";
+  const Palta = "runtime";
+  return Palta1
+    .html`<div><!--[-->${__$props}<!--]--><!--[-->${__$element$0}<!--]--><!--[-->${__$root}<!--]--><!--[-->${Palta}<!--]--><!--[-->${value}<!--]--></div>`;
+};
+
+export default Component;
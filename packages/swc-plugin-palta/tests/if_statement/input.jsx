@@ -0,0 +1,16 @@
+// @Palta.component
+const Component = ({ user }) => {
+  if (!user) {
+    return <span>Loading...</span>;
+  }
+
+  const greeting = "Hello, " + user.name;
+
+  return (
+    <div>
+      <h1>{greeting}</h1>
+    </div>
+  );
+};
+
+export default Component;
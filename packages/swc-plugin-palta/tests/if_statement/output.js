@@ -0,0 +1,77 @@
+import Palta from "palta";
+
+const Component = () => {
+  let __$element$3;
+  let __$element$2;
+  let __$element$1;
+  const __$element$0 = Palta.createBranch([
+    () => __$element$1,
+    () => __$element$2,
+  ]);
+  let __$props = {};
+  const __$update = ({ user }) => {
+    __$props = {
+      user,
+    };
+    __$guard: {
+      if (!user) {
+        __$element$0.updateBranch(0, () => {
+          __$element$1 = Palta.createElement("span", ["Loading..."]);
+          __$element$1.initialize({});
+        }, () => {});
+        break __$guard;
+      }
+      const greeting = "Hello, " + user.name;
+      __$element$0.updateBranch(1, () => {
+        __$element$3 = Palta.createElement("h1", [""]);
+        __$element$2 = Palta.createElement("div", [__$element$3]);
+        __$element$3.updateChild(0, () => {
+          return greeting;
+        });
+        __$element$3.initialize({});
+        __$element$2.initialize({});
+      }, () => {
+        __$element$3.updateChild(0, () => {
+          return greeting;
+        });
+      });
+    }
+  };
+  let __$root = __$element$0;
+
+  return {
+    slots: {},
+    initialize: ({ user }) => {
+      __$props = {
+        user,
+      };
+      __$guard: {
+        if (!user) {
+          __$element$0.updateBranch(0, () => {
+            __$element$1 = Palta.createElement("span", ["Loading..."]);
+            __$element$1.initialize({});
+          }, () => {});
+          break __$guard;
+        }
+        const greeting = "Hello, " + user.name;
+        __$element$0.updateBranch(1, () => {
+          __$element$3 = Palta.createElement("h1", [""]);
+          __$element$2 = Palta.createElement("div", [__$element$3]);
+          __$element$3.updateChild(0, () => {
+            return greeting;
+          });
+          __$element$3.initialize({});
+          __$element$2.initialize({});
+        }, () => {
+          __$element$3.updateChild(0, () => {
+            return greeting;
+          });
+        });
+      }
+    },
+    update: __$update,
+    getRoot: () => __$root,
+  };
+};
+
+export default Component;
@@ -0,0 +1,11 @@
+import Palta from "palta";
+
+const Component = ({ user }) => {
+  if (!user) {
+    return Palta.html`<span>Loading...</span>`;
+  }
+  const greeting = "Hello, " + user.name;
+  return Palta.html`<div><h1><!--[-->${greeting}<!--]--></h1></div>`;
+};
+
+export default Component;
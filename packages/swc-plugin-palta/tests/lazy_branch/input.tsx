@@ -0,0 +1,15 @@
+// @Palta.component
+const Component = ({ tab, title }: { tab: number; title: string }) => {
+  return (
+    <div>
+      {title && <h1>{title}</h1>}
+      {tab === 0 ? (
+        <section>{title}</section>
+      ) : tab === 1 ? (
+        <p>Second</p>
+      ) : null}
+    </div>
+  );
+};
+
+export default Component;
@@ -0,0 +1,15 @@
+import Palta from "palta";
+
+const Component = ({ tab, title }) => {
+  return Palta.html`<div><!--[-->${
+    title && Palta.html`<h1><!--[-->${title}<!--]--></h1>`
+  }<!--]--><!--[-->${
+    tab === 0
+      ? Palta.html`<section><!--[-->${title}<!--]--></section>`
+      : tab === 1
+      ? Palta.html`<p>Second</p>`
+      : null
+  }<!--]--></div>`;
+};
+
+export default Component;
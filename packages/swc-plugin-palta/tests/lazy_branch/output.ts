@@ -0,0 +1,142 @@
+import Palta from "palta";
+
+const Component = (): Palta.ComponentDefinition<{
+  tab: number;
+  title: string;
+}> => {
+  let __$element$6: any;
+  let __$element$5: any;
+  const __$element$4 = Palta.createBranch([
+    () => __$element$5,
+    () => __$element$6,
+    () => null,
+  ]);
+  let __$element$3: any;
+  let __$element$2: any;
+  const __$element$1 = Palta.createBranch([
+    () => __$element$2,
+    () => __$element$3,
+  ]);
+  const __$element$0 = Palta.createElement("div", [__$element$1, __$element$4]);
+  let __$props: any = {};
+  const __$update = ({ tab, title }: {
+    tab: number;
+    title: string;
+  }) => {
+    __$props = {
+      tab,
+      title,
+    };
+    if (title) {
+      __$element$1.updateBranch(0, () => {
+        __$element$2 = Palta.createElement("h1", [""]);
+        __$element$2.updateChild(0, () => {
+          return title;
+        });
+        __$element$2.initialize({});
+      }, () => {
+        __$element$2.updateChild(0, () => {
+          return title;
+        });
+      });
+    } else {
+      __$element$1.updateBranch(1, () => {
+        __$element$3 = Palta.createFragment([""]);
+        __$element$3.updateChild(0, () => {
+          return title;
+        });
+      }, () => {
+        __$element$3.updateChild(0, () => {
+          return title;
+        });
+      });
+    }
+    if (tab === 0) {
+      __$element$4.updateBranch(0, () => {
+        __$element$5 = Palta.createElement("section", [""]);
+        __$element$5.updateChild(0, () => {
+          return title;
+        });
+        __$element$5.initialize({});
+      }, () => {
+        __$element$5.updateChild(0, () => {
+          return title;
+        });
+      });
+    } else {
+      if (tab === 1) {
+        __$element$4.updateBranch(1, () => {
+          __$element$6 = Palta.createElement("p", ["Second"]);
+          __$element$6.initialize({});
+        }, () => {});
+      } else {
+        __$element$4.updateBranch(2);
+      }
+    }
+  };
+  let __$root = __$element$0;
+
+  return {
+    slots: {},
+    initialize: ({ tab, title }: {
+      tab: number;
+      title: string;
+    }) => {
+      __$props = {
+        tab,
+        title,
+      };
+      if (title) {
+        __$element$1.updateBranch(0, () => {
+          __$element$2 = Palta.createElement("h1", [""]);
+          __$element$2.updateChild(0, () => {
+            return title;
+          });
+          __$element$2.initialize({});
+        }, () => {
+          __$element$2.updateChild(0, () => {
+            return title;
+          });
+        });
+      } else {
+        __$element$1.updateBranch(1, () => {
+          __$element$3 = Palta.createFragment([""]);
+          __$element$3.updateChild(0, () => {
+            return title;
+          });
+        }, () => {
+          __$element$3.updateChild(0, () => {
+            return title;
+          });
+        });
+      }
+      if (tab === 0) {
+        __$element$4.updateBranch(0, () => {
+          __$element$5 = Palta.createElement("section", [""]);
+          __$element$5.updateChild(0, () => {
+            return title;
+          });
+          __$element$5.initialize({});
+        }, () => {
+          __$element$5.updateChild(0, () => {
+            return title;
+          });
+        });
+      } else {
+        if (tab === 1) {
+          __$element$4.updateBranch(1, () => {
+            __$element$6 = Palta.createElement("p", ["Second"]);
+            __$element$6.initialize({});
+          }, () => {});
+        } else {
+          __$element$4.updateBranch(2);
+        }
+      }
+      __$element$0.initialize({});
+    },
+    update: __$update,
+    getRoot: () => __$root,
+  };
+};
+
+export default Component;
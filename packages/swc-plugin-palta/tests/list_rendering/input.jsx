@@ -0,0 +1,14 @@
+// @Palta.component
+const Component = ({ title, items }) => {
+  return (
+    <ul>
+      {items.map((item) => (
+        <li key={item.id}>
+          {title}: {item.name}
+        </li>
+      ))}
+    </ul>
+  );
+};
+
+export default Component;
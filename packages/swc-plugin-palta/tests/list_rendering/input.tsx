@@ -0,0 +1,20 @@
+// @Palta.component
+const Component = ({
+  title,
+  items,
+}: {
+  title: string;
+  items: { id: number; name: string }[];
+}) => {
+  return (
+    <ul>
+      {items.map((item) => (
+        <li key={item.id}>
+          {title}: {item.name}
+        </li>
+      ))}
+    </ul>
+  );
+};
+
+export default Component;
@@ -0,0 +1,67 @@
+import Palta from "palta";
+
+const Component = () => {
+  const __$list$0 = Palta.createList(() => {
+    const __$element$0 = Palta.createElement("li", ["", ": ", ""]);
+    let __$props = {};
+    const __$update = ([item, title]) => {
+      __$props = [item, title];
+      __$element$0.updateChild(0, () => {
+        return title;
+      });
+      __$element$0.updateChild(2, () => {
+        return item.name;
+      });
+    };
+    let __$root = __$element$0;
+    return {
+      slots: {},
+      initialize: ([item, title]) => {
+        __$props = [item, title];
+        __$element$0.updateChild(0, () => {
+          return title;
+        });
+        __$element$0.updateChild(2, () => {
+          return item.name;
+        });
+        __$element$0.initialize({});
+      },
+      update: __$update,
+      getRoot: () => __$root,
+    };
+  });
+  const __$element$0 = Palta.createElement("ul", [""]);
+  let __$props = {};
+  const __$update = ({ title, items }) => {
+    __$props = {
+      title,
+      items,
+    };
+    __$element$0.updateChild(0, () => {
+      return __$list$0.collect(() =>
+        items.map((item) => __$list$0.render([item, title], item.id))
+      );
+    });
+  };
+  let __$root = __$element$0;
+
+  return {
+    slots: {},
+    initialize: ({ title, items }) => {
+      __$props = {
+        title,
+        items,
+      };
+      __$element$0.updateChild(0, () => {
+        return __$list$0.collect(() =>
+          items.map((item) => __$list$0.render([item, title], item.id))
+        );
+      });
+      __$element$0.initialize({});
+    },
+    update: __$update,
+    getRoot: () => __$root,
+  };
+};
+
+export default Component;
@@ -0,0 +1,12 @@
+import Palta from "palta";
+
+const Component = ({ title, items }) => {
+  return Palta.html`<ul><!--[-->${
+    items.map((item) =>
+      Palta
+        .html`<li><!--[-->${title}<!--]-->: <!--[-->${item.name}<!--]--></li>`
+    )
+  }<!--]--></ul>`;
+};
+
+export default Component;
@@ -0,0 +1,85 @@
+import Palta from "palta";
+
+const Component = (): Palta.ComponentDefinition<{
+  title: string;
+  items: {
+    id: number;
+    name: string;
+  }[];
+}> => {
+  const __$list$0 = Palta.createList((): Palta.ComponentDefinition<any> => {
+    const __$element$0 = Palta.createElement("li", ["", ": ", ""]);
+    let __$props: any = {};
+    const __$update = ([item, title]: any) => {
+      __$props = [item, title];
+      __$element$0.updateChild(0, () => {
+        return title;
+      });
+      __$element$0.updateChild(2, () => {
+        return item.name;
+      });
+    };
+    let __$root = __$element$0;
+    return {
+      slots: {},
+      initialize: ([item, title]: any) => {
+        __$props = [item, title];
+        __$element$0.updateChild(0, () => {
+          return title;
+        });
+        __$element$0.updateChild(2, () => {
+          return item.name;
+        });
+        __$element$0.initialize({});
+      },
+      update: __$update,
+      getRoot: () => __$root,
+    };
+  });
+  const __$element$0 = Palta.createElement("ul", [""]);
+  let __$props: any = {};
+  const __$update = ({ title, items }: {
+    title: string;
+    items: {
+      id: number;
+      name: string;
+    }[];
+  }) => {
+    __$props = {
+      title,
+      items,
+    };
+    __$element$0.updateChild(0, () => {
+      return __$list$0.collect(() =>
+        items.map((item) => __$list$0.render([item, title], item.id))
+      );
+    });
+  };
+  let __$root = __$element$0;
+
+  return {
+    slots: {},
+    initialize: ({ title, items }: {
+      title: string;
+      items: {
+        id: number;
+        name: string;
+      }[];
+    }) => {
+      __$props = {
+        title,
+        items,
+      };
+      __$element$0.updateChild(0, () => {
+        return __$list$0.collect(() =>
+          items.map((item) => __$list$0.render([item, title], item.id))
+        );
+      });
+      __$element$0.initialize({});
+    },
+    update: __$update,
+    getRoot: () => __$root,
+  };
+};
+
+export default Component;
@@ -0,0 +1,26 @@
+import Palta from "palta";
+
+/* @Palta.component */
+const Title = ({ text }) => <h1>{text}</h1>;
+
+/**
+ * Displays the user name.
+ *
+ * @component
+ */
+function Name({ name }) {
+  return <span>{name}</span>;
+}
+
+// @Palta.component
+export const Greeting = ({ name }) => (
+  <p>
+    <Title text="Hello" />
+    <Name name={name} />
+  </p>
+);
+
+export const Footer = Palta.component(() => <footer>Footer</footer>);
+
+/** Not a component */
+const label = () => <span>Label</span>;
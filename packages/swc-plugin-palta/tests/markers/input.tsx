@@ -0,0 +1,26 @@
+import Palta from "palta";
+
+/* @Palta.component */
+const Title = ({ text }: { text: string }) => <h1>{text}</h1>;
+
+/**
+ * Displays the user name.
+ *
+ * @component
+ */
+function Name({ name }: { name: string }) {
+  return <span>{name}</span>;
+}
+
+// @Palta.component
+export const Greeting = ({ name }: { name: string }) => (
+  <p>
+    <Title text="Hello" />
+    <Name name={name} />
+  </p>
+);
+
+export const Footer = Palta.component(() => <footer>Footer</footer>);
+
+/** Not a component */
+const label = () => <span>Label</span>;
@@ -0,0 +1,114 @@
+import Palta from "palta";
+
+const Title = () => {
+  const __$element$0 = Palta.createElement("h1", [""]);
+  let __$props = {};
+  const __$update = ({ text }) => {
+    __$props = {
+      text,
+    };
+    __$element$0.updateChild(0, () => {
+      return text;
+    });
+  };
+  let __$root = __$element$0;
+
+  return {
+    slots: {},
+    initialize: ({ text }) => {
+      __$props = {
+        text,
+      };
+      __$element$0.updateChild(0, () => {
+        return text;
+      });
+      __$element$0.initialize({});
+    },
+    update: __$update,
+    getRoot: () => __$root,
+  };
+};
+
+function Name() {
+  const __$element$0 = Palta.createElement("span", [""]);
+  let __$props = {};
+  const __$update = ({ name }) => {
+    __$props = {
+      name,
+    };
+    __$element$0.updateChild(0, () => {
+      return name;
+    });
+  };
+  let __$root = __$element$0;
+
+  return {
+    slots: {},
+    initialize: ({ name }) => {
+      __$props = {
+        name,
+      };
+      __$element$0.updateChild(0, () => {
+        return name;
+      });
+      __$element$0.initialize({});
+    },
+    update: __$update,
+    getRoot: () => __$root,
+  };
+}
+
+export const Greeting = () => {
+  const __$element$2 = Palta.createComponent(Name, []);
+  const __$element$1 = Palta.createComponent(Title, []);
+  const __$element$0 = Palta.createElement("p", [__$element$1, __$element$2]);
+  let __$props = {};
+  const __$update = ({ name }) => {
+    __$props = {
+      name,
+    };
+    __$element$2.updateProps({
+      name: name,
+    });
+  };
+  let __$root = __$element$0;
+
+  return {
+    slots: {},
+    initialize: ({ name }) => {
+      __$props = {
+        name,
+      };
+      __$element$1.initialize({
+        text: "Hello",
+      });
+      __$element$2.initialize({
+        name: name,
+      });
+      __$element$0.initialize({});
+    },
+    update: __$update,
+    getRoot: () => __$root,
+  };
+};
+
+export const Footer = () => {
+  const __$element$0 = Palta.createElement("footer", ["Footer"]);
+  let __$props = {};
+  const __$update = ({}) => {
+    __$props = {};
+  };
+  let __$root = __$element$0;
+
+  return {
+    slots: {},
+    initialize: ({}) => {
+      __$props = {};
+      __$element$0.initialize({});
+    },
+    update: __$update,
+    getRoot: () => __$root,
+  };
+};
+
+/** Not a component */ const label = () => <span>Label</span>;
@@ -0,0 +1,22 @@
+import Palta from "palta";
+
+const Title = ({ text }) => Palta.html`<h1><!--[-->${text}<!--]--></h1>`;
+
+function Name({ name }) {
+  return Palta.html`<span><!--[-->${name}<!--]--></span>`;
+}
+
+export const Greeting = ({ name }) =>
+  Palta.html`<p><!--[-->${
+    Palta.renderComponent(Title, {
+      text: "Hello",
+    })
+  }<!--]--><!--[-->${
+    Palta.renderComponent(Name, {
+      name: name,
+    })
+  }<!--]--></p>`;
+
+export const Footer = () => Palta.html`<footer>Footer</footer>`;
+
+/** Not a component */ const label = () => <span>Label</span>;
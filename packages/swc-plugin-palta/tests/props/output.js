@@ -14,7 +14,7 @@ const Component = () => {
   let __$root = __$element$0;
 
   return {
-    childrenElement: null,
+    slots: {},
     initialize: ({ name, className }) => {
       __$props = { name, className };
       __$element$1.updateChild(1, () => {
@@ -0,0 +1,9 @@
+import Palta from "palta";
+
+const Component = ({ name, className }) => {
+  return Palta.html`<div><h1${
+    Palta.attribute("className", className)
+  }>Hello, <!--[-->${name}<!--]-->!</h1></div>`;
+};
+
+export default Component;
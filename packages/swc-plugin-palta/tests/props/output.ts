@@ -23,7 +23,7 @@ const Component = (): Palta.ComponentDefinition<{
   let __$root = __$element$0;
 
   return {
-    childrenElement: null,
+    slots: {},
     initialize: ({ name, className }: { name: string; className: string }) => {
       __$props = { name, className };
       __$element$1.updateChild(1, () => {
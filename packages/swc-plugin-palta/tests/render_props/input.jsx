@@ -0,0 +1,36 @@
+import Palta, { $children, $state } from "palta";
+
+// @Palta.component
+const DataLoader = ({ url }) => {
+  const [data, setData] = $state([]);
+
+  return (
+    <section>
+      <h2>{url}</h2>
+      {$children(data, data.length)}
+    </section>
+  );
+};
+
+// @Palta.component
+const Row = ({ value }) => {
+  return <li>{value}</li>;
+};
+
+// @Palta.component
+const Table = ({ title }) => {
+  return (
+    <DataLoader url="/rows" empty={() => <p>No rows</p>}>
+      {(rows, count) => (
+        <ul title={title}>
+          {count}
+          {rows.map((row) => (
+            <Row key={row} value={row} />
+          ))}
+        </ul>
+      )}
+    </DataLoader>
+  );
+};
+
+export default Table;
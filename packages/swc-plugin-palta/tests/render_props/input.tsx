@@ -0,0 +1,36 @@
+import Palta, { $children, $state } from "palta";
+
+// @Palta.component
+const DataLoader = ({ url }: { url: string }) => {
+  const [data, setData] = $state<string[]>([]);
+
+  return (
+    <section>
+      <h2>{url}</h2>
+      {$children(data, data.length)}
+    </section>
+  );
+};
+
+// @Palta.component
+const Row = ({ value }: { value: string }) => {
+  return <li>{value}</li>;
+};
+
+// @Palta.component
+const Table = ({ title }: { title: string }) => {
+  return (
+    <DataLoader url="/rows" empty={() => <p>No rows</p>}>
+      {(rows: string[], count: number) => (
+        <ul title={title}>
+          {count}
+          {rows.map((row) => (
+            <Row key={row} value={row} />
+          ))}
+        </ul>
+      )}
+    </DataLoader>
+  );
+};
+
+export default Table;
@@ -0,0 +1,210 @@
+import Palta from "palta";
+
+const DataLoader = () => {
+  const __$element$2 = Palta.createChildren();
+  const __$element$1 = Palta.createElement("h2", [""]);
+  const __$element$0 = Palta.createElement("section", [__$element$1, ""]);
+  let __$props = {};
+  let data = [];
+  const setData = (value1) => {
+    data = typeof value1 === "function" ? value1(data) : value1;
+    Palta.componentUpdate(() => __$update$0(__$props));
+  };
+  const __$update = ({ url }) => {
+    __$props = {
+      url,
+    };
+    __$element$1.updateChild(0, () => {
+      return url;
+    });
+    __$element$0.updateChild(1, () => {
+      return __$element$2.collect(
+        0,
+        () => __$element$2.render(0, data, data.length)
+      );
+    });
+  };
+  const __$update$0 = ({ url }) => {
+    __$element$0.updateChild(1, () => {
+      return __$element$2.collect(
+        0,
+        () => __$element$2.render(0, data, data.length)
+      );
+    });
+  };
+  let __$root = __$element$0;
+
+  return {
+    slots: {
+      children: __$element$2,
+    },
+    initialize: ({ url }) => {
+      __$props = {
+        url,
+      };
+      __$element$1.updateChild(0, () => {
+        return url;
+      });
+      __$element$1.initialize({});
+      __$element$0.updateChild(1, () => {
+        return __$element$2.collect(
+          0,
+          () => __$element$2.render(0, data, data.length)
+        );
+      });
+      __$element$0.initialize({});
+    },
+    update: __$update,
+    getRoot: () => __$root,
+  };
+};
+
+const Row = () => {
+  const __$element$0 = Palta.createElement("li", [""]);
+  let __$props = {};
+  const __$update = ({ value }) => {
+    __$props = {
+      value,
+    };
+    __$element$0.updateChild(0, () => {
+      return value;
+    });
+  };
+  let __$root = __$element$0;
+
+  return {
+    slots: {},
+    initialize: ({ value }) => {
+      __$props = {
+        value,
+      };
+      __$element$0.updateChild(0, () => {
+        return value;
+      });
+      __$element$0.initialize({});
+    },
+    update: __$update,
+    getRoot: () => __$root,
+  };
+};
+
+const Table = () => {
+  const __$list$1 = () => {
+    const __$list$0 = Palta.createList(() => {
+      const __$element$0 = Palta.createComponent(Row, []);
+      let __$props = {};
+      const __$update = ([row]) => {
+        __$props = [row];
+        __$element$0.updateProps({
+          value: row,
+        });
+      };
+      let __$root = __$element$0;
+      return {
+        slots: {},
+        initialize: ([row]) => {
+          __$props = [row];
+          __$element$0.initialize({
+            value: row,
+          });
+        },
+        update: __$update,
+        getRoot: () => __$root,
+      };
+    });
+    const __$element$0 = Palta.createElement("ul", ["", ""]);
+    let __$props = {};
+    const __$update = ([rows, count, title]) => {
+      __$props = [rows, count, title];
+      __$element$0.updateChild(0, () => {
+        return count;
+      });
+      __$element$0.updateChild(1, () => {
+        return __$list$0.collect(() =>
+          rows.map((row) => __$list$0.render([row], row))
+        );
+      });
+      __$element$0.updateProps({
+        title: title,
+      });
+    };
+    let __$root = __$element$0;
+    return {
+      slots: {},
+      initialize: ([rows, count, title]) => {
+        __$props = [rows, count, title];
+        __$element$0.updateChild(0, () => {
+          return count;
+        });
+        __$element$0.updateChild(1, () => {
+          return __$list$0.collect(() =>
+            rows.map((row) => __$list$0.render([row], row))
+          );
+        });
+        __$element$0.initialize({
+          title: title,
+        });
+      },
+      update: __$update,
+      getRoot: () => __$root,
+    };
+  };
+  const __$list$0 = () => {
+    const __$element$0 = Palta.createElement("p", ["No rows"]);
+    let __$props = {};
+    const __$update = ([]) => {
+      __$props = [];
+    };
+    let __$root = __$element$0;
+    return {
+      slots: {},
+      initialize: ([]) => {
+        __$props = [];
+        __$element$0.initialize({});
+      },
+      update: __$update,
+      getRoot: () => __$root,
+    };
+  };
+  const __$element$0 = Palta.createComponent(DataLoader, [""]);
+  let __$props = {};
+  const __$update = ({ title }) => {
+    __$props = {
+      title,
+    };
+    __$element$0.updateChild(0, () => {
+      return Palta.createRenderProp(
+        __$list$1,
+        (rows, count) => [rows, count, title]
+      );
+    });
+    __$element$0.updateProps({
+      url: "/rows",
+      empty: Palta.createRenderProp(__$list$0, () => []),
+    });
+  };
+  let __$root = __$element$0;
+
+  return {
+    slots: {},
+    initialize: ({ title }) => {
+      __$props = {
+        title,
+      };
+      __$element$0.updateChild(0, () => {
+        return Palta.createRenderProp(
+          __$list$1,
+          (rows, count) => [rows, count, title]
+        );
+      });
+      __$element$0.initialize({
+        url: "/rows",
+        empty: Palta.createRenderProp(__$list$0, () => []),
+      });
+    },
+    update: __$update,
+    getRoot: () => __$root,
+  };
+};
+
+export default Table;
@@ -0,0 +1,37 @@
+import Palta from "palta";
+
+const DataLoader = ({ url }, __$children, __$slots) => {
+  const data = [], setData = () => {};
+  return Palta.html`<section><h2><!--[-->${url}<!--]--></h2><!--[-->${
+    Palta.renderChildren(__$slots, data, data.length)
+  }<!--]--></section>`;
+};
+
+const Row = ({ value }) => {
+  return Palta.html`<li><!--[-->${value}<!--]--></li>`;
+};
+
+const Table = ({ title }) => {
+  return Palta.renderComponent(
+    DataLoader,
+    {
+      url: "/rows",
+      empty: () => Palta.html`<p>No rows</p>`,
+    },
+    Palta.html``,
+    {
+      $children: (rows, count) =>
+        Palta.html`<ul${
+          Palta.attribute("title", title)
+        }><!--[-->${count}<!--]--><!--[-->${
+          rows.map((row) =>
+            Palta.renderComponent(Row, {
+              value: row,
+            })
+          )
+        }<!--]--></ul>`,
+    }
+  );
+};
+
+export default Table;
@@ -0,0 +1,230 @@
+import Palta from "palta";
+
+const DataLoader = (): Palta.ComponentDefinition<{
+  url: string;
+}> => {
+  const __$element$2 = Palta.createChildren();
+  const __$element$1 = Palta.createElement("h2", [""]);
+  const __$element$0 = Palta.createElement("section", [__$element$1, ""]);
+  let __$props: any = {};
+  let data = [];
+  const setData: Palta.StateUpdater<any> = (value1) => {
+    data = typeof value1 === "function" ? value1(data) : value1;
+    Palta.componentUpdate(() => __$update$0(__$props));
+  };
+  const __$update = ({ url }: {
+    url: string;
+  }) => {
+    __$props = {
+      url,
+    };
+    __$element$1.updateChild(0, () => {
+      return url;
+    });
+    __$element$0.updateChild(1, () => {
+      return __$element$2.collect(
+        0,
+        () => __$element$2.render(0, data, data.length)
+      );
+    });
+  };
+  const __$update$0 = ({ url }: {
+    url: string;
+  }) => {
+    __$element$0.updateChild(1, () => {
+      return __$element$2.collect(
+        0,
+        () => __$element$2.render(0, data, data.length)
+      );
+    });
+  };
+  let __$root = __$element$0;
+
+  return {
+    slots: {
+      children: __$element$2,
+    },
+    initialize: ({ url }: {
+      url: string;
+    }) => {
+      __$props = {
+        url,
+      };
+      __$element$1.updateChild(0, () => {
+        return url;
+      });
+      __$element$1.initialize({});
+      __$element$0.updateChild(1, () => {
+        return __$element$2.collect(
+          0,
+          () => __$element$2.render(0, data, data.length)
+        );
+      });
+      __$element$0.initialize({});
+    },
+    update: __$update,
+    getRoot: () => __$root,
+  };
+};
+
+const Row = (): Palta.ComponentDefinition<{
+  value: string;
+}> => {
+  const __$element$0 = Palta.createElement("li", [""]);
+  let __$props: any = {};
+  const __$update = ({ value }: {
+    value: string;
+  }) => {
+    __$props = {
+      value,
+    };
+    __$element$0.updateChild(0, () => {
+      return value;
+    });
+  };
+  let __$root = __$element$0;
+
+  return {
+    slots: {},
+    initialize: ({ value }: {
+      value: string;
+    }) => {
+      __$props = {
+        value,
+      };
+      __$element$0.updateChild(0, () => {
+        return value;
+      });
+      __$element$0.initialize({});
+    },
+    update: __$update,
+    getRoot: () => __$root,
+  };
+};
+
+const Table = (): Palta.ComponentDefinition<{
+  title: string;
+}> => {
+  const __$list$1 = (): Palta.ComponentDefinition<any> => {
+    const __$list$0 = Palta.createList((): Palta.ComponentDefinition<any> => {
+      const __$element$0 = Palta.createComponent(Row, []);
+      let __$props: any = {};
+      const __$update = ([row]: any) => {
+        __$props = [row];
+        __$element$0.updateProps({
+          value: row,
+        });
+      };
+      let __$root = __$element$0;
+      return {
+        slots: {},
+        initialize: ([row]: any) => {
+          __$props = [row];
+          __$element$0.initialize({
+            value: row,
+          });
+        },
+        update: __$update,
+        getRoot: () => __$root,
+      };
+    });
+    const __$element$0 = Palta.createElement("ul", ["", ""]);
+    let __$props: any = {};
+    const __$update = ([rows, count, title]: any) => {
+      __$props = [rows, count, title];
+      __$element$0.updateChild(0, () => {
+        return count;
+      });
+      __$element$0.updateChild(1, () => {
+        return __$list$0.collect(() =>
+          rows.map((row) => __$list$0.render([row], row))
+        );
+      });
+      __$element$0.updateProps({
+        title: title,
+      });
+    };
+    let __$root = __$element$0;
+    return {
+      slots: {},
+      initialize: ([rows, count, title]: any) => {
+        __$props = [rows, count, title];
+        __$element$0.updateChild(0, () => {
+          return count;
+        });
+        __$element$0.updateChild(1, () => {
+          return __$list$0.collect(() =>
+            rows.map((row) => __$list$0.render([row], row))
+          );
+        });
+        __$element$0.initialize({
+          title: title,
+        });
+      },
+      update: __$update,
+      getRoot: () => __$root,
+    };
+  };
+  const __$list$0 = (): Palta.ComponentDefinition<any> => {
+    const __$element$0 = Palta.createElement("p", ["No rows"]);
+    let __$props: any = {};
+    const __$update = ([]: any) => {
+      __$props = [];
+    };
+    let __$root = __$element$0;
+    return {
+      slots: {},
+      initialize: ([]: any) => {
+        __$props = [];
+        __$element$0.initialize({});
+      },
+      update: __$update,
+      getRoot: () => __$root,
+    };
+  };
+  const __$element$0 = Palta.createComponent(DataLoader, [""]);
+  let __$props: any = {};
+  const __$update = ({ title }: {
+    title: string;
+  }) => {
+    __$props = {
+      title,
+    };
+    __$element$0.updateChild(0, () => {
+      return Palta.createRenderProp(
+        __$list$1,
+        (rows: string[], count: number) => [rows, count, title]
+      );
+    });
+    __$element$0.updateProps({
+      url: "/rows",
+      empty: Palta.createRenderProp(__$list$0, () => []),
+    });
+  };
+  let __$root = __$element$0;
+
+  return {
+    slots: {},
+    initialize: ({ title }: {
+      title: string;
+    }) => {
+      __$props = {
+        title,
+      };
+      __$element$0.updateChild(0, () => {
+        return Palta.createRenderProp(
+          __$list$1,
+          (rows: string[], count: number) => [rows, count, title]
+        );
+      });
+      __$element$0.initialize({
+        url: "/rows",
+        empty: Palta.createRenderProp(__$list$0, () => []),
+      });
+    },
+    update: __$update,
+    getRoot: () => __$root,
+  };
+};
+
+export default Table;
@@ -0,0 +1,14 @@
+// @Palta.component
+const Component = ({ user }: { user: string | null }) => {
+  let content = <p>Anonymous</p>;
+
+  if (user) {
+    const greeting = "Hello, " + user;
+
+    content = <p>{greeting}!</p>;
+  }
+
+  return content;
+};
+
+export default Component;
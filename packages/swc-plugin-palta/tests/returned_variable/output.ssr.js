@@ -0,0 +1,12 @@
+import Palta from "palta";
+
+const Component = ({ user }) => {
+  let content = Palta.html`<p>Anonymous</p>`;
+  if (user) {
+    const greeting = "Hello, " + user;
+    content = Palta.html`<p><!--[-->${greeting}<!--]-->!</p>`;
+  }
+  return content;
+};
+
+export default Component;
@@ -0,0 +1,73 @@
+import Palta from "palta";
+
+const Component = (): Palta.ComponentDefinition<{
+  user: string | null;
+}> => {
+  const __$element$3 = Palta.createFragment([""]);
+  let __$element$2: any;
+  const __$element$1 = Palta.createBranch([() => __$element$2]);
+  const __$element$0 = Palta.createElement("p", ["Anonymous"]);
+  let __$props: any = {};
+  const __$update = ({ user }: {
+    user: string | null;
+  }) => {
+    __$props = {
+      user,
+    };
+    let content = __$element$0;
+    if (user) {
+      const greeting = "Hello, " + user;
+      __$element$1.updateBranch(0, () => {
+        __$element$2 = Palta.createElement("p", ["", "!"]);
+        __$element$2.updateChild(0, () => {
+          return greeting;
+        });
+        __$element$2.initialize({});
+      }, () => {
+        __$element$2.updateChild(0, () => {
+          return greeting;
+        });
+      });
+      content = __$element$2;
+    }
+    __$element$3.updateChild(0, () => {
+      return content;
+    });
+  };
+  let __$root = __$element$3;
+
+  return {
+    slots: {},
+    initialize: ({ user }: {
+      user: string | null;
+    }) => {
+      __$props = {
+        user,
+      };
+      __$element$0.initialize({});
+      let content = __$element$0;
+      if (user) {
+        const greeting = "Hello, " + user;
+        __$element$1.updateBranch(0, () => {
+          __$element$2 = Palta.createElement("p", ["", "!"]);
+          __$element$2.updateChild(0, () => {
+            return greeting;
+          });
+          __$element$2.initialize({});
+        }, () => {
+          __$element$2.updateChild(0, () => {
+            return greeting;
+          });
+        });
+        content = __$element$2;
+      }
+      __$element$3.updateChild(0, () => {
+        return content;
+      });
+    },
+    update: __$update,
+    getRoot: () => __$root,
+  };
+};
+
+export default Component;
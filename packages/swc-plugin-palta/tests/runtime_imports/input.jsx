@@ -0,0 +1,31 @@
+import { $state as useState, $effect } from "palta";
+import * as P from "palta";
+import Children from "./Children";
+
+// @Palta.component
+export const Counter = () => {
+  const [count, setCount] = useState(0);
+
+  P.$effect(() => console.log(count), [count]);
+
+  return <button onClick={() => setCount(count + 1)}>{count}</button>;
+};
+
+// @Palta.component
+export const Layout = () => (
+  <section>
+    <P.Children />
+  </section>
+);
+
+// @Palta.component
+export const Wrapper = () => <Children title="Title" />;
+
+// @Palta.component
+export const Logger = () => {
+  const $effect = (message) => console.log(message);
+
+  $effect("Rendered");
+
+  return <p>Logger</p>;
+};
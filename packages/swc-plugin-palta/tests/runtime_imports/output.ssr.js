@@ -0,0 +1,24 @@
+import Palta from "palta";
+
+import * as P from "palta";
+
+import Children from "./Children";
+
+export const Counter = () => {
+  const count = 0, setCount = () => {};
+  return Palta.html`<button><!--[-->${count}<!--]--></button>`;
+};
+
+export const Layout = ({}, __$children) =>
+  Palta.html`<section><!--[-->${__$children}<!--]--></section>`;
+
+export const Wrapper = () =>
+  Palta.renderComponent(Children, {
+    title: "Title",
+  });
+
+export const Logger = () => {
+  const $effect = (message) => console.log(message);
+  $effect("Rendered");
+  return Palta.html`<p>Logger</p>`;
+};
@@ -0,0 +1,130 @@
+import Palta from "palta";
+
+import * as P from "palta";
+
+import Children from "./Children";
+
+export const Counter = () => {
+  const __$element$0 = Palta.createElement("button", [""]);
+  let __$props = {};
+  let count = 0;
+  const setCount = (value) => {
+    count = typeof value === "function" ? value(count) : value;
+    Palta.componentUpdate(() => __$update$0(__$props));
+  };
+  const __$effect$0 = {
+    deps: null,
+    cleanup: null,
+  };
+  const __$update = ({}) => {
+    __$props = {};
+    __$element$0.updateChild(0, () => {
+      return count;
+    });
+    __$element$0.updateProps({
+      onClick: () => setCount(count + 1),
+    });
+    Palta.runEffect(__$effect$0, () => {
+      return console.log(count);
+    }, [count]);
+  };
+  const __$update$0 = ({}) => {
+    __$element$0.updateChild(0, () => {
+      return count;
+    });
+    __$element$0.updateProps({
+      onClick: () => setCount(count + 1),
+    });
+    Palta.runEffect(__$effect$0, () => {
+      return console.log(count);
+    }, [count]);
+  };
+  let __$root = __$element$0;
+
+  return {
+    slots: {},
+    initialize: ({}) => {
+      __$props = {};
+      Palta.mountEffect(__$effect$0, () => {
+        return console.log(count);
+      }, [count]);
+      __$element$0.updateChild(0, () => {
+        return count;
+      });
+      __$element$0.initialize({
+        onClick: () => setCount(count + 1),
+      });
+    },
+    update: __$update,
+    getRoot: () => __$root,
+    unmount: () => {
+      Palta.cleanupEffect(__$effect$0);
+    },
+  };
+};
+
+export const Layout = () => {
+  const __$element$1 = Palta.createChildren();
+  const __$element$0 = Palta.createElement("section", [__$element$1]);
+  let __$props = {};
+  const __$update = ({}) => {
+    __$props = {};
+  };
+  let __$root = __$element$0;
+
+  return {
+    slots: {
+      children: __$element$1,
+    },
+    initialize: ({}) => {
+      __$props = {};
+      __$element$0.initialize({});
+    },
+    update: __$update,
+    getRoot: () => __$root,
+  };
+};
+
+export const Wrapper = () => {
+  const __$element$0 = Palta.createComponent(Children, []);
+  let __$props = {};
+  const __$update = ({}) => {
+    __$props = {};
+  };
+  let __$root = __$element$0;
+
+  return {
+    slots: {},
+    initialize: ({}) => {
+      __$props = {};
+      __$element$0.initialize({
+        title: "Title",
+      });
+    },
+    update: __$update,
+    getRoot: () => __$root,
+  };
+};
+
+export const Logger = () => {
+  const __$element$0 = Palta.createElement("p", ["Logger"]);
+  let __$props = {};
+  const __$update = ({}) => {
+    __$props = {};
+    const $effect = (message: string) => console.log(message);
+    $effect("Rendered");
+  };
+  let __$root = __$element$0;
+
+  return {
+    slots: {},
+    initialize: ({}) => {
+      __$props = {};
+      const $effect = (message: string) => console.log(message);
+      $effect("Rendered");
+      __$element$0.initialize({});
+    },
+    update: __$update,
+    getRoot: () => __$root,
+  };
+};
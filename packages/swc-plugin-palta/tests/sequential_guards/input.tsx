@@ -0,0 +1,28 @@
+type Props = { a: boolean; b: boolean; c: boolean; d: boolean; e: boolean };
+
+// @Palta.component
+const Component = ({ a, b, c, d, e }: Props) => {
+  if (a) {
+    return <p>a</p>;
+  }
+
+  if (b) {
+    return <p>b</p>;
+  }
+
+  if (c) {
+    return <p>c</p>;
+  }
+
+  if (d) {
+    return <p>d</p>;
+  }
+
+  if (e) {
+    return <p>e</p>;
+  }
+
+  return <div>Ready</div>;
+};
+
+export default Component;
@@ -0,0 +1,128 @@
+import Palta from "palta";
+
+const Component = () => {
+  let __$element$6;
+  let __$element$5;
+  let __$element$4;
+  let __$element$3;
+  let __$element$2;
+  let __$element$1;
+  const __$element$0 = Palta.createBranch([
+    () => __$element$1,
+    () => __$element$2,
+    () => __$element$3,
+    () => __$element$4,
+    () => __$element$5,
+    () => __$element$6,
+  ]);
+  let __$props = {};
+  const __$update = ({ a, b, c, d, e }) => {
+    __$props = {
+      a,
+      b,
+      c,
+      d,
+      e,
+    };
+    __$guard: {
+      if (a) {
+        __$element$0.updateBranch(0, () => {
+          __$element$1 = Palta.createElement("p", ["a"]);
+          __$element$1.initialize({});
+        }, () => {});
+        break __$guard;
+      }
+      if (b) {
+        __$element$0.updateBranch(1, () => {
+          __$element$2 = Palta.createElement("p", ["b"]);
+          __$element$2.initialize({});
+        }, () => {});
+        break __$guard;
+      }
+      if (c) {
+        __$element$0.updateBranch(2, () => {
+          __$element$3 = Palta.createElement("p", ["c"]);
+          __$element$3.initialize({});
+        }, () => {});
+        break __$guard;
+      }
+      if (d) {
+        __$element$0.updateBranch(3, () => {
+          __$element$4 = Palta.createElement("p", ["d"]);
+          __$element$4.initialize({});
+        }, () => {});
+        break __$guard;
+      }
+      if (e) {
+        __$element$0.updateBranch(4, () => {
+          __$element$5 = Palta.createElement("p", ["e"]);
+          __$element$5.initialize({});
+        }, () => {});
+        break __$guard;
+      }
+      __$element$0.updateBranch(5, () => {
+        __$element$6 = Palta.createElement("div", ["Ready"]);
+        __$element$6.initialize({});
+      }, () => {});
+    }
+  };
+  let __$root = __$element$0;
+
+  return {
+    slots: {},
+    initialize: ({ a, b, c, d, e }) => {
+      __$props = {
+        a,
+        b,
+        c,
+        d,
+        e,
+      };
+      __$guard: {
+        if (a) {
+          __$element$0.updateBranch(0, () => {
+            __$element$1 = Palta.createElement("p", ["a"]);
+            __$element$1.initialize({});
+          }, () => {});
+          break __$guard;
+        }
+        if (b) {
+          __$element$0.updateBranch(1, () => {
+            __$element$2 = Palta.createElement("p", ["b"]);
+            __$element$2.initialize({});
+          }, () => {});
+          break __$guard;
+        }
+        if (c) {
+          __$element$0.updateBranch(2, () => {
+            __$element$3 = Palta.createElement("p", ["c"]);
+            __$element$3.initialize({});
+          }, () => {});
+          break __$guard;
+        }
+        if (d) {
+          __$element$0.updateBranch(3, () => {
+            __$element$4 = Palta.createElement("p", ["d"]);
+            __$element$4.initialize({});
+          }, () => {});
+          break __$guard;
+        }
+        if (e) {
+          __$element$0.updateBranch(4, () => {
+            __$element$5 = Palta.createElement("p", ["e"]);
+            __$element$5.initialize({});
+          }, () => {});
+          break __$guard;
+        }
+        __$element$0.updateBranch(5, () => {
+          __$element$6 = Palta.createElement("div", ["Ready"]);
+          __$element$6.initialize({});
+        }, () => {});
+      }
+    },
+    update: __$update,
+    getRoot: () => __$root,
+  };
+};
+
+export default Component;
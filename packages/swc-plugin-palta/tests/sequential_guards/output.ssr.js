@@ -0,0 +1,22 @@
+import Palta from "palta";
+
+const Component = ({ a, b, c, d, e }) => {
+  if (a) {
+    return Palta.html`<p>a</p>`;
+  }
+  if (b) {
+    return Palta.html`<p>b</p>`;
+  }
+  if (c) {
+    return Palta.html`<p>c</p>`;
+  }
+  if (d) {
+    return Palta.html`<p>d</p>`;
+  }
+  if (e) {
+    return Palta.html`<p>e</p>`;
+  }
+  return Palta.html`<div>Ready</div>`;
+};
+
+export default Component;
@@ -0,0 +1,31 @@
+import Palta, { Children, Slot } from "palta";
+
+// @Palta.component
+const Card = ({ title }: { title: string; footer?: Palta.Node }) => {
+  return (
+    <article>
+      <header>
+        <Children name="header" />
+      </header>
+      <h2>{title}</h2>
+      <Children />
+      <footer>
+        <Children name="footer" />
+      </footer>
+    </article>
+  );
+};
+
+// @Palta.component
+const Page = ({ user }: { user: string }) => {
+  return (
+    <Card title="Profile" footer={<small>Updated today</small>}>
+      <Slot name="header">
+        <h1>Hello, {user}!</h1>
+      </Slot>
+      <p>Welcome back.</p>
+    </Card>
+  );
+};
+
+export default Page;
@@ -0,0 +1,22 @@
+import Palta from "palta";
+
+const Card = ({ title }, __$children, __$slots) => {
+  return Palta
+    .html`<article><header><!--[-->${__$slots.header}<!--]--></header><h2><!--[-->${title}<!--]--></h2><!--[-->${__$children}<!--]--><footer><!--[-->${__$slots.footer}<!--]--></footer></article>`;
+};
+
+const Page = ({ user }) => {
+  return Palta.renderComponent(
+    Card,
+    {
+      title: "Profile",
+      footer: Palta.html`<small>Updated today</small>`,
+    },
+    Palta.html`<p>Welcome back.</p>`,
+    {
+      header: Palta.html`<h1>Hello, <!--[-->${user}<!--]-->!</h1>`,
+    }
+  );
+};
+
+export default Page;
@@ -0,0 +1,111 @@
+import Palta from "palta";
+
+const Card = (): Palta.ComponentDefinition<{
+  title: string;
+  footer?: Palta.Node;
+}> => {
+  const __$element$6 = Palta.createChildren();
+  const __$element$5 = Palta.createElement("footer", [__$element$6]);
+  const __$element$4 = Palta.createChildren();
+  const __$element$3 = Palta.createElement("h2", [""]);
+  const __$element$2 = Palta.createChildren();
+  const __$element$1 = Palta.createElement("header", [__$element$2]);
+  const __$element$0 = Palta.createElement("article", [
+    __$element$1,
+    __$element$3,
+    __$element$4,
+    __$element$5,
+  ]);
+  let __$props: any = {};
+  const __$update = ({ title }: {
+    title: string;
+    footer?: Palta.Node;
+  }) => {
+    __$props = {
+      title,
+    };
+    __$element$3.updateChild(0, () => {
+      return title;
+    });
+  };
+  let __$root = __$element$0;
+
+  return {
+    slots: {
+      header: __$element$2,
+      children: __$element$4,
+      footer: __$element$6,
+    },
+    initialize: ({ title }: {
+      title: string;
+      footer?: Palta.Node;
+    }) => {
+      __$props = {
+        title,
+      };
+      __$element$1.initialize({});
+      __$element$3.updateChild(0, () => {
+        return title;
+      });
+      __$element$3.initialize({});
+      __$element$5.initialize({});
+      __$element$0.initialize({});
+    },
+    update: __$update,
+    getRoot: () => __$root,
+  };
+};
+
+const Page = (): Palta.ComponentDefinition<{
+  user: string;
+}> => {
+  const __$element$4 = Palta.createElement("p", ["Welcome back."]);
+  const __$element$3 = Palta.createElement("h1", ["Hello, ", "", "!"]);
+  const __$element$2 = Palta.createSlot("header", [__$element$3]);
+  const __$element$1 = Palta.createComponent(Card, [
+    __$element$2,
+    __$element$4,
+  ]);
+  const __$element$0 = Palta.createElement("small", ["Updated today"]);
+  let __$props: any = {};
+  const __$update = ({ user }: {
+    user: string;
+  }) => {
+    __$props = {
+      user,
+    };
+    __$element$3.updateChild(1, () => {
+      return user;
+    });
+    __$element$1.updateProps({
+      title: "Profile",
+      footer: __$element$0,
+    });
+  };
+  let __$root = __$element$1;
+
+  return {
+    slots: {},
+    initialize: ({ user }: {
+      user: string;
+    }) => {
+      __$props = {
+        user,
+      };
+      __$element$0.initialize({});
+      __$element$3.updateChild(1, () => {
+        return user;
+      });
+      __$element$3.initialize({});
+      __$element$4.initialize({});
+      __$element$1.initialize({
+        title: "Profile",
+        footer: __$element$0,
+      });
+    },
+    update: __$update,
+    getRoot: () => __$root,
+  };
+};
+
+export default Page;
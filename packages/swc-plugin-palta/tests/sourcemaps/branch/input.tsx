@@ -0,0 +1,6 @@
+// @Palta.component
+const Toggle = ({ open }: { open: boolean }) => {
+  return <div>{open ? <span>opened</span> : <span>closed</span>}</div>;
+};
+
+export default Toggle;
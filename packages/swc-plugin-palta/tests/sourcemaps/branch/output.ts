@@ -0,0 +1,60 @@
+import Palta from "palta";
+
+const Toggle = (): Palta.ComponentDefinition<{
+  open: boolean;
+}> => {
+  let __$element$3: any;
+  let __$element$2: any;
+  const __$element$1 = Palta.createBranch([
+    () => __$element$2,
+    () => __$element$3,
+  ]);
+  const __$element$0 = Palta.createElement("div", [__$element$1]);
+  let __$props: any = {};
+  const __$update = ({ open }: {
+    open: boolean;
+  }) => {
+    __$props = {
+      open,
+    };
+    if (open) {
+      __$element$1.updateBranch(0, () => {
+        __$element$2 = Palta.createElement("span", ["opened"]);
+        __$element$2.initialize({});
+      }, () => {});
+    } else {
+      __$element$1.updateBranch(1, () => {
+        __$element$3 = Palta.createElement("span", ["closed"]);
+        __$element$3.initialize({});
+      }, () => {});
+    }
+  };
+  let __$root = __$element$0;
+
+  return {
+    slots: {},
+    initialize: ({ open }: {
+      open: boolean;
+    }) => {
+      __$props = {
+        open,
+      };
+      if (open) {
+        __$element$1.updateBranch(0, () => {
+          __$element$2 = Palta.createElement("span", ["opened"]);
+          __$element$2.initialize({});
+        }, () => {});
+      } else {
+        __$element$1.updateBranch(1, () => {
+          __$element$3 = Palta.createElement("span", ["closed"]);
+          __$element$3.initialize({});
+        }, () => {});
+      }
+      __$element$0.initialize({});
+    },
+    update: __$update,
+    getRoot: () => __$root,
+  };
+};
+
+export default Toggle;
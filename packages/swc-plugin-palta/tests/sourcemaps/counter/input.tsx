@@ -0,0 +1,18 @@
+import { $state, $effect } from "palta";
+
+// @Palta.component
+const Counter = ({ step }: { step: number }) => {
+  const [count, setCount] = $state(0);
+
+  $effect(() => {
+    document.title = `${count}`;
+  }, [count]);
+
+  return (
+    <button title={`+${step}`} onClick={() => setCount(count + step)}>
+      {count}
+    </button>
+  );
+};
+
+export default Counter;
@@ -0,0 +1,77 @@
+import Palta from "palta";
+
+const Counter = (): Palta.ComponentDefinition<{
+  step: number;
+}> => {
+  const __$element$0 = Palta.createElement("button", [""]);
+  let __$props: any = {};
+  let count = 0;
+  const setCount: Palta.StateUpdater<any> = (value) => {
+    count = typeof value === "function" ? value(count) : value;
+    Palta.componentUpdate(() => __$update$0(__$props));
+  };
+  const __$effect$0 = {
+    deps: null,
+    cleanup: null,
+  };
+  const __$update = ({ step }: {
+    step: number;
+  }) => {
+    __$props = {
+      step,
+    };
+    __$element$0.updateChild(0, () => {
+      return count;
+    });
+    __$element$0.updateProps({
+      title: `+${step}`,
+      onClick: () => setCount(count + step),
+    });
+    Palta.runEffect(__$effect$0, () => {
+      document.title = `${count}`;
+    }, [count]);
+  };
+  const __$update$0 = ({ step }: {
+    step: number;
+  }) => {
+    __$element$0.updateChild(0, () => {
+      return count;
+    });
+    __$element$0.updateProps({
+      title: `+${step}`,
+      onClick: () => setCount(count + step),
+    });
+    Palta.runEffect(__$effect$0, () => {
+      document.title = `${count}`;
+    }, [count]);
+  };
+  let __$root = __$element$0;
+
+  return {
+    slots: {},
+    initialize: ({ step }: {
+      step: number;
+    }) => {
+      __$props = {
+        step,
+      };
+      Palta.mountEffect(__$effect$0, () => {
+        document.title = `${count}`;
+      }, [count]);
+      __$element$0.updateChild(0, () => {
+        return count;
+      });
+      __$element$0.initialize({
+        title: `+${step}`,
+        onClick: () => setCount(count + step),
+      });
+    },
+    update: __$update,
+    getRoot: () => __$root,
+    unmount: () => {
+      Palta.cleanupEffect(__$effect$0);
+    },
+  };
+};
+
+export default Counter;
@@ -0,0 +1,12 @@
+// @Palta.component
+const List = ({ items }: { items: string[] }) => {
+  return (
+    <ul>
+      {items.map((item) => (
+        <li key={item}>{item}</li>
+      ))}
+    </ul>
+  );
+};
+
+export default List;
@@ -0,0 +1,65 @@
+import Palta from "palta";
+
+const List = (): Palta.ComponentDefinition<{
+  items: string[];
+}> => {
+  const __$list$0 = Palta.createList((): Palta.ComponentDefinition<any> => {
+    const __$element$0 = Palta.createElement("li", [""]);
+    let __$props: any = {};
+    const __$update = ([item]: any) => {
+      __$props = [item];
+      __$element$0.updateChild(0, () => {
+        return item;
+      });
+    };
+    let __$root = __$element$0;
+    return {
+      slots: {},
+      initialize: ([item]: any) => {
+        __$props = [item];
+        __$element$0.updateChild(0, () => {
+          return item;
+        });
+        __$element$0.initialize({});
+      },
+      update: __$update,
+      getRoot: () => __$root,
+    };
+  });
+  const __$element$0 = Palta.createElement("ul", [""]);
+  let __$props: any = {};
+  const __$update = ({ items }: {
+    items: string[];
+  }) => {
+    __$props = {
+      items,
+    };
+    __$element$0.updateChild(0, () => {
+      return __$list$0.collect(() =>
+        items.map((item) => __$list$0.render([item], item))
+      );
+    });
+  };
+  let __$root = __$element$0;
+
+  return {
+    slots: {},
+    initialize: ({ items }: {
+      items: string[];
+    }) => {
+      __$props = {
+        items,
+      };
+      __$element$0.updateChild(0, () => {
+        return __$list$0.collect(() =>
+          items.map((item) => __$list$0.render([item], item))
+        );
+      });
+      __$element$0.initialize({});
+    },
+    update: __$update,
+    getRoot: () => __$root,
+  };
+};
+
+export default List;
@@ -0,0 +1,18 @@
+import Palta, { $state } from "palta";
+
+// @Palta.component
+const Component = ({ items }) => {
+  const [selected, setSelected] = $state(0);
+
+  return (
+    <ul>
+      <li>First</li>
+      {...items.map((item, index) => (
+        <li onClick={() => setSelected(index)}>{item}</li>
+      ))}
+      <li>Selected: {selected}</li>
+    </ul>
+  );
+};
+
+export default Component;
@@ -0,0 +1,101 @@
+import Palta from "palta";
+
+const __$template$0 = Palta.createTemplate(
+  "<ul><li>First</li><!--[--><!--]--><li>Selected: <!--[--><!--]--></li></ul>"
+);
+
+const Component = () => {
+  const __$list$0 = Palta.createList(() => {
+    const __$element$0 = Palta.createElement("li", [""]);
+    let __$props = {};
+    const __$update = ([item, index, setSelected]) => {
+      __$props = [item, index, setSelected];
+      __$element$0.updateChild(0, () => {
+        return item;
+      });
+      __$element$0.updateProps({
+        onClick: () => setSelected(index),
+      });
+    };
+    let __$root = __$element$0;
+    return {
+      slots: {},
+      initialize: ([item, index, setSelected]) => {
+        __$props = [item, index, setSelected];
+        __$element$0.updateChild(0, () => {
+          return item;
+        });
+        __$element$0.initialize({
+          onClick: () => setSelected(index),
+        });
+      },
+      update: __$update,
+      getRoot: () => __$root,
+    };
+  });
+  const __$element$2 = Palta.claimElement(() => __$element$0, 2, "li", [
+    "Selected: ",
+    "",
+  ]);
+  const __$element$0 = Palta.cloneTemplate(__$template$0, "ul", [
+    null,
+    "",
+    __$element$2,
+  ]);
+  let __$props = {};
+  let selected = 0;
+  const setSelected = (value) => {
+    selected = typeof value === "function" ? value(selected) : value;
+    Palta.componentUpdate(() => __$update$0(__$props));
+  };
+  const __$update = ({ items }) => {
+    __$props = {
+      items,
+    };
+    __$element$0.updateChild(1, () => {
+      return [
+        ...__$list$0.collect(() =>
+          items.map((item, index) =>
+            __$list$0.render([item, index, setSelected])
+          )
+        ),
+      ];
+    });
+    __$element$2.updateChild(1, () => {
+      return selected;
+    });
+  };
+  const __$update$0 = ({ items }) => {
+    __$element$2.updateChild(1, () => {
+      return selected;
+    });
+  };
+  let __$root = __$element$0;
+
+  return {
+    slots: {},
+    initialize: ({ items }) => {
+      __$props = {
+        items,
+      };
+      __$element$0.updateChild(1, () => {
+        return [
+          ...__$list$0.collect(() =>
+            items.map((item, index) =>
+              __$list$0.render([item, index, setSelected])
+            )
+          ),
+        ];
+      });
+      __$element$2.updateChild(1, () => {
+        return selected;
+      });
+      __$element$2.initialize({});
+      __$element$0.initialize({});
+    },
+    update: __$update,
+    getRoot: () => __$root,
+  };
+};
+
+export default Component;
@@ -0,0 +1,10 @@
+import Palta from "palta";
+
+const Component = ({ items }) => {
+  const selected = 0, setSelected = () => {};
+  return Palta.html`<ul><li>First</li><!--[-->${[
+    ...items.map((item, index) => Palta.html`<li><!--[-->${item}<!--]--></li>`),
+  ]}<!--]--><li>Selected: <!--[-->${selected}<!--]--></li></ul>`;
+};
+
+export default Component;
@@ -0,0 +1,8 @@
+import Palta from "palta";
+
+const Component = () => {
+  const name = "World", setName = () => {};
+  return Palta.html`<div><h1>Hello, <!--[-->${name}<!--]-->!</h1></div>`;
+};
+
+export default Component;
@@ -7,7 +7,7 @@ const Component = (): Palta.ComponentDefinition<{}> => {
   let name = "World";
   const setName: Palta.StateUpdater<any> = (value) => {
     name = typeof value === "function" ? value(name) : value;
-    Palta.componentUpdate(() => __$update(__$props));
+    Palta.componentUpdate(() => __$update$0(__$props));
   };
   const __$update = ({}: {}) => {
     __$props = {};
@@ -15,10 +15,15 @@ const Component = (): Palta.ComponentDefinition<{}> => {
       return name;
     });
   };
+  const __$update$0 = ({}: {}) => {
+    __$element$1.updateChild(1, () => {
+      return name;
+    });
+  };
   let __$root = __$element$0;
 
   return {
-    childrenElement: null,
+    slots: {},
     initialize: ({}: {}) => {
       __$props = {};
       __$element$1.updateChild(1, () => {
@@ -0,0 +1,17 @@
+import Badge from "./Badge";
+
+// @Palta.component
+const Card = ({ title, count }) => {
+  return (
+    <div className="card" style={{ display: "flex" }}>
+      <h1 className="card-title" id={title}>
+        {title}
+      </h1>
+      <span>{"Total: "}{count}</span>
+      <Badge color="red" size={2} />
+      <Badge color="blue" count={count} />
+    </div>
+  );
+};
+
+export default Card;
@@ -0,0 +1,25 @@
+import Palta from "palta";
+
+import Badge from "./Badge";
+
+const Card = ({ title, count }) => {
+  return Palta.html`<div class="card"${
+    Palta.attribute("style", {
+      display: "flex",
+    })
+  }><h1 class="card-title"${
+    Palta.attribute("id", title)
+  }><!--[-->${title}<!--]--></h1><span><!--[-->${"Total: "}<!--]--><!--[-->${count}<!--]--></span><!--[-->${
+    Palta.renderComponent(Badge, {
+      color: "red",
+      size: 2,
+    })
+  }<!--]--><!--[-->${
+    Palta.renderComponent(Badge, {
+      color: "blue",
+      count: count,
+    })
+  }<!--]--></div>`;
+};
+
+export default Card;
@@ -0,0 +1,88 @@
+import Palta from "palta";
+
+import Badge from "./Badge";
+
+const Card = (): Palta.ComponentDefinition<{
+  title: string;
+  count: number;
+}> => {
+  const __$element$4 = Palta.createComponent(Badge, []);
+  const __$element$3 = Palta.createComponent(Badge, []);
+  const __$element$2 = Palta.createElement("span", ["", ""]);
+  const __$element$1 = Palta.createElement("h1", [""]);
+  const __$element$0 = Palta.createElement("div", [
+    __$element$1,
+    __$element$2,
+    __$element$3,
+    __$element$4,
+  ]);
+  let __$props: any = {};
+  const __$update = ({ title, count }: {
+    title: string;
+    count: number;
+  }) => {
+    __$props = {
+      title,
+      count,
+    };
+    __$element$1.updateChild(0, () => {
+      return title;
+    });
+    __$element$1.updateProps({
+      id: title,
+    });
+    __$element$2.updateChild(1, () => {
+      return count;
+    });
+    __$element$4.updateProps({
+      color: "blue",
+      count: count,
+    });
+  };
+  let __$root = __$element$0;
+
+  return {
+    slots: {},
+    initialize: ({ title, count }: {
+      title: string;
+      count: number;
+    }) => {
+      __$props = {
+        title,
+        count,
+      };
+      __$element$1.updateChild(0, () => {
+        return title;
+      });
+      __$element$1.initialize({
+        className: "card-title",
+        id: title,
+      });
+      __$element$2.updateChild(0, () => {
+        return "Total: ";
+      });
+      __$element$2.updateChild(1, () => {
+        return count;
+      });
+      __$element$2.initialize({});
+      __$element$3.initialize({
+        color: "red",
+        size: 2,
+      });
+      __$element$4.initialize({
+        color: "blue",
+        count: count,
+      });
+      __$element$0.initialize({
+        className: "card",
+        style: {
+          display: "flex",
+        },
+      });
+    },
+    update: __$update,
+    getRoot: () => __$root,
+  };
+};
+
+export default Card;
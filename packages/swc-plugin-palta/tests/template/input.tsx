@@ -0,0 +1,22 @@
+import Avatar from "./Avatar";
+
+// @Palta.component
+const Page = ({ name, onSave }: { name: string; onSave: () => void }) => {
+  return (
+    <main className="page">
+      <header>
+        <h1 id="title">Hello, {name}!</h1>
+        <Avatar name={name} />
+      </header>
+      <button onClick={onSave}>Save</button>
+      <footer className="footer">
+        <p>
+          Made with <a href="https://palta.dev">Palta</a>
+        </p>
+        <span tabIndex={0}>&copy; 2024</span>
+      </footer>
+    </main>
+  );
+};
+
+export default Page;
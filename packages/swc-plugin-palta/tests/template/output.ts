@@ -0,0 +1,84 @@
+import Palta from "palta";
+
+import Avatar from "./Avatar";
+
+const __$template$0 = Palta.createTemplate(
+  '<main class="page"><header><h1 id="title">Hello, <!--[--><!--]-->!</h1><!--[--><!--]--></header><button>Save</button><footer class="footer"><p>\n          Made with <a href="https://palta.dev">Palta</a></p><span tabIndex="0">© 2024</span></footer></main>'
+);
+
+const Page = (): Palta.ComponentDefinition<{
+  name: string;
+  onSave: () => void;
+}> => {
+  const __$element$4 = Palta.claimElement(() => __$element$0, 1, "button", [
+    "Save",
+  ]);
+  const __$element$3 = Palta.createComponent(Avatar, []);
+  const __$element$2 = Palta.claimElement(() => __$element$1, 0, "h1", [
+    "Hello, ",
+    "",
+    "!",
+  ]);
+  const __$element$1 = Palta.claimElement(() => __$element$0, 0, "header", [
+    __$element$2,
+    __$element$3,
+  ]);
+  const __$element$0 = Palta.cloneTemplate(__$template$0, "main", [
+    __$element$1,
+    __$element$4,
+    null,
+  ]);
+  let __$props: any = {};
+  const __$update = ({ name, onSave }: {
+    name: string;
+    onSave: () => void;
+  }) => {
+    __$props = {
+      name,
+      onSave,
+    };
+    __$element$2.updateChild(1, () => {
+      return name;
+    });
+    __$element$3.updateProps({
+      name: name,
+    });
+    __$element$4.updateProps({
+      onClick: onSave,
+    });
+  };
+  let __$root = __$element$0;
+
+  return {
+    slots: {},
+    initialize: ({ name, onSave }: {
+      name: string;
+      onSave: () => void;
+    }) => {
+      __$props = {
+        name,
+        onSave,
+      };
+      __$element$2.updateChild(1, () => {
+        return name;
+      });
+      __$element$2.initialize({
+        id: "title",
+      });
+      __$element$3.initialize({
+        name: name,
+      });
+      __$element$1.initialize({});
+      __$element$4.initialize({
+        onClick: onSave,
+      });
+      __$element$0.initialize({
+        className: "page",
+      });
+    },
+    update: __$update,
+    getRoot: () => __$root,
+  };
+};
+
+export default Page;
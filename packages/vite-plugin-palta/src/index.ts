@@ -17,9 +17,21 @@
 import { createFilter, Plugin } from "vite";
 import { transform } from "@swc/core";
 
-const palta = (): Plugin => {
+export type PaltaOptions = {
+  importSource?: string;
+  marker?: string;
+  ignoreMarker?: string;
+  detection?: "marker" | "convention";
+  // Defaults to true when running the dev server
+  hmr?: boolean;
+  // Adopts the server-rendered markup in the client build
+  hydrate?: boolean;
+};
+
+const palta = (options: PaltaOptions = {}): Plugin => {
   const typescriptFilter = createFilter(/\.tsx$/);
   const javascriptFilter = createFilter(/\.jsx$/);
+  let hmr = false;
 
   return {
     name: "palta",
@@ -28,7 +40,10 @@ const palta = (): Plugin => {
         exclude: /\.[tj]sx?$/,
       },
     }),
-    async transform(code, id) {
+    configResolved(config) {
+      hmr = options.hmr ?? config.command === "serve";
+    },
+    async transform(code, id, transformOptions) {
       const isTS = typescriptFilter(id);
 
       if (!javascriptFilter(id) && !isTS) {
@@ -36,6 +51,7 @@ const palta = (): Plugin => {
       }
 
       const result = await transform(code, {
+        filename: id,
         jsc: {
           parser: isTS
             ? {
@@ -47,7 +63,16 @@ const palta = (): Plugin => {
                 jsx: true,
               },
           experimental: {
-            plugins: [["swc-plugin-palta", {}]],
+            plugins: [
+              [
+                "swc-plugin-palta",
+                {
+                  ...options,
+                  hmr,
+                  target: transformOptions?.ssr ? "ssr" : "dom",
+                },
+              ],
+            ],
           },
           transform: {
             react: {